@@ -41,12 +41,19 @@ criterion_group! {
 criterion_group! {
     name = ae_duration_groth16;
     config = Criterion::default();
-    targets = ae_duration_groth16_ciminion, ae_duration_groth16_griffin, ae_duration_groth16_neptune
+    targets = ae_duration_groth16_ciminion, ae_duration_groth16_griffin, ae_duration_groth16_neptune, ae_duration_groth16_ciminion_subkeys
+}
+
+criterion_group! {
+    name = signature_duration_groth16;
+    config = Criterion::default();
+    targets = signature_duration_groth16_griffin, signature_duration_groth16_neptune, signature_duration_groth16_rescue
 }
 
 criterion_main!(
     hash_duration_marlin,
     ae_duration_marlin,
     hash_duration_groth16,
-    ae_duration_groth16
+    ae_duration_groth16,
+    signature_duration_groth16
 );
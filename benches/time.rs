@@ -24,30 +24,36 @@ use ark_snark::SNARK;
 use blake2::Blake2s;
 use criterion::*;
 
-use ark_ff::{BigInteger256 as I256, UniformRand};
+use ark_ff::UniformRand;
 
 use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
 
 use zekrom_arkworks::{
-    api::Sponge,
-
-    common::test_utils::to_bls,
+    api::{ChipAPI, Sponge},
+    ciminion::{
+        chip::CiminionChip,
+        circuit::{CiminionCircuit, CiminionSubkeysCircuit},
+        primitives::CiminionPrimitiveBlsFr,
+    },
     griffin::{
-        ae_circuit::GriffinAECircuit,
-        chip::{GriffinChip, GriffinParameters},
-        hash_circuit::GriffinHashCircuit,
+        ae_circuit::GriffinAECircuit, chip::GriffinChip, hash_circuit::GriffinHashCircuit,
         primitives::GriffinPrimitivesBlsFr,
-    }, rescue_prime::{chip::{RescuePrimeParameters, RescuePrimeChip}, params::{ROUND_CONSTANTS, MDS, ALPHAINV_BLS381, ALPHA_BLS381}, primitives::RescuePrimePrimitivesBlsFr, circuit::RescuePrimeHashCircuit}, neptune::{chip::{NeptuneChip, NeptuneParameters}, hash_circuit::NeptuneHashCircuit, primitives::NeptunePrimitivesBlsFr, ae_circuit::NeptuneAECircuit}, ciminion::params::{NB_R_PE_C, NB_R_PC},
+    },
+    neptune::{
+        ae_circuit::NeptuneAECircuit, chip::NeptuneChip, hash_circuit::NeptuneHashCircuit,
+        primitives::NeptunePrimitivesBlsFr,
+    },
+    rescue_prime::{
+        chip::RescuePrimeChip, circuit::RescuePrimeHashCircuit,
+        primitives::RescuePrimePrimitivesBlsFr,
+    },
+    signature::{circuit::XmssCircuit, wots::Wots, xmss::XmssKeypair, WotsParameters},
 };
 
 const MAX_SIZE: usize = 10;
-const KRC: u32 = 11;
-const KRP: u32 = 6;
-const KNH: u32 = 6;
-const KNC: u32 = 7;
-const KGH: u32 = 8;
-const KGC: u32 = 9;
-const KC: u32 = 14;
+
+/// The heights of the XMSS-lite trees, a tree of height 0 is a single WOTS+ key
+const HEIGHTS: [usize; 3] = [0, 2, 4];
 
 pub fn hash_duration_marlin_rescue(c: &mut Criterion) {
     let mut group = c.benchmark_group("Rescue Prime Hash - marlin - Time bench");
@@ -59,12 +65,7 @@ pub fn hash_duration_marlin_rescue(c: &mut Criterion) {
     let nc = 200000;
     let nv = 1;
 
-    let parameters = RescuePrimeParameters {
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        mds: to_bls(&MDS),
-        alpha_inv: ALPHAINV_BLS381,
-        alpha: ALPHA_BLS381,
-    };
+    let parameters = zekrom_arkworks::rescue_prime::params::bls12_381();
     let chip = RescuePrimeChip::new(parameters);
 
     type RpSpnBls381 = Sponge<RescuePrimeChip<BlsFr>>;
@@ -100,7 +101,7 @@ pub fn hash_duration_marlin_rescue(c: &mut Criterion) {
         let proof = MarlinSetup::prove(&pk, circuit.clone(), rng).unwrap();
 
         group.bench_function(
-            &format!("Rescue Prime Marlin proof Verification with N = {}", size),
+            format!("Rescue Prime Marlin proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = MarlinSetup::verify(&vk, &[hash], &proof, rng).unwrap();
@@ -118,24 +119,13 @@ pub fn hash_duration_marlin_neptune(c: &mut Criterion) {
     type NepChpBls381 = NeptuneChip<BlsFr>;
     type NepSpnBls381 = Sponge<NepChpBls381>;
     type NepHshCrcBls381 = NeptuneHashCircuit<BlsFr>;
-    type NepPrmBls381 = NeptuneParameters<BlsFr>;
 
     let rng = &mut ark_std::test_rng();
 
     let mut nc = 10000;
     let nv = 1;
 
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [
-            zekrom_arkworks::neptune::params::NEB,
-            zekrom_arkworks::neptune::params::NEE,
-        ],
-        nb_rounds_int: zekrom_arkworks::neptune::params::NI,
-        round_constants: to_bls(&zekrom_arkworks::neptune::params::ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::neptune::params::GAMMA_BLS))),
-        d: zekrom_arkworks::neptune::params::D,
-        matrix_int: to_bls(&zekrom_arkworks::neptune::params::INTERNAL_MATRIX_BLS),
-    };
+    let parameters = zekrom_arkworks::neptune::params::bls12_381();
     let chip = NepChpBls381::new(parameters);
     let sponge = NepSpnBls381::new(chip);
 
@@ -172,7 +162,7 @@ pub fn hash_duration_marlin_neptune(c: &mut Criterion) {
         let proof = MarlinSetup::prove(&pk, circuit.clone(), rng).unwrap();
 
         group.bench_function(
-            &format!("Neptune Hash Marlin proof Verification with N = {}", size),
+            format!("Neptune Hash Marlin proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = MarlinSetup::verify(&vk, &[hash], &proof, rng).unwrap();
@@ -190,7 +180,6 @@ pub fn hash_duration_marlin_griffin(c: &mut Criterion) {
 
     type GrifChpBls381 = GriffinChip<BlsFr>;
     type GrifHshCrcBls381 = GriffinHashCircuit<BlsFr>;
-    type GrifPrmBls381 = GriffinParameters<BlsFr>;
 
     type GrifSpnBls381 = Sponge<GrifChpBls381>;
 
@@ -199,14 +188,7 @@ pub fn hash_duration_marlin_griffin(c: &mut Criterion) {
     let nc = 50000;
     let nv = 1;
 
-    let parameters = GrifPrmBls381 {
-        nb_rounds: zekrom_arkworks::griffin::params::N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::BETA))),
-        d: zekrom_arkworks::griffin::params::D_BLS381,
-        d_inv: zekrom_arkworks::griffin::params::D_INV_BLS381,
-    };
+    let parameters = zekrom_arkworks::griffin::params::bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -240,7 +222,7 @@ pub fn hash_duration_marlin_griffin(c: &mut Criterion) {
         let proof = MarlinSetup::prove(&pk, circuit.clone(), rng).unwrap();
 
         group.bench_function(
-            &format!("Griffin Hash Marlin proof Verification with N = {}", size),
+            format!("Griffin Hash Marlin proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = MarlinSetup::verify(&vk, &[hash], &proof, rng).unwrap();
@@ -257,7 +239,6 @@ pub fn ae_duration_marlin_griffin(c: &mut Criterion) {
     type MarlinSetup = Marlin<BlsFr, KZG10, Blake2s>;
 
     type GrifChpBls381 = GriffinChip<BlsFr>;
-    type GrifPrmBls381 = GriffinParameters<BlsFr>;
 
     type GrifSpnBls381 = Sponge<GrifChpBls381>;
     type GrifAECrcBls381 = GriffinAECircuit<BlsFr>;
@@ -266,14 +247,7 @@ pub fn ae_duration_marlin_griffin(c: &mut Criterion) {
 
     let nc = 40000;
 
-    let parameters = GrifPrmBls381 {
-        nb_rounds: zekrom_arkworks::griffin::params::N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::BETA))),
-        d: zekrom_arkworks::griffin::params::D_BLS381,
-        d_inv: zekrom_arkworks::griffin::params::D_INV_BLS381,
-    };
+    let parameters = zekrom_arkworks::griffin::params::bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -316,7 +290,7 @@ pub fn ae_duration_marlin_griffin(c: &mut Criterion) {
         public.push(nonce);
 
         group.bench_function(
-            &format!("Griffin AE Marlin proof Verification with N = {}", size),
+            format!("Griffin AE Marlin proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = MarlinSetup::verify(&vk, &public, &proof, rng).unwrap();
@@ -334,23 +308,12 @@ pub fn ae_duration_marlin_neptune(c: &mut Criterion) {
     type NepChpBls381 = NeptuneChip<BlsFr>;
     type NepSpnBls381 = Sponge<NepChpBls381>;
     type NepAECrcBls381 = NeptuneAECircuit<BlsFr>;
-    type NepPrmBls381 = NeptuneParameters<BlsFr>;
 
     let rng = &mut ark_std::test_rng();
 
     let nc = 40000;
 
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [
-            zekrom_arkworks::neptune::params::NEB,
-            zekrom_arkworks::neptune::params::NEE,
-        ],
-        nb_rounds_int: zekrom_arkworks::neptune::params::NI,
-        round_constants: to_bls(&zekrom_arkworks::neptune::params::ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::neptune::params::GAMMA_BLS))),
-        d: zekrom_arkworks::neptune::params::D,
-        matrix_int: to_bls(&zekrom_arkworks::neptune::params::INTERNAL_MATRIX_BLS),
-    };
+    let parameters = zekrom_arkworks::neptune::params::bls12_381();
     let chip = NepChpBls381::new(parameters);
     let sponge = NepSpnBls381::new(chip);
 
@@ -393,7 +356,7 @@ pub fn ae_duration_marlin_neptune(c: &mut Criterion) {
         public.push(nonce);
 
         group.bench_function(
-            &format!("Neptune AE Marlin proof Verification with N = {}", size),
+            format!("Neptune AE Marlin proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = MarlinSetup::verify(&vk, &public, &proof, rng).unwrap();
@@ -417,11 +380,7 @@ pub fn ae_duration_marlin_ciminion(c: &mut Criterion) {
     let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
     let nonce = BlsFr::rand(rng);
 
-    let parameters = zekrom_arkworks::ciminion::chip::CiminionParameters {
-        nb_rounds_pe: NB_R_PE_C,
-        nb_rounds_pc: NB_R_PC,
-        round_constants: to_bls(&zekrom_arkworks::ciminion::params::ROUND_CONSTANTS_BLS),
-    };
+    let parameters = zekrom_arkworks::ciminion::params::bls12_381();
 
     let chip = zekrom_arkworks::ciminion::chip::CiminionChip::new(parameters);
 
@@ -458,7 +417,7 @@ pub fn ae_duration_marlin_ciminion(c: &mut Criterion) {
         public.push(nonce);
 
         group.bench_function(
-            &format!("Ciminion AE Marlin proof Verification with N = {}", size),
+            format!("Ciminion AE Marlin proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = MarlinSetup::verify(&vk, &public, &proof, rng).unwrap();
@@ -473,12 +432,7 @@ pub fn hash_duration_groth16_rescue(c: &mut Criterion) {
 
     let rng = &mut ark_std::test_rng();
 
-    let parameters = RescuePrimeParameters {
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        mds: to_bls(&MDS),
-        alpha_inv: ALPHAINV_BLS381,
-        alpha: ALPHA_BLS381,
-    };
+    let parameters = zekrom_arkworks::rescue_prime::params::bls12_381();
     let chip = RescuePrimeChip::new(parameters);
 
     type RpSpnBls381 = Sponge<RescuePrimeChip<BlsFr>>;
@@ -515,7 +469,7 @@ pub fn hash_duration_groth16_rescue(c: &mut Criterion) {
         let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
 
         group.bench_function(
-            &format!("Rescue Prime Groth16 proof Verification with N = {}", size),
+            format!("Rescue Prime Groth16 proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = GrothSetup::verify(&vk, &[hash], &proof).unwrap();
@@ -531,21 +485,10 @@ pub fn hash_duration_groth16_neptune(c: &mut Criterion) {
     type NepChpBls381 = NeptuneChip<BlsFr>;
     type NepSpnBls381 = Sponge<NepChpBls381>;
     type NepHshCrcBls381 = NeptuneHashCircuit<BlsFr>;
-    type NepPrmBls381 = NeptuneParameters<BlsFr>;
 
     let rng = &mut ark_std::test_rng();
 
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [
-            zekrom_arkworks::neptune::params::NEB,
-            zekrom_arkworks::neptune::params::NEE,
-        ],
-        nb_rounds_int: zekrom_arkworks::neptune::params::NI,
-        round_constants: to_bls(&zekrom_arkworks::neptune::params::ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::neptune::params::GAMMA_BLS))),
-        d: zekrom_arkworks::neptune::params::D,
-        matrix_int: to_bls(&zekrom_arkworks::neptune::params::INTERNAL_MATRIX_BLS),
-    };
+    let parameters = zekrom_arkworks::neptune::params::bls12_381();
     let chip = NepChpBls381::new(parameters);
     let sponge = NepSpnBls381::new(chip);
 
@@ -586,7 +529,7 @@ pub fn hash_duration_groth16_neptune(c: &mut Criterion) {
         let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
 
         group.bench_function(
-            &format!("Neptune Hash Groth16 proof Verification with N = {}", size),
+            format!("Neptune Hash Groth16 proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = GrothSetup::verify(&vk, &[hash], &proof).unwrap();
@@ -603,20 +546,12 @@ pub fn hash_duration_groth16_griffin(c: &mut Criterion) {
 
     type GrifChpBls381 = GriffinChip<BlsFr>;
     type GrifHshCrcBls381 = GriffinHashCircuit<BlsFr>;
-    type GrifPrmBls381 = GriffinParameters<BlsFr>;
 
     type GrifSpnBls381 = Sponge<GrifChpBls381>;
 
     let rng = &mut ark_std::test_rng();
 
-    let parameters = GrifPrmBls381 {
-        nb_rounds: zekrom_arkworks::griffin::params::N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::BETA))),
-        d: zekrom_arkworks::griffin::params::D_BLS381,
-        d_inv: zekrom_arkworks::griffin::params::D_INV_BLS381,
-    };
+    let parameters = zekrom_arkworks::griffin::params::bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -649,7 +584,7 @@ pub fn hash_duration_groth16_griffin(c: &mut Criterion) {
         let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
 
         group.bench_function(
-            &format!("Griffin Hash Groth16 proof Verification with N = {}", size),
+            format!("Griffin Hash Groth16 proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = GrothSetup::verify(&vk, &[hash], &proof).unwrap();
@@ -665,21 +600,13 @@ pub fn ae_duration_groth16_griffin(c: &mut Criterion) {
     type GrothSetup = Groth16<Bls381>;
 
     type GrifChpBls381 = GriffinChip<BlsFr>;
-    type GrifPrmBls381 = GriffinParameters<BlsFr>;
 
     type GrifSpnBls381 = Sponge<GrifChpBls381>;
     type GrifAECrcBls381 = GriffinAECircuit<BlsFr>;
 
     let rng = &mut ark_std::test_rng();
 
-    let parameters = GrifPrmBls381 {
-        nb_rounds: zekrom_arkworks::griffin::params::N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::griffin::params::BETA))),
-        d: zekrom_arkworks::griffin::params::D_BLS381,
-        d_inv: zekrom_arkworks::griffin::params::D_INV_BLS381,
-    };
+    let parameters = zekrom_arkworks::griffin::params::bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -720,7 +647,7 @@ pub fn ae_duration_groth16_griffin(c: &mut Criterion) {
         public.push(nonce);
 
         group.bench_function(
-            &format!("Griffin AE Groth16 proof Verification with N = {}", size),
+            format!("Griffin AE Groth16 proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = GrothSetup::verify(&vk, &public, &proof).unwrap();
@@ -738,21 +665,10 @@ pub fn ae_duration_groth16_neptune(c: &mut Criterion) {
     type NepChpBls381 = NeptuneChip<BlsFr>;
     type NepSpnBls381 = Sponge<NepChpBls381>;
     type NepAECrcBls381 = NeptuneAECircuit<BlsFr>;
-    type NepPrmBls381 = NeptuneParameters<BlsFr>;
 
     let rng = &mut ark_std::test_rng();
 
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [
-            zekrom_arkworks::neptune::params::NEB,
-            zekrom_arkworks::neptune::params::NEE,
-        ],
-        nb_rounds_int: zekrom_arkworks::neptune::params::NI,
-        round_constants: to_bls(&zekrom_arkworks::neptune::params::ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(zekrom_arkworks::neptune::params::GAMMA_BLS))),
-        d: zekrom_arkworks::neptune::params::D,
-        matrix_int: to_bls(&zekrom_arkworks::neptune::params::INTERNAL_MATRIX_BLS),
-    };
+    let parameters = zekrom_arkworks::neptune::params::bls12_381();
     let chip = NepChpBls381::new(parameters);
     let sponge = NepSpnBls381::new(chip);
 
@@ -792,7 +708,7 @@ pub fn ae_duration_groth16_neptune(c: &mut Criterion) {
         public.push(nonce);
 
         group.bench_function(
-            &format!("Neptune AE Groth16 proof Verification with N = {}", size),
+            format!("Neptune AE Groth16 proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = GrothSetup::verify(&vk, &public, &proof).unwrap();
@@ -812,11 +728,7 @@ pub fn ae_duration_groth16_ciminion(c: &mut Criterion) {
     let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
     let nonce = BlsFr::rand(rng);
 
-    let parameters = zekrom_arkworks::ciminion::chip::CiminionParameters {
-        nb_rounds_pe: NB_R_PE_C,
-        nb_rounds_pc: NB_R_PC,
-        round_constants: to_bls(&zekrom_arkworks::ciminion::params::ROUND_CONSTANTS_BLS),
-    };
+    let parameters = zekrom_arkworks::ciminion::params::bls12_381();
 
     let chip = zekrom_arkworks::ciminion::chip::CiminionChip::new(parameters);
 
@@ -852,7 +764,7 @@ pub fn ae_duration_groth16_ciminion(c: &mut Criterion) {
         public.push(nonce);
 
         group.bench_function(
-            &format!("Ciminion AE Groth16 proof Verification with N = {}", size),
+            format!("Ciminion AE Groth16 proof Verification with N = {}", size),
             |b| {
                 b.iter(|| {
                     let _ = GrothSetup::verify(&vk, &public, &proof).unwrap();
@@ -861,3 +773,153 @@ pub fn ae_duration_groth16_ciminion(c: &mut Criterion) {
         );
     }
 }
+
+pub fn ae_duration_groth16_ciminion_subkeys(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Ciminion AE subkeys - groth16 - Time bench");
+
+    type GrothSetup = Groth16<Bls381>;
+
+    let rng = &mut ark_std::test_rng();
+
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let nonce = BlsFr::rand(rng);
+
+    let chip = CiminionChip::new(zekrom_arkworks::ciminion::params::bls12_381());
+
+    // The subkeys are committed with Neptune at full rate
+    let neptune = NeptuneChip::new(zekrom_arkworks::neptune::params::bls12_381());
+    let sponge = Sponge::with_rate(neptune, 3);
+
+    // Compare the derived and the witnessed subkeys over the message size
+    for size in 1..MAX_SIZE + 1 {
+        let message = (0..size).map(|_| BlsFr::rand(rng)).collect::<Vec<_>>();
+        let ciphertext = CiminionPrimitiveBlsFr::encrypt(&message, keys, nonce);
+        let subkeys = CiminionPrimitiveBlsFr::subkeys(keys, size);
+        let commitment = CiminionPrimitiveBlsFr::commit_subkeys(&sponge, &subkeys).unwrap();
+
+        let derived = CiminionCircuit {
+            chip: chip.clone(),
+            message: message.clone(),
+            ciphertext: ciphertext.clone(),
+            keys,
+            nonce,
+        };
+        let witnessed = CiminionSubkeysCircuit {
+            chip: chip.clone(),
+            sponge: sponge.clone(),
+            message,
+            ciphertext,
+            subkeys,
+            commitment,
+            nonce,
+        };
+
+        let (pk, _) = GrothSetup::circuit_specific_setup(derived.clone(), rng).unwrap();
+        group.bench_function(
+            format!(
+                "Ciminion AE derived subkeys Groth16 proof Generation with N = {}",
+                size
+            ),
+            |b| {
+                b.iter(|| {
+                    let _ = GrothSetup::prove(&pk, derived.clone(), rng).unwrap();
+                })
+            },
+        );
+
+        let (pk, _) = GrothSetup::circuit_specific_setup(witnessed.clone(), rng).unwrap();
+        group.bench_function(
+            format!(
+                "Ciminion AE witnessed subkeys Groth16 proof Generation with N = {}",
+                size
+            ),
+            |b| {
+                b.iter(|| {
+                    let _ = GrothSetup::prove(&pk, witnessed.clone(), rng).unwrap();
+                })
+            },
+        );
+    }
+}
+
+/// Compares the verification of WOTS+ and XMSS-lite signatures over a hash
+///
+/// The messages have 16 bits and w = 4, i.e. 11 chains, to keep the circuits of Griffin and
+/// Rescue Prime tractable.
+fn signature_duration_groth16<C>(c: &mut Criterion, name: &str, sponge: Sponge<C>)
+where
+    C: ChipAPI<Value = FpVar<BlsFr>> + Clone,
+{
+    let mut group = c.benchmark_group(format!("{} WOTS+ / XMSS-lite - groth16 - Time bench", name));
+
+    type GrothSetup = Groth16<Bls381>;
+
+    let rng = &mut ark_std::test_rng();
+
+    let wots = Wots::new(sponge, WotsParameters::new(16, 2).unwrap()).unwrap();
+    let message = BlsFr::from(0xcafeu64);
+
+    for height in HEIGHTS {
+        let mut keypair = XmssKeypair::new(rng, wots.clone(), height).unwrap();
+        let circuit = XmssCircuit {
+            wots: wots.clone(),
+            public_key: keypair.public_key(),
+            message,
+            signature: keypair.sign(message).unwrap(),
+        };
+        let public_inputs = circuit.public_inputs();
+
+        let index = AHPForR1CS::index(circuit.clone()).unwrap();
+        println!(
+            "Number of constraints for R1CS - {} signature . {} for height {}",
+            name, index.index_info.num_constraints, height
+        );
+
+        let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+
+        group.bench_function(
+            format!(
+                "{} signature Groth16 proof Generation with height = {}",
+                name, height
+            ),
+            |b| {
+                b.iter(|| {
+                    let _ = GrothSetup::prove(&pk, circuit.clone(), rng).unwrap();
+                })
+            },
+        );
+
+        let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+        group.bench_function(
+            format!(
+                "{} signature Groth16 proof Verification with height = {}",
+                name, height
+            ),
+            |b| {
+                b.iter(|| {
+                    let _ = GrothSetup::verify(&vk, &public_inputs, &proof).unwrap();
+                });
+            },
+        );
+    }
+}
+
+pub fn signature_duration_groth16_griffin(c: &mut Criterion) {
+    let chip = GriffinChip::new(zekrom_arkworks::griffin::params::bls12_381());
+    signature_duration_groth16(c, "Griffin", Sponge::<GriffinChip<BlsFr>>::new(chip));
+}
+
+pub fn signature_duration_groth16_neptune(c: &mut Criterion) {
+    let chip = NeptuneChip::new(zekrom_arkworks::neptune::params::bls12_381());
+    signature_duration_groth16(c, "Neptune", Sponge::<NeptuneChip<BlsFr>>::new(chip));
+}
+
+pub fn signature_duration_groth16_rescue(c: &mut Criterion) {
+    let chip = RescuePrimeChip::new(zekrom_arkworks::rescue_prime::params::bls12_381());
+    signature_duration_groth16(
+        c,
+        "Rescue Prime",
+        Sponge::<RescuePrimeChip<BlsFr>>::new(chip),
+    );
+}
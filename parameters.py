@@ -0,0 +1,272 @@
+# zekrom-arkworks
+# Copyright (C) 2023
+#
+# This program is free software: you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation, either version 3 of the License, or
+# (at your option) any later version.
+#
+# This program is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+# GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License
+# along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+# Writes the parameter files found in params/<field>/ (see common::params_file for the format)
+#
+# This is a plain Python port of the helpers of parameters.sage, it only needs the standard library.
+# The Griffin and Ciminion constants for BLS12-381 are reproduced by this script, while the
+# bundled BLS12-381 files for Neptune and Rescue Prime come from earlier runs and are kept as is
+# (they are listed in KEPT, and never overwritten).
+#
+# Usage: python3 parameters.py [field ...]   (all the fields below by default)
+
+import hashlib
+import json
+import os
+import sys
+from math import ceil, log2
+
+# The security level (in bits)
+S = 128
+
+# Prime, S-box exponent and factorization of p-1 (used to find a primitive element)
+FIELDS = {
+    "bls12_381": (
+        0x73EDA753299D7D483339D80809A1D80553BDA402FFFE5BFEFFFFFFFF00000001,
+        5,
+        [2, 3, 11, 19, 10177, 125527, 859267, 906349, 2508409, 2529403, 52437899, 254760293],
+    ),
+    "bn254": (
+        0x30644E72E131A029B85045B68181585D2833E84879B9709143E1F593F0000001,
+        5,
+        [2, 3, 13, 29, 983, 11003, 237073, 405928799, 1670836401704629, 13818364434197438864469338081],
+    ),
+    "bls12_377": (
+        0x12AB655E9A2CA55660B44D1E5C37B00159AA76FED00000010A11800000000001,
+        11,
+        [2, 3, 5, 7, 13, 499, 958612291309063373, 9586122913090633729],
+    ),
+    "pallas": (
+        0x40000000000000000000000000000000224698FC0994A8DD8C46EB2100000001,
+        5,
+        [2, 3, 1709, 24859, 1690502597179744445941507, 10427374428728808478656897599072717],
+    ),
+    "vesta": (
+        0x40000000000000000000000000000000224698FC094CF91B992D30ED00000001,
+        5,
+        [2, 3, 463, 539204044132271846773, 8999194758858563409123804352480028797519453],
+    ),
+}
+
+# The bundled files which weren't generated by this script
+KEPT = {("bls12_381", "neptune"), ("bls12_381", "rescue_prime")}
+
+# Small fields, with the width of the Rescue Prime state, which is the only primitive generated for them.
+# As the sponge has a rate of 1, the capacity must hold 2 * S bits (4 elements for a 64-bit prime).
+SMALL_FIELDS = {
+    "goldilocks": (2 ** 64 - 2 ** 32 + 1, 7, [2, 3, 5, 17, 257, 65537], 5),
+}
+
+
+class Shake:
+    """Reads a SHAKE stream incrementally, as done by Crypto.Hash in parameters.sage"""
+
+    def __init__(self, name, data):
+        self.hash = hashlib.new(name, data)
+        self.buffer = b""
+        self.position = 0
+
+    def read(self, n):
+        while self.position + n > len(self.buffer):
+            self.buffer = self.hash.digest(max(2 * len(self.buffer), 4096))
+        out = self.buffer[self.position:self.position + n]
+        self.position += n
+        return out
+
+
+def get_random_element(p, shake, rejection=False):
+    size = ceil(len(bin(p)[2:]) / 8) + 1
+    val = int.from_bytes(shake.read(size), "little")
+    while rejection and val >= p:
+        val = int.from_bytes(shake.read(size), "little")
+    return val % p
+
+
+def get_n_random_elements(p, n, shake, rejection=False):
+    return [get_random_element(p, shake, rejection) for _ in range(n)]
+
+
+def seed(p):
+    # The 64-bit limbs of p, as given to SHAKE in parameters.sage
+    return p.to_bytes(8 * ceil(p.bit_length() / 64), "little")
+
+
+def is_non_residue(x, p):
+    return pow(x, (p - 1) // 2, p) == p - 1
+
+
+def get_inverse(x, p):
+    return pow(x, -1, p - 1)
+
+
+def log2_binomial(n, k):
+    return sum(log2(n - i) - log2(i + 1) for i in range(k))
+
+
+### ----- Number of rounds (see common::rounds) ----- ###
+def get_nb_rounds_griffin(d, t, s):
+    r = next((r for r in range(1, 25)
+              if 2 * min(log2_binomial(r * (d + t) + 1, 1 + t * r),
+                         log2_binomial(d ** r + 1 + r, 1 + r)) >= s), 25)
+    return ceil(1.2 * max(6, 1 + r))
+
+
+def get_nb_rounds_neptune(d, p, t, s):
+    ri_p_1 = (min(s, p.bit_length()) - 6) / log2(d) + 3 + t + log2(t) / log2(d)
+    ri_p_2 = s / 2 - 4 * t - 2
+    return 6, ceil(1.125 * max(ri_p_1, ri_p_2))
+
+
+def get_number_of_rounds_rescue(m, c, s, d):
+    r = m - c
+    def dcon(n): return int(0.5 * (d - 1) * m * (n - 1) + 2)
+    def v(n): return m * (n - 1) + r
+    l1 = next((n for n in range(1, 25) if 2 * log2_binomial(v(n) + dcon(n), v(n)) > s), 25)
+    return ceil(1.5 * max(5, l1))
+
+
+def get_nb_rounds_ciminion(p, s):
+    # The paper requires 2^s < p. pE uses the conservative number of rounds, 1.5 times the
+    # standard max(6, ceil((s + 37) / 12)) rounds of common::rounds::ciminion_rounds
+    assert s < p.bit_length()
+    return s + 6, ceil(1.5 * max(6, ceil((s + 37) / 12)))
+
+
+### ----- Constants ----- ###
+def get_params_griffin(p, m, n):
+    shake = Shake("shake128", b"Griffin" + seed(p))
+    # There are no constants in the last round
+    consts = get_n_random_elements(p, (n - 1) * m, shake)
+    while True:
+        alpha, beta = get_random_element(p, shake), get_random_element(p, shake)
+        if is_non_residue((alpha * alpha - 4 * beta) % p, p):
+            return alpha, beta, consts
+
+
+def get_round_constants_neptune(p, m, n):
+    shake = Shake("shake128", b"Neptune" + seed(p))
+    consts = get_n_random_elements(p, n * m, shake)
+    gamma = get_random_element(p, shake)
+    int_matrix = get_n_random_elements(p, m, shake)
+    return consts, gamma, int_matrix
+
+
+def get_round_constants_rescue(p, m, capacity, security_level, n):
+    shake = Shake("shake256", bytes("Rescue-XLIX (%i,%i,%i,%i)" % (p, m, capacity, security_level), "ascii"))
+    # Two steps per round
+    return get_n_random_elements(p, 2 * m * n, shake)
+
+
+def get_round_constants_ciminion(p, n):
+    shake = Shake("shake256", bytes(f"GF({p})", "ascii"))
+    return get_n_random_elements(p, 4 * n, shake, True)
+
+
+def get_primitive_element(p, factors):
+    assert all((p - 1) % q == 0 for q in factors)
+    g = 2
+    while any(pow(g, (p - 1) // q, p) == 1 for q in factors):
+        g += 1
+    return g
+
+
+def get_mds_matrix(p, m, factors):
+    # Systematic generator matrix of a Reed-Solomon code, as in parameters.sage
+    g = get_primitive_element(p, factors)
+    V = [[pow(g, i * j, p) for j in range(2 * m)] for i in range(m)]
+
+    # Reduced row echelon form
+    for col in range(m):
+        pivot = next(row for row in range(col, m) if V[row][col] != 0)
+        V[col], V[pivot] = V[pivot], V[col]
+        inv = pow(V[col][col], -1, p)
+        V[col] = [x * inv % p for x in V[col]]
+        for row in range(m):
+            if row != col and V[row][col] != 0:
+                f = V[row][col]
+                V[row] = [(x - f * y) % p for x, y in zip(V[row], V[col])]
+
+    # The MDS matrix is the transpose of the right half of this matrix
+    return [[V[i][m + j] for i in range(m)] for j in range(m)]
+
+
+### ----- Parameter files ----- ###
+def write_params_file(field, primitive, p, width, rounds, constants):
+    if (field, primitive) in KEPT:
+        return
+
+    def encode(v):
+        return [hex(x) for x in v] if isinstance(v, list) else (str(v) if v < 2 ** 16 else hex(v))
+
+    os.makedirs(f"params/{field}", exist_ok=True)
+    content = {
+        "primitive": primitive,
+        "field_modulus": hex(p),
+        "width": width,
+        "rounds": rounds,
+        "constants": {name: encode(v) for name, v in constants.items()},
+    }
+    with open(f"params/{field}/{primitive}.json", "w") as f:
+        f.write(json.dumps(content, indent=4) + "\n")
+
+
+def write_rescue_prime(field, p, d, factors, m):
+    # The rate is 1, the rest of the state is the capacity
+    c = m - 1
+    n = get_number_of_rounds_rescue(m, c, S, d)
+    mds = get_mds_matrix(p, m, factors)
+    write_params_file(field, "rescue_prime", p, m, {"rounds": n}, {
+        "alpha": d, "alpha_inv": get_inverse(d, p), "mds": [v for row in mds for v in row],
+        "round_constants": get_round_constants_rescue(p, m, c, S, n),
+    })
+
+
+def write_field(field, p, d, factors):
+    d_inv = get_inverse(d, p)
+
+    ### ----- Griffin ----- ###
+    m = 3
+    n = get_nb_rounds_griffin(d, m, S)
+    alpha, beta, consts = get_params_griffin(p, m, n)
+    write_params_file(field, "griffin", p, m, {"rounds": n}, {
+        "d": d, "d_inv": d_inv, "alpha": alpha, "beta": beta, "round_constants": consts,
+    })
+
+    ### ----- Neptune ----- ###
+    m = 4
+    re, ri = get_nb_rounds_neptune(d, p, m, S)
+    consts, gamma, matrix = get_round_constants_neptune(p, m, re + ri)
+    write_params_file(field, "neptune", p, m, {
+        "external_begin": re // 2, "internal": ri, "external_end": re - re // 2,
+    }, {"d": d, "gamma": gamma, "matrix_int": matrix, "round_constants": consts})
+
+    ### ----- Rescue Prime ----- ###
+    write_rescue_prime(field, p, d, factors, 3)
+
+    ### ----- Ciminion ----- ###
+    pc, pe = get_nb_rounds_ciminion(p, S)
+    write_params_file(field, "ciminion", p, 3, {"pc": pc, "pe": pe}, {
+        "round_constants": get_round_constants_ciminion(p, pc),
+    })
+
+
+if __name__ == "__main__":
+    for field in sys.argv[1:] or [*FIELDS, *SMALL_FIELDS]:
+        if field in SMALL_FIELDS:
+            p, d, factors, m = SMALL_FIELDS[field]
+            write_rescue_prime(field, p, d, factors, m)
+        else:
+            write_field(field, *FIELDS[field])
@@ -0,0 +1,92 @@
+# zekrom-arkworks
+# Copyright (C) 2023
+#
+# This program is free software: you can redistribute it and/or modify
+# it under the terms of the GNU General Public License as published by
+# the Free Software Foundation, either version 3 of the License, or
+# (at your option) any later version.
+#
+# This program is distributed in the hope that it will be useful,
+# but WITHOUT ANY WARRANTY; without even the implied warranty of
+# MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+# GNU General Public License for more details.
+#
+# You should have received a copy of the GNU General Public License
+# along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+
+# A plain Python model of Rescue Prime and of its sponge hash, used to compute the known-answer
+# vectors of rescue_prime::tests on the bundled parameter files. It only shares the parameter
+# files with the Rust implementation, and only needs the standard library.
+#
+# Usage: python3 reference.py [field ...]   (all the bundled fields by default)
+
+import json
+import os
+import sys
+
+# A large 128-bit prime, the base of the hasher of common::pattern
+HASHER_BASE = 2 ** 128 - 159
+MASK = 2 ** 128 - 1
+
+
+def load(field):
+    with open(f"params/{field}/rescue_prime.json") as f:
+        content = json.load(f)
+    constants = content["constants"]
+    return {
+        "p": int(content["field_modulus"], 0),
+        "width": content["width"],
+        "rounds": content["rounds"]["rounds"],
+        "alpha": int(constants["alpha"], 0),
+        "alpha_inv": int(constants["alpha_inv"], 0),
+        "mds": [int(x, 0) for x in constants["mds"]],
+        "round_constants": [int(x, 0) for x in constants["round_constants"]],
+    }
+
+
+def linear_layer(state, mds, constants, p):
+    m = len(state)
+    # The product by the MDS matrix of the Rescue Prime specification
+    state[:] = [(constants[j] + sum(x * mds[j * m + k] for k, x in enumerate(state))) % p
+                for j in range(m)]
+
+
+def permute(params, state):
+    p, m = params["p"], params["width"]
+    rc = params["round_constants"]
+    for i in range(params["rounds"]):
+        state[:] = [pow(x, params["alpha"], p) for x in state]
+        linear_layer(state, params["mds"], rc[2 * m * i:(2 * i + 1) * m], p)
+        state[:] = [pow(x, params["alpha_inv"], p) for x in state]
+        linear_layer(state, params["mds"], rc[(2 * i + 1) * m:2 * m * (i + 1)], p)
+
+
+def tag(ops, domain_separator=0):
+    # The tag of an IO pattern, ops is a list of ("absorb" | "squeeze", count) already coalesced
+    x_i, state = 1, 0
+    for op, count in ops + [(None, domain_separator)]:
+        value = count + 2 ** 31 if op == "absorb" else count
+        x_i = x_i * HASHER_BASE & MASK
+        state = state + x_i * value & MASK
+    return state
+
+
+def hash(params, message):
+    # The rate is 1 and the tag is in the first element of the capacity (rescue_prime::primitives)
+    p = params["p"]
+    state = [0] * params["width"]
+    state[1] = tag([("absorb", len(message)), ("squeeze", 1)]) % p
+    for element in message:
+        state[0] = (state[0] + element) % p
+        permute(params, state)
+    return state[0]
+
+
+if __name__ == "__main__":
+    for field in sys.argv[1:] or sorted(os.listdir("params")):
+        params = load(field)
+        p = params["p"]
+        print(f"{field}:")
+        for message in ([1, 2, 3], [0], [p - 1, 2 ** 32, 7]):
+            print(f"    hash({message}) = {hash(params, message)}")
@@ -0,0 +1,171 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Authenticated encryption with associated data over the SAFE sponges
+//!
+//! The key and the nonce are absorbed, then the associated data, whose length is bound into
+//! the tag of the IOPattern (see `gen_aead_pattern`). Each element of the message is added to
+//! a squeezed element and absorbed, the last squeezed element is the tag. The associated data
+//! stays in the clear but is authenticated by the tag. Without associated data, this is the
+//! AE of the Griffin and Neptune circuits.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{Boolean, EqGadget},
+    R1CSVar,
+};
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{
+    api::{constants, ChipAPI, Sponge, SpongeAPI},
+    common::pattern::gen_aead_pattern,
+};
+
+pub mod tests;
+
+/// Starts the sponge and absorbs the key, the nonce and the associated data
+fn start<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    nonce: &FpVar<F>,
+    ad: &[FpVar<F>],
+    m_len: usize,
+) -> Sponge<C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let mut sponge = sponge.clone();
+
+    sponge.start(gen_aead_pattern(m_len, 1, 1, ad.len()), None);
+    sponge.absorb(1, std::slice::from_ref(key));
+    sponge.absorb(1, std::slice::from_ref(nonce));
+    if !ad.is_empty() {
+        sponge.absorb(ad.len() as u32, ad);
+    }
+
+    sponge
+}
+
+/// Encrypts a message, the ciphertext is followed by the tag
+///
+/// It works both natively (on constants) and in a circuit.
+pub fn encrypt<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    nonce: &FpVar<F>,
+    ad: &[FpVar<F>],
+    message: &[FpVar<F>],
+) -> Result<Vec<FpVar<F>>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let mut sponge = start(sponge, key, nonce, ad, message.len());
+    let mut ciphertext = Vec::with_capacity(message.len() + 1);
+
+    for block in message {
+        ciphertext.push(sponge.squeeze(1)[0].clone() + block);
+        sponge.absorb(1, std::slice::from_ref(block));
+    }
+    ciphertext.push(sponge.squeeze(1)[0].clone());
+
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(ciphertext)
+}
+
+/// Decrypts a ciphertext followed by its tag, returns the message and whether the tag is valid
+pub fn decrypt<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    nonce: &FpVar<F>,
+    ad: &[FpVar<F>],
+    ciphertext: &[FpVar<F>],
+) -> Result<(Vec<FpVar<F>>, Boolean<F>), SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (tag, ciphertext) = ciphertext
+        .split_last()
+        .ok_or(SynthesisError::Unsatisfiable)?;
+
+    let mut sponge = start(sponge, key, nonce, ad, ciphertext.len());
+    let mut message = Vec::with_capacity(ciphertext.len());
+
+    for block in ciphertext {
+        let element = block - sponge.squeeze(1)[0].clone();
+        sponge.absorb(1, std::slice::from_ref(&element));
+        message.push(element);
+    }
+    let is_valid = sponge.squeeze(1)[0].is_eq(tag)?;
+
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok((message, is_valid))
+}
+
+/// Encrypts natively, the ciphertext is followed by the tag
+pub fn encrypt_native<F, C>(
+    sponge: &Sponge<C>,
+    key: F,
+    nonce: F,
+    ad: &[F],
+    message: &[F],
+) -> Result<Vec<F>, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let ciphertext = encrypt(
+        sponge,
+        &FpVar::Constant(key),
+        &FpVar::Constant(nonce),
+        &constants(ad),
+        &constants(message),
+    )?;
+
+    Ok(ciphertext.value()?)
+}
+
+/// Decrypts natively a ciphertext followed by its tag, fails if the tag is invalid
+pub fn decrypt_native<F, C>(
+    sponge: &Sponge<C>,
+    key: F,
+    nonce: F,
+    ad: &[F],
+    ciphertext: &[F],
+) -> Result<Vec<F>, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (message, is_valid) = decrypt(
+        sponge,
+        &FpVar::Constant(key),
+        &FpVar::Constant(nonce),
+        &constants(ad),
+        &constants(ciphertext),
+    )?;
+
+    if !is_valid.value()? {
+        return Err(anyhow::Error::msg("Invalid tag"));
+    }
+    Ok(message.value()?)
+}
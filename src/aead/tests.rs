@@ -0,0 +1,216 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::Fr as BlsFr;
+use ark_bn254::{Bn254, Fr as BnFr};
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_snark::SNARK;
+
+use super::{decrypt, decrypt_native, encrypt_native};
+use crate::{
+    api::{ChipAPI, Sponge},
+    common::test_utils::is_satisfied,
+    griffin::{
+        self, ae_circuit::GriffinAEADCircuit, chip::GriffinChip, primitives::GriffinPrimitives,
+    },
+    neptune::{
+        self, ae_circuit::NeptuneAEADCircuit, chip::NeptuneChip, primitives::NeptunePrimitives,
+    },
+};
+
+fn griffin<F: PrimeField>() -> Sponge<GriffinChip<F>> {
+    Sponge::<GriffinChip<F>>::new(
+        GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn neptune<F: PrimeField>() -> Sponge<NeptuneChip<F>> {
+    Sponge::<NeptuneChip<F>>::new(
+        NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn random<F: PrimeField>(len: usize) -> Vec<F> {
+    let rng = &mut ark_std::test_rng();
+    (0..len).map(|_| F::rand(rng)).collect()
+}
+
+/// Encrypts and decrypts messages of several lengths, and checks that any change is detected
+fn round_trip<F, C>(sponge: &Sponge<C>)
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (key, nonce) = (F::from(3u64), F::from(7u64));
+    let one = F::from(1u64);
+
+    for (ad_len, message_len) in [(0, 0), (0, 1), (1, 0), (2, 5), (7, 3)] {
+        let ad = random::<F>(ad_len);
+        let message = random::<F>(message_len);
+
+        let ciphertext = encrypt_native(sponge, key, nonce, &ad, &message).unwrap();
+        assert_eq!(ciphertext.len(), message.len() + 1);
+        assert_eq!(
+            decrypt_native(sponge, key, nonce, &ad, &ciphertext).unwrap(),
+            message
+        );
+
+        assert!(decrypt_native(sponge, key + one, nonce, &ad, &ciphertext).is_err());
+        assert!(decrypt_native(sponge, key, nonce + one, &ad, &ciphertext).is_err());
+        // The length of the associated data is bound, even a single zero is detected
+        let mut other = ad.clone();
+        match other.last_mut() {
+            Some(x) => *x += one,
+            None => other.push(F::zero()),
+        }
+        assert!(decrypt_native(sponge, key, nonce, &other, &ciphertext).is_err());
+        for i in 0..ciphertext.len() {
+            let mut invalid = ciphertext.clone();
+            invalid[i] += one;
+            assert!(decrypt_native(sponge, key, nonce, &ad, &invalid).is_err());
+        }
+    }
+    assert!(decrypt_native(sponge, key, nonce, &[], &[]).is_err());
+}
+
+#[test]
+fn aead_bls() {
+    round_trip(&griffin::<BlsFr>());
+    round_trip(&neptune::<BlsFr>());
+}
+
+#[test]
+fn aead_bn() {
+    round_trip(&griffin::<BnFr>());
+    round_trip(&neptune::<BnFr>());
+}
+
+#[test]
+fn empty_ad_is_ae() {
+    let message = random::<BlsFr>(4);
+    let (key, nonce) = (BlsFr::from(3u64), BlsFr::from(7u64));
+
+    let ae = GriffinPrimitives::<BlsFr>::ae(message.clone(), key, nonce)
+        .unwrap()
+        .value()
+        .unwrap();
+    let aead = encrypt_native(&griffin(), key, nonce, &[], &message).unwrap();
+    assert_eq!(ae, aead);
+
+    let ae = NeptunePrimitives::<BlsFr>::ae(message.clone(), key, nonce)
+        .unwrap()
+        .value()
+        .unwrap();
+    let aead = encrypt_native(&neptune(), key, nonce, &[], &message).unwrap();
+    assert_eq!(ae, aead);
+}
+
+#[test]
+fn aead_gadget() {
+    let sponge = neptune::<BlsFr>();
+    let (key, nonce, ad, message) = (random(1), random(1), random(2), random(3));
+    let ciphertext = encrypt_native(&sponge, key[0], nonce[0], &ad, &message).unwrap();
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let witness = |x: &Vec<BlsFr>| Vec::new_witness(cs.clone(), || Ok(x.clone())).unwrap();
+    let (decrypted, is_valid) = decrypt(
+        &sponge,
+        &witness(&key)[0],
+        &witness(&nonce)[0],
+        &witness(&ad),
+        &witness(&ciphertext),
+    )
+    .unwrap();
+    assert_eq!(decrypted.value().unwrap(), message);
+    assert!(is_valid.value().unwrap());
+
+    let (_, is_valid) = decrypt(
+        &sponge,
+        &witness(&key)[0],
+        &witness(&nonce)[0],
+        &witness(&ad[..1].to_vec()),
+        &witness(&ciphertext),
+    )
+    .unwrap();
+    assert!(!is_valid.value().unwrap());
+    assert!(cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn aead_circuits() {
+    let (key, nonce, ad, message) = (BnFr::from(3u64), BnFr::from(7u64), random(3), random(4));
+
+    let circuit = GriffinAEADCircuit {
+        sponge: griffin(),
+        ciphertext: encrypt_native(&griffin(), key, nonce, &ad, &message).unwrap(),
+        message: message.clone(),
+        key,
+        nonce,
+        ad: ad.clone(),
+    };
+    assert!(is_satisfied(circuit.clone()));
+    let mut invalid = circuit;
+    invalid.ad.pop();
+    assert!(!is_satisfied(invalid));
+
+    let circuit = NeptuneAEADCircuit {
+        sponge: neptune(),
+        ciphertext: encrypt_native(&neptune(), key, nonce, &ad, &message).unwrap(),
+        message,
+        key,
+        nonce,
+        ad,
+    };
+    assert!(is_satisfied(circuit.clone()));
+    let mut invalid = circuit;
+    invalid.ad[0] += BnFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_aead_bn254() {
+    let rng = &mut ark_std::test_rng();
+    let (key, nonce, ad, message) = (BnFr::rand(rng), BnFr::rand(rng), random(2), random(3));
+    let circuit = NeptuneAEADCircuit {
+        sponge: neptune(),
+        ciphertext: encrypt_native(&neptune(), key, nonce, &ad, &message).unwrap(),
+        message,
+        key,
+        nonce,
+        ad,
+    };
+    let inputs = [
+        circuit.ciphertext.clone(),
+        vec![circuit.nonce],
+        circuit.ad.clone(),
+    ]
+    .concat();
+
+    type GrothSetup = Groth16<Bn254>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &inputs, &proof).unwrap());
+    let mut invalid = inputs;
+    invalid[5] += BnFr::from(1u64);
+    assert!(!GrothSetup::verify(&vk, &invalid, &proof).unwrap());
+}
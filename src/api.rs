@@ -18,6 +18,11 @@
 //! Mostly based on "SAFE (Sponge API for Field Elements) – A Toolbox for ZK Hash Applications" - https://hackmd.io/bHgsH6mMStCVibM_wYvb2w
 //! Code inspiration by the reference implementation : https://github.com/filecoin-project/neptune/tree/master/src/sponge
 
+use std::ops::AddAssign;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+
 use crate::common::pattern::*;
 
 /// This trait define the API from [SAFE (Sponge API for Field Elements)](https://hackmd.io/bHgsH6mMStCVibM_wYvb2w)
@@ -31,23 +36,48 @@ pub trait SpongeAPI {
     fn finish(&mut self) -> Result<(), anyhow::Error>;
 }
 
-/// This trait defines the necessary functions inside a chip implementation.
-/// It allows the SpongeAPI to use the chip transparently, and leaves the
-/// details of each construction to the developer.
-pub trait ChipAPI {
-    type Value;
-
-    /// This method initializes the state of the chip
+/// A permutation of a state owned by the caller
+///
+/// Every chip implements it natively (on field elements) and as a gadget (on `FpVar`), the
+/// modes (the sponge, the compression, ...) are built on top of it without touching the chips.
+/// Ciminion has two permutations, given by `CiminionChip::pc_permutation` and `pe_permutation`.
+pub trait Permutation<V> {
+    /// The number of elements of the state
     ///
-    /// It can take a tag to initialize the capacity with a value x
-    /// It can also be called with 0 to reset the state to an array of M 0s
-    fn init(&mut self, tag: u128) -> Result<(), anyhow::Error>;
+    /// It isn't a constant as the width of some primitives (Rescue Prime) is a parameter.
+    fn width(&self) -> usize;
+
+    /// Permutes the state in place, it must have `width()` elements
+    fn permute(&self, state: &mut [V]) -> Result<(), anyhow::Error>;
+}
 
-    fn read(&mut self) -> Self::Value;
+/// This trait defines what the sponge needs from a chip.
+/// The chip is the permutation, while the sponge holds the state and the logic of SAFE,
+/// which leaves the details of each construction to the developer.
+pub trait ChipAPI: Permutation<Self::Value> {
+    type Value: Clone + for<'a> AddAssign<&'a Self::Value>;
 
-    fn add(&mut self, val: &Self::Value) -> Result<(), anyhow::Error>;
+    /// The element of value x, used to initialize the state
+    fn constant(x: u128) -> Self::Value;
+}
+
+/// Applies the permutation gadget of a chip to field elements
+pub fn permute_native<F, P>(permutation: &P, state: &mut [F]) -> Result<(), anyhow::Error>
+where
+    F: PrimeField,
+    P: Permutation<FpVar<F>>,
+{
+    let mut vars = constants(state);
+    permutation.permute(&mut vars)?;
+    for (x, var) in state.iter_mut().zip(vars.iter()) {
+        *x = var.value()?;
+    }
+    Ok(())
+}
 
-    fn permutation(&mut self) -> Result<(), anyhow::Error>;
+/// Turns field elements into constants, to run a gadget natively
+pub fn constants<F: PrimeField>(elements: &[F]) -> Vec<FpVar<F>> {
+    elements.iter().map(|x| FpVar::Constant(*x)).collect()
 }
 
 /// This struct defines the basics of a sponge
@@ -61,6 +91,40 @@ pub struct Sponge<C: ChipAPI> {
     pub squeeze_pos: usize,
     pub op_count: usize,
     pub pattern: IOPattern,
+    pub state: Vec<C::Value>,
+    /// The first error of the permutation, returned by `finish`
+    pub error: Option<String>,
+}
+
+impl<C: ChipAPI> Sponge<C> {
+    /// Creates a sponge over any chip, the rest of the state after the rate is the capacity
+    ///
+    /// It panics if the rate doesn't leave any capacity, see `try_with_rate`.
+    pub fn with_rate(chip: C, rate: usize) -> Self {
+        Self::try_with_rate(chip, rate).expect("The rate must leave some capacity")
+    }
+
+    /// Creates a sponge over any chip, or fails if the rate isn't in 1..width
+    pub fn try_with_rate(chip: C, rate: usize) -> Result<Self, anyhow::Error> {
+        if rate == 0 || rate >= chip.width() {
+            return Err(anyhow::Error::msg(format!(
+                "The rate must be between 1 and {}, got {}",
+                chip.width() - 1,
+                rate
+            )));
+        }
+
+        Ok(Self {
+            state: vec![C::constant(0); chip.width()],
+            chip,
+            absord_pos: 0,
+            squeeze_pos: 0,
+            op_count: 0,
+            pattern: IOPattern::new(vec![]),
+            rate,
+            error: None,
+        })
+    }
 }
 
 /// This trait defines an interface between the sponge and the chip.
@@ -74,7 +138,7 @@ pub trait InnerSpongeAPI {
     // Methods based on the paper recommendation
     fn initialize_capacity(&mut self, tag: u128);
     fn read_rate_element(&mut self, offset: usize) -> Self::Value;
-    fn permute(&mut self);
+    fn permute(&mut self) -> Result<(), anyhow::Error>;
 
     // Some additions based on the reference implementation
     fn rate(&self) -> usize;
@@ -87,32 +151,31 @@ pub trait InnerSpongeAPI {
 
     fn increment_io_count(&mut self) -> usize;
 
+    /// Keeps the first error of the permutation, to be returned by `finish`
+    fn set_error(&mut self, error: anyhow::Error);
+    fn take_error(&mut self) -> Option<anyhow::Error>;
+
     fn add_element_to_rate_at(&mut self, offset: usize, x: &Self::Value);
 }
 
 impl<C: ChipAPI> InnerSpongeAPI for Sponge<C> {
     type Value = C::Value;
 
-    /// Call the init function of a chip, provides the tag
+    /// Resets the state, the tag is set in the first element of the capacity
     fn initialize_capacity(&mut self, tag: u128) {
-        let res = self.chip.init(tag);
-        // println!("{:?}", res);
-        assert!(res.is_ok(), "init failed") // todo : clean this
+        self.state = vec![C::constant(0); self.chip.width()];
+        self.state[self.rate] = C::constant(tag);
     }
 
     /// Read the rate at said offset
     fn read_rate_element(&mut self, offset: usize) -> Self::Value {
         assert!(offset < self.rate, "Offset outside of rate!");
-        self.chip.read()
+        self.state[offset].clone()
     }
 
-    /// Ask the chip to permute its internal state
-    fn permute(&mut self) {
-        let res = self.chip.permutation();
-        if res.is_err() {
-            println!("{:?}", res);
-        }
-        assert!(res.is_ok(), "The permutation didn't complete correctly")
+    /// Ask the chip to permute the state
+    fn permute(&mut self) -> Result<(), anyhow::Error> {
+        self.chip.permute(&mut self.state)
     }
 
     fn rate(&self) -> usize {
@@ -149,11 +212,18 @@ impl<C: ChipAPI> InnerSpongeAPI for Sponge<C> {
         self.op_count - 1
     }
 
-    /// Ask the chip to add an element to the rate at the specified offset
+    fn set_error(&mut self, error: anyhow::Error) {
+        self.error.get_or_insert_with(|| format!("{:#}", error));
+    }
+
+    fn take_error(&mut self) -> Option<anyhow::Error> {
+        self.error.take().map(anyhow::Error::msg)
+    }
+
+    /// Add an element to the rate at the specified offset
     fn add_element_to_rate_at(&mut self, offset: usize, x: &Self::Value) {
-        assert_eq!(offset, 0, "Offset outside of rate!");
-        let res = self.chip.add(x);
-        assert!(res.is_ok(), "Add failed"); // todo : clean
+        assert!(offset < self.rate, "Offset outside of rate!");
+        self.state[offset] += x;
     }
 }
 
@@ -168,6 +238,7 @@ impl<S: InnerSpongeAPI> SpongeAPI for S {
 
         self.set_pattern(pattern);
         self.initialize_capacity(tag);
+        self.take_error();
 
         self.set_absorb_pos(0);
         self.set_squeeze_pos(0);
@@ -183,7 +254,9 @@ impl<S: InnerSpongeAPI> SpongeAPI for S {
 
         for element in elements.iter() {
             if self.absorb_pos() == rate {
-                self.permute();
+                if let Err(error) = self.permute() {
+                    self.set_error(error);
+                }
                 self.set_absorb_pos(0);
             }
             self.add_element_to_rate_at(self.absorb_pos(), element);
@@ -204,7 +277,9 @@ impl<S: InnerSpongeAPI> SpongeAPI for S {
 
         for _ in 0..length {
             if self.squeeze_pos() == rate {
-                self.permute();
+                if let Err(error) = self.permute() {
+                    self.set_error(error);
+                }
                 self.set_squeeze_pos(0);
                 self.set_absorb_pos(0);
             }
@@ -222,8 +297,12 @@ impl<S: InnerSpongeAPI> SpongeAPI for S {
     ///
     /// It will reset the internal state, (todo) attempt to erase it from memory
     /// and assert that the correct number of calls were made.
+    /// It returns the first error of the permutation, if any.
     fn finish(&mut self) -> Result<(), anyhow::Error> {
         self.initialize_capacity(0);
+        if let Some(error) = self.take_error() {
+            return Err(error);
+        }
         let final_io_count = self.increment_io_count();
         if final_io_count == self.pattern().len() {
             Ok(())
@@ -18,10 +18,22 @@
 use std::iter::zip;
 
 use ark_ff::PrimeField;
-use ark_r1cs_std::{fields::fp::FpVar, prelude::FieldVar};
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{Boolean, EqGadget, FieldVar},
+};
 use ark_relations::r1cs::SynthesisError;
 
-use ark_r1cs_std::R1CSVar;
+use super::params::STATE_SIZE;
+use crate::{
+    api::{permute_native, ChipAPI, Permutation, Sponge},
+    common::{
+        params_file::ParameterFile,
+        rounds::{ciminion_rounds, SECURITY_LEVEL},
+        validation::{check_count, value},
+    },
+    crh,
+};
 
 #[derive(Clone)]
 pub struct CiminionParameters<F: PrimeField> {
@@ -30,6 +42,58 @@ pub struct CiminionParameters<F: PrimeField> {
     pub round_constants: Vec<FpVar<F>>,
 }
 
+impl<F: PrimeField> CiminionParameters<F> {
+    /// Loads the parameters from a parameter file over F
+    ///
+    /// It expects the `pc` and `pe` numbers of rounds and the `round_constants` constants.
+    pub fn from_file(file: &ParameterFile) -> Result<Self, anyhow::Error> {
+        file.check::<F>("ciminion", STATE_SIZE)?;
+
+        Ok(Self {
+            nb_rounds_pe: file.rounds("pe")?,
+            nb_rounds_pc: file.rounds("pc")?,
+            round_constants: file.constants("round_constants")?,
+        })
+    }
+
+    /// Checks that the parameters define valid Ciminion permutations
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        check_count(
+            "round constants",
+            self.round_constants.len(),
+            4 * self.nb_rounds_pc,
+        )?;
+
+        // pE uses the constants of the last rounds of pC
+        if self.nb_rounds_pe > self.nb_rounds_pc {
+            return Err(anyhow::Error::msg("pE can't have more rounds than pC"));
+        }
+
+        // The constant multiplying the state in each round must differ from 0 and 1
+        for rc in self.round_constants.iter().skip(3).step_by(4) {
+            let rc = value(rc)?;
+            if rc.is_zero() || rc.is_one() {
+                return Err(anyhow::Error::msg(
+                    "The round constants RC4 can't be equal to 0 or 1",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Checks that the number of rounds reaches the recommended one for a security level (in bits)
+    pub fn check_rounds(&self, security_level: usize) -> Result<(), anyhow::Error> {
+        let (pc, pe) = ciminion_rounds::<F>(security_level)?;
+        if self.nb_rounds_pc < pc || self.nb_rounds_pe < pe {
+            return Err(anyhow::Error::msg(format!(
+                "Ciminion uses {} rounds for pC and {} for pE, {} and {} are recommended for {} bits of security",
+                self.nb_rounds_pc, self.nb_rounds_pe, pc, pe, security_level
+            )));
+        }
+        Ok(())
+    }
+}
+
 #[derive(Clone)]
 pub struct CiminionChip<F: PrimeField> {
     parameters: CiminionParameters<F>,
@@ -37,28 +101,106 @@ pub struct CiminionChip<F: PrimeField> {
 }
 
 impl<F: PrimeField> CiminionChip<F> {
+    /// Creates a chip, panics if the parameters are invalid
+    ///
+    /// Parameters with fewer rounds than recommended are accepted, with a warning logged through
+    /// the `log` crate
     pub fn new(parameters: CiminionParameters<F>) -> Self {
+        if let Err(e) = parameters.validate() {
+            panic!("Invalid Ciminion parameters: {}", e);
+        }
+        if let Err(e) = parameters.check_rounds(SECURITY_LEVEL) {
+            log::warn!("{}", e);
+        }
+
         Self {
             parameters,
             keys: vec![],
         }
     }
 
+    /// Creates a chip, rejecting invalid parameters and those below 128 bits of security
+    pub fn try_new(parameters: CiminionParameters<F>) -> Result<Self, anyhow::Error> {
+        parameters.validate()?;
+        parameters.check_rounds(SECURITY_LEVEL)?;
+
+        Ok(Self {
+            parameters,
+            keys: vec![],
+        })
+    }
+
+    /// The pC permutation of the chip, applied to a state owned by the caller
+    pub fn pc_permutation(&self) -> CiminionPC<F> {
+        CiminionPC {
+            parameters: self.parameters.clone(),
+        }
+    }
+
+    /// The pE permutation of the chip, applied to a state owned by the caller
+    pub fn pe_permutation(&self) -> CiminionPE<F> {
+        CiminionPE {
+            parameters: self.parameters.clone(),
+        }
+    }
+
     /// This inits an existing CiminionChip to perform encryption from the master keys MK1 and MK2.
     /// The parameter max_len defines the maximum length of a message that can be encrypted
     /// (it defines how many subkeys are generated, a costly process we want to run only once).
     /// By design, max_len shouldn't be odd, as N and N-1 use the same number of keys for any even N
+    /// The subkeys of a length are a prefix of those of a longer one, so a chip initialized once
+    /// encrypts any message up to max_len as a chip initialized with its length would.
     pub fn init(&mut self, mk1: FpVar<F>, mk2: FpVar<F>, max_len: usize) {
         self.keys = self.gen_keys(mk1, mk2, max_len)
     }
 
+    /// Inits the chip with subkeys given directly, e.g. witnessed, instead of deriving them
+    ///
+    /// This skips the `pc` permutation of each subkey, the subkeys are then expected to be bound
+    /// to a commitment (see `commit_keys`) checked once against the master keys.
+    /// A schedule for messages up to max_len has `max_len + 3` subkeys (rounded up to odd).
+    pub fn init_with_subkeys(&mut self, keys: Vec<FpVar<F>>) -> Result<(), anyhow::Error> {
+        if keys.len() < number_keys(0) || keys.len().is_multiple_of(2) {
+            return Err(anyhow::Error::msg(format!(
+                "A Ciminion key schedule can't have {} subkeys",
+                keys.len()
+            )));
+        }
+        self.keys = keys;
+        Ok(())
+    }
+
+    /// Commits to the subkeys the chip was initialized with, by hashing them with a sponge
+    ///
+    /// A sponge at full rate (`Sponge::with_rate`) absorbs several subkeys per permutation.
+    pub fn commit_keys<C>(&self, sponge: &Sponge<C>) -> Result<FpVar<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        crh::hash(sponge, &self.keys)
+    }
+
     pub fn ae(
         &self,
         message: &[FpVar<F>],
         nonce: FpVar<F>,
     ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        self.aead(message, nonce, &[])
+    }
+
+    /// Encrypts a message and authenticates it along with associated data
+    ///
+    /// The associated data stays in the clear, it goes through the polynomial MAC ahead of the
+    /// ciphertext and its length is bound into the tag. With no associated data, this is `ae`.
+    pub fn aead(
+        &self,
+        message: &[FpVar<F>],
+        nonce: FpVar<F>,
+        ad: &[FpVar<F>],
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        let number_keys = number_keys(message.len());
         assert!(
-            self.keys.len() >= message.len() + 3,
+            self.keys.len() >= number_keys,
             "Stop talking! The chip doesn't support messages that long!"
         );
 
@@ -71,42 +213,79 @@ impl<F: PrimeField> CiminionChip<F> {
             ciphertext.push(pt_block + ks_block)
         }
 
-        let tag = Self::authenticate(&ciphertext, t1, self.keys[self.keys.len() - 1].clone());
+        let tag = Self::authenticate(ad, &ciphertext, t1, self.keys[number_keys - 1].clone());
 
         ciphertext.push(tag);
 
         Ok(ciphertext)
     }
 
+    /// Computes a tag over public data, without encrypting anything
+    ///
+    /// The polynomial MAC is keyed by the second output of pE on the state of the nonce, which
+    /// `aead` never outputs, instead of a subkey: the key of `mac` is never a keystream subkey,
+    /// whatever the length of the messages encrypted under the same master keys. The nonce must
+    /// not be reused under the same keys.
+    pub fn mac(&self, data: &[FpVar<F>], nonce: FpVar<F>) -> Result<FpVar<F>, SynthesisError> {
+        if self.keys.len() < number_keys(0) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut state = [nonce, self.keys[0].clone(), self.keys[1].clone()];
+        self.pc(&mut state);
+        let (t1, key) = self.pe(state);
+
+        Ok(Self::authenticate(data, &[], t1, key))
+    }
+
+    /// Generates `len` elements of keystream, without computing a tag
+    ///
+    /// Adding it to a message gives the ciphertext of `ae` without its tag, for the modes that
+    /// authenticate the message otherwise (see `siv::ciminion`).
+    pub fn keystream(&self, nonce: FpVar<F>, len: usize) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        if self.keys.len() < number_keys(len) {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mut state = [nonce, self.keys[0].clone(), self.keys[1].clone()];
+        self.pc(&mut state);
+
+        Ok(self.gen_keystream(state, len))
+    }
+
+    /// Decrypts a ciphertext followed by its tag, authenticated along with associated data
+    ///
+    /// It returns the message and whether the tag is valid, which is left to the caller to
+    /// enforce. The chip must hold the subkeys of the message length, as for `aead`.
     pub fn ad(
         &self,
-        mut ciphertext: Vec<FpVar<F>>,
+        ciphertext: &[FpVar<F>],
         nonce: FpVar<F>,
-    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        ad: &[FpVar<F>],
+    ) -> Result<(Vec<FpVar<F>>, Boolean<F>), SynthesisError> {
+        let (expected, ciphertext) = ciphertext
+            .split_last()
+            .ok_or(SynthesisError::Unsatisfiable)?;
+
+        let number_keys = number_keys(ciphertext.len());
         assert!(
-            self.keys.len() >= ciphertext.len() + 2,
-            "Ciphertext too long!"
+            self.keys.len() >= number_keys,
+            "Stop talking! The chip doesn't support messages that long!"
         );
 
         let (state, t1) = self.gen_state_and_t(nonce);
 
-        // We verify the tag before anything else
-        let expected = ciphertext.pop().unwrap_or_else(|| FpVar::zero());
-        let tag = Self::authenticate(&ciphertext, t1, self.keys[self.keys.len()].clone());
-        assert!(
-            tag.value() == expected.value(),
-            "The tag didn't match, aborting!"
-        );
+        let tag = Self::authenticate(ad, ciphertext, t1, self.keys[number_keys - 1].clone());
+        let is_valid = tag.is_eq(expected)?;
 
-        // If the tag matches, then proceed to decrypt
         let mut message = Vec::with_capacity(ciphertext.len());
         let keystream = self.gen_keystream(state, ciphertext.len());
 
         for (ct_block, ks_block) in zip(ciphertext, keystream) {
-            message.push(ct_block + ks_block)
+            message.push(ct_block - ks_block)
         }
 
-        Ok(message)
+        Ok((message, is_valid))
     }
 
     fn gen_state_and_t(&self, nonce: FpVar<F>) -> ([FpVar<F>; 3], FpVar<F>) {
@@ -136,15 +315,15 @@ impl<F: PrimeField> CiminionChip<F> {
         keystream
     }
 
-    fn authenticate(ct: &[FpVar<F>], t1: FpVar<F>, key: FpVar<F>) -> FpVar<F> {
+    fn authenticate(ad: &[FpVar<F>], ct: &[FpVar<F>], t1: FpVar<F>, key: FpVar<F>) -> FpVar<F> {
         let mut tag = FpVar::<F>::zero();
 
-        for block in ct {
+        for block in ad.iter().chain(ct) {
             tag += block;
             tag *= key.clone();
         }
 
-        tag += FpVar::Constant(F::from(ct.len() as u32));
+        tag += FpVar::Constant(lengths(ad.len(), ct.len()));
         tag *= key;
         tag += t1;
 
@@ -154,11 +333,7 @@ impl<F: PrimeField> CiminionChip<F> {
     fn gen_keys(&self, mk1: FpVar<F>, mk2: FpVar<F>, len: usize) -> Vec<FpVar<F>> {
         let mut state = vec![FpVar::one(), mk1, mk2];
 
-        let mut number_keys = len + 3;
-        if len % 2 != 0 {
-            number_keys += 1
-        };
-
+        let number_keys = number_keys(len);
         let mut keys = Vec::with_capacity(number_keys);
 
         for _i in 0..number_keys {
@@ -183,7 +358,7 @@ impl<F: PrimeField> CiminionChip<F> {
 
     fn pc(&self, state: &mut [FpVar<F>]) {
         for i in 0..self.parameters.nb_rounds_pc {
-            self.permutation(state, i);
+            round(&self.parameters, state, i);
         }
     }
 
@@ -191,25 +366,112 @@ impl<F: PrimeField> CiminionChip<F> {
         for i in self.parameters.nb_rounds_pc - self.parameters.nb_rounds_pe
             ..self.parameters.nb_rounds_pc
         {
-            self.permutation(&mut state, i);
+            round(&self.parameters, &mut state, i);
         }
 
         (state[0].clone(), state[1].clone())
     }
+}
+
+/// The pC permutation of Ciminion (`nb_rounds_pc` rounds), used to derive the subkeys and the
+/// initial state
+///
+/// Ciminion keys its permutations in the mode rather than in the rounds, so they are plain
+/// permutations of 3 elements, built from a chip with `CiminionChip::pc_permutation`.
+#[derive(Clone)]
+pub struct CiminionPC<F: PrimeField> {
+    parameters: CiminionParameters<F>,
+}
+
+/// The pE permutation of Ciminion, the last `nb_rounds_pe` rounds of pC
+///
+/// Ciminion only outputs the first two elements of pE, the whole state is returned here.
+#[derive(Clone)]
+pub struct CiminionPE<F: PrimeField> {
+    parameters: CiminionParameters<F>,
+}
+
+impl<F: PrimeField> Permutation<FpVar<F>> for CiminionPC<F> {
+    fn width(&self) -> usize {
+        STATE_SIZE
+    }
+
+    fn permute(&self, state: &mut [FpVar<F>]) -> Result<(), anyhow::Error> {
+        check_count("state elements", state.len(), STATE_SIZE)?;
+
+        for i in 0..self.parameters.nb_rounds_pc {
+            round(&self.parameters, state, i);
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> Permutation<F> for CiminionPC<F> {
+    fn width(&self) -> usize {
+        STATE_SIZE
+    }
+
+    fn permute(&self, state: &mut [F]) -> Result<(), anyhow::Error> {
+        permute_native(self, state)
+    }
+}
+
+impl<F: PrimeField> Permutation<FpVar<F>> for CiminionPE<F> {
+    fn width(&self) -> usize {
+        STATE_SIZE
+    }
+
+    fn permute(&self, state: &mut [FpVar<F>]) -> Result<(), anyhow::Error> {
+        check_count("state elements", state.len(), STATE_SIZE)?;
+
+        for i in self.parameters.nb_rounds_pc - self.parameters.nb_rounds_pe
+            ..self.parameters.nb_rounds_pc
+        {
+            round(&self.parameters, state, i);
+        }
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> Permutation<F> for CiminionPE<F> {
+    fn width(&self) -> usize {
+        STATE_SIZE
+    }
 
-    fn permutation(&self, state: &mut [FpVar<F>], i: usize) {
-        let tmp = state[2].clone() + state[1].clone() * state[0].clone() + state[1].clone();
+    fn permute(&self, state: &mut [F]) -> Result<(), anyhow::Error> {
+        permute_native(self, state)
+    }
+}
 
-        let a = state[2].clone()
-            + state[1].clone() * state[0].clone()
-            + self.parameters.round_constants[4 * i + 2].clone();
-        let b = state[0].clone()
-            + self.parameters.round_constants[4 * i + 3].clone() * tmp.clone()
-            + self.parameters.round_constants[4 * i].clone();
-        let c = tmp.clone() + self.parameters.round_constants[4 * i + 1].clone();
+/// The round i of the Ciminion permutations
+fn round<F: PrimeField>(parameters: &CiminionParameters<F>, state: &mut [FpVar<F>], i: usize) {
+    let tmp = state[2].clone() + state[1].clone() * state[0].clone() + state[1].clone();
+
+    let a = state[2].clone()
+        + state[1].clone() * state[0].clone()
+        + parameters.round_constants[4 * i + 2].clone();
+    let b = state[0].clone()
+        + parameters.round_constants[4 * i + 3].clone() * tmp.clone()
+        + parameters.round_constants[4 * i].clone();
+    let c = tmp.clone() + parameters.round_constants[4 * i + 1].clone();
+
+    state[0] = a;
+    state[1] = b;
+    state[2] = c;
+}
 
-        state[0] = a;
-        state[1] = b;
-        state[2] = c;
+/// The number of subkeys used to encrypt a message of length `len`, the last one is the MAC key
+fn number_keys(len: usize) -> usize {
+    if len.is_multiple_of(2) {
+        len + 3
+    } else {
+        len + 4
     }
 }
+
+/// Encodes the lengths of the associated data and of the ciphertext in a single element
+///
+/// Without associated data, this is the length of the ciphertext.
+pub(crate) fn lengths<F: PrimeField>(ad_len: usize, ct_len: usize) -> F {
+    F::from(((ad_len as u64) << 32) + ct_len as u64)
+}
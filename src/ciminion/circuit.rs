@@ -23,6 +23,11 @@ use ark_r1cs_std::{
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 
 use super::chip::CiminionChip;
+use crate::{
+    api::{ChipAPI, Sponge},
+    common::enforce_distinct,
+    key_commitment::KeyedCircuit,
+};
 
 #[derive(Clone)]
 pub struct CiminionCircuit<F: PrimeField> {
@@ -34,7 +39,16 @@ pub struct CiminionCircuit<F: PrimeField> {
 }
 
 impl<F: PrimeField> ConstraintSynthesizer<F> for CiminionCircuit<F> {
-    fn generate_constraints(mut self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        self.generate_keyed_constraints(cs).map(|_| ())
+    }
+}
+
+impl<F: PrimeField> KeyedCircuit<F> for CiminionCircuit<F> {
+    fn generate_keyed_constraints(
+        mut self,
+        cs: ConstraintSystemRef<F>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
         let mut m = Vec::with_capacity(self.message.len());
         let mut ct = Vec::with_capacity(self.message.len() + 1);
 
@@ -50,7 +64,158 @@ impl<F: PrimeField> ConstraintSynthesizer<F> for CiminionCircuit<F> {
         let mk2 = FpVar::new_witness(cs.clone(), || Ok(self.keys.1))?;
         let n = FpVar::new_input(cs, || Ok(self.nonce))?;
 
+        self.chip.init(mk1.clone(), mk2.clone(), self.message.len());
+        let result = self.chip.ae(&m, n)?;
+
+        result.enforce_equal(&ct)?;
+
+        Ok(vec![mk1, mk2])
+    }
+}
+
+/// Ciminion encryption with associated data, see `CiminionChip::aead`
+///
+/// The ciphertext (followed by the tag), the nonce and the associated data are public inputs.
+#[derive(Clone)]
+pub struct CiminionAEADCircuit<F: PrimeField> {
+    pub chip: CiminionChip<F>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+    pub keys: (F, F),
+    pub nonce: F,
+    pub ad: Vec<F>,
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CiminionAEADCircuit<F> {
+    fn generate_constraints(mut self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message.clone()))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let mk1 = FpVar::new_witness(cs.clone(), || Ok(self.keys.0))?;
+        let mk2 = FpVar::new_witness(cs.clone(), || Ok(self.keys.1))?;
+        let n = FpVar::new_input(cs.clone(), || Ok(self.nonce))?;
+        let ad = Vec::new_input(cs, || Ok(self.ad))?;
+
         self.chip.init(mk1, mk2, self.message.len());
+        let result = self.chip.aead(&m, n, &ad)?;
+
+        result.enforce_equal(&ct)?;
+
+        Ok(())
+    }
+}
+
+/// Ciminion MAC over public data under secret master keys, see `CiminionChip::mac`
+///
+/// The data, the nonce and the tag are public inputs.
+#[derive(Clone)]
+pub struct CiminionMACCircuit<F: PrimeField> {
+    pub chip: CiminionChip<F>,
+    pub data: Vec<F>,
+    pub keys: (F, F),
+    pub nonce: F,
+    pub tag: F,
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CiminionMACCircuit<F> {
+    fn generate_constraints(mut self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let data = Vec::new_input(cs.clone(), || Ok(self.data))?;
+        let mk1 = FpVar::new_witness(cs.clone(), || Ok(self.keys.0))?;
+        let mk2 = FpVar::new_witness(cs.clone(), || Ok(self.keys.1))?;
+        let n = FpVar::new_input(cs.clone(), || Ok(self.nonce))?;
+        let tag = FpVar::new_input(cs, || Ok(self.tag))?;
+
+        self.chip.init(mk1, mk2, 0);
+        let result = self.chip.mac(&data, n)?;
+
+        result.enforce_equal(&tag)?;
+
+        Ok(())
+    }
+}
+
+/// Encryption of several messages under the same master keys, each with its own nonce
+///
+/// The subkeys are derived once, for the longest message. The ciphertexts and the nonces are
+/// public inputs, in the order ciphertext then nonce for each message as in `CiminionCircuit`.
+/// The circuit enforces that the nonces are distinct.
+#[derive(Clone)]
+pub struct CiminionBatchCircuit<F: PrimeField> {
+    pub chip: CiminionChip<F>,
+    pub messages: Vec<Vec<F>>,
+    pub ciphertexts: Vec<Vec<F>>,
+    pub keys: (F, F),
+    pub nonces: Vec<F>,
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for CiminionBatchCircuit<F> {
+    fn generate_constraints(mut self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        if self.ciphertexts.len() != self.messages.len() || self.nonces.len() != self.messages.len()
+        {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let mk1 = FpVar::new_witness(cs.clone(), || Ok(self.keys.0))?;
+        let mk2 = FpVar::new_witness(cs.clone(), || Ok(self.keys.1))?;
+
+        let max_len = self.messages.iter().map(Vec::len).max().unwrap_or(0);
+        self.chip.init(mk1, mk2, max_len);
+        let mut nonces = Vec::with_capacity(self.nonces.len());
+
+        for ((message, ciphertext), nonce) in self
+            .messages
+            .into_iter()
+            .zip(self.ciphertexts)
+            .zip(self.nonces)
+        {
+            let m = Vec::new_witness(cs.clone(), || Ok(message))?;
+            let ct = Vec::new_input(cs.clone(), || Ok(ciphertext))?;
+            let n = FpVar::new_input(cs.clone(), || Ok(nonce))?;
+
+            let result = self.chip.ae(&m, n.clone())?;
+
+            result.enforce_equal(&ct)?;
+            nonces.push(n);
+        }
+
+        enforce_distinct(&nonces)
+    }
+}
+
+/// Ciminion encryption with witnessed subkeys, bound to a public commitment
+///
+/// The subkeys aren't derived in the circuit, they are hashed and checked against the commitment,
+/// which is itself proven once with `CiminionKeyScheduleCircuit`. The ciphertext, the nonce and
+/// the commitment are public inputs.
+#[derive(Clone)]
+pub struct CiminionSubkeysCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub chip: CiminionChip<F>,
+    pub sponge: Sponge<C>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+    pub subkeys: Vec<F>,
+    pub commitment: F,
+    pub nonce: F,
+}
+
+impl<F, C> ConstraintSynthesizer<F> for CiminionSubkeysCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(mut self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let n = FpVar::new_input(cs.clone(), || Ok(self.nonce))?;
+        let subkeys = Vec::new_witness(cs.clone(), || Ok(self.subkeys))?;
+        let commitment = FpVar::new_input(cs, || Ok(self.commitment))?;
+
+        self.chip
+            .init_with_subkeys(subkeys)
+            .map_err(|_| SynthesisError::Unsatisfiable)?;
+        self.chip
+            .commit_keys(&self.sponge)?
+            .enforce_equal(&commitment)?;
+
         let result = self.chip.ae(&m, n)?;
 
         result.enforce_equal(&ct)?;
@@ -58,3 +223,34 @@ impl<F: PrimeField> ConstraintSynthesizer<F> for CiminionCircuit<F> {
         Ok(())
     }
 }
+
+/// Proves that a commitment is the one of the key schedule of secret master keys
+///
+/// The commitment is the only public input, the schedule covers messages up to max_len.
+#[derive(Clone)]
+pub struct CiminionKeyScheduleCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub chip: CiminionChip<F>,
+    pub sponge: Sponge<C>,
+    pub keys: (F, F),
+    pub max_len: usize,
+    pub commitment: F,
+}
+
+impl<F, C> ConstraintSynthesizer<F> for CiminionKeyScheduleCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(mut self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let mk1 = FpVar::new_witness(cs.clone(), || Ok(self.keys.0))?;
+        let mk2 = FpVar::new_witness(cs.clone(), || Ok(self.keys.1))?;
+        let commitment = FpVar::new_input(cs, || Ok(self.commitment))?;
+
+        self.chip.init(mk1, mk2, self.max_len);
+        self.chip
+            .commit_keys(&self.sponge)?
+            .enforce_equal(&commitment)?;
+
+        Ok(())
+    }
+}
@@ -17,70 +17,188 @@
 
 #![allow(dead_code)]
 
-use ark_bls12_381::Fr as F;
-use ark_ff::{BigInteger256 as I256, One, Zero};
-
-use super::params::*;
+use std::marker::PhantomData;
+
+use ark_bls12_381::Fr as BlsFr;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+
+use super::{
+    chip::{lengths, CiminionParameters},
+    params::*,
+};
+use crate::{
+    api::{ChipAPI, Sponge},
+    crh,
+};
+
+pub struct CiminionPrimitive<F: PrimeField> {
+    _field: PhantomData<F>,
+}
 
-pub struct CiminionPrimitiveBlsFr {}
+pub type CiminionPrimitiveBlsFr = CiminionPrimitive<BlsFr>;
 
-impl CiminionPrimitiveBlsFr {
+impl<F: PrimeField> CiminionPrimitive<F> {
     pub fn encrypt(message: &[F], master_key: (F, F), nonce: F) -> Vec<F> {
+        Self::encrypt_with_ad(message, master_key, nonce, &[])
+    }
+
+    /// Encrypts a message and authenticates it along with associated data, see `CiminionChip::aead`
+    pub fn encrypt_with_ad(message: &[F], master_key: (F, F), nonce: F, ad: &[F]) -> Vec<F> {
+        let parameters = bundled::<F>().expect("No Ciminion parameters for this field");
+
         // Init the state for key generation
-        let keys = Self::gen_keys(master_key.0, master_key.1, message.len());
+        let keys = Self::gen_keys(&parameters, master_key.0, master_key.1, message.len());
 
         // Now we init the state with the first derived key
         let mut state = [nonce, keys[0], keys[1]];
 
-        Self::pc(&mut state);
+        Self::pc(&parameters, &mut state);
 
         let mut ct: Vec<F> = Vec::with_capacity(message.len() + 1);
 
-        let (t1, _) = Self::pe(state);
+        let (t1, _) = Self::pe(&parameters, state);
 
         for i in (0..message.len()).step_by(2) {
             Self::iter(&mut state, keys[i + 2], keys[i + 3]);
-            let (out1, out2) = Self::pe(state);
+            let (out1, out2) = Self::pe(&parameters, state);
             ct.push(message[i] + out1);
             if i < message.len() - 1 {
                 ct.push(message[i + 1] + out2);
             }
         }
 
-        let tag = Self::authenticate(&ct, t1, keys[keys.len() - 1]);
+        let tag = Self::authenticate(ad, &ct, t1, keys[keys.len() - 1]);
 
         ct.push(tag);
 
         ct
     }
 
-    fn authenticate(ct: &[F], t1: F, key: F) -> F {
+    /// Decrypts a ciphertext followed by its tag, fails if the tag is invalid
+    pub fn decrypt(
+        ciphertext: &[F],
+        master_key: (F, F),
+        nonce: F,
+    ) -> Result<Vec<F>, anyhow::Error> {
+        Self::decrypt_with_ad(ciphertext, master_key, nonce, &[])
+    }
+
+    /// Decrypts a ciphertext authenticated along with associated data, see `CiminionChip::ad`
+    pub fn decrypt_with_ad(
+        ciphertext: &[F],
+        master_key: (F, F),
+        nonce: F,
+        ad: &[F],
+    ) -> Result<Vec<F>, anyhow::Error> {
+        let parameters = bundled::<F>()?;
+        let (expected, ct) = ciphertext
+            .split_last()
+            .ok_or_else(|| anyhow::Error::msg("Missing tag"))?;
+
+        let keys = Self::gen_keys(&parameters, master_key.0, master_key.1, ct.len());
+        let mut state = [nonce, keys[0], keys[1]];
+
+        Self::pc(&parameters, &mut state);
+
+        let (t1, _) = Self::pe(&parameters, state);
+        if Self::authenticate(ad, ct, t1, keys[keys.len() - 1]) != *expected {
+            return Err(anyhow::Error::msg("Invalid tag"));
+        }
+
+        let mut message: Vec<F> = Vec::with_capacity(ct.len());
+        for i in (0..ct.len()).step_by(2) {
+            Self::iter(&mut state, keys[i + 2], keys[i + 3]);
+            let (out1, out2) = Self::pe(&parameters, state);
+            message.push(ct[i] - out1);
+            if i < ct.len() - 1 {
+                message.push(ct[i + 1] - out2);
+            }
+        }
+
+        Ok(message)
+    }
+
+    /// Computes a tag over public data under the master key, see `CiminionChip::mac`
+    pub fn mac(data: &[F], master_key: (F, F), nonce: F) -> F {
+        let parameters = bundled::<F>().expect("No Ciminion parameters for this field");
+
+        let keys = Self::gen_keys(&parameters, master_key.0, master_key.1, 0);
+        let mut state = [nonce, keys[0], keys[1]];
+
+        Self::pc(&parameters, &mut state);
+
+        let (t1, key) = Self::pe(&parameters, state);
+
+        Self::authenticate(data, &[], t1, key)
+    }
+
+    /// Generates `len` elements of keystream, see `CiminionChip::keystream`
+    pub fn keystream(master_key: (F, F), nonce: F, len: usize) -> Result<Vec<F>, anyhow::Error> {
+        let parameters = bundled::<F>()?;
+
+        let keys = Self::gen_keys(&parameters, master_key.0, master_key.1, len);
+        let mut state = [nonce, keys[0], keys[1]];
+
+        Self::pc(&parameters, &mut state);
+
+        let mut keystream = Vec::with_capacity(len);
+        for i in (0..len).step_by(2) {
+            Self::iter(&mut state, keys[i + 2], keys[i + 3]);
+            let (out1, out2) = Self::pe(&parameters, state);
+            keystream.push(out1);
+            if i < len - 1 {
+                keystream.push(out2);
+            }
+        }
+
+        Ok(keystream)
+    }
+
+    /// Derives the subkeys of messages up to max_len, see `CiminionChip::init_with_subkeys`
+    pub fn subkeys(master_key: (F, F), max_len: usize) -> Vec<F> {
+        let parameters = bundled::<F>().expect("No Ciminion parameters for this field");
+
+        Self::gen_keys(&parameters, master_key.0, master_key.1, max_len)
+    }
+
+    /// Commits to subkeys natively, see `CiminionChip::commit_keys`
+    pub fn commit_subkeys<C>(sponge: &Sponge<C>, subkeys: &[F]) -> Result<F, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        let subkeys: Vec<_> = subkeys.iter().map(|k| FpVar::Constant(*k)).collect();
+
+        Ok(crh::hash(sponge, &subkeys)?.value()?)
+    }
+
+    fn authenticate(ad: &[F], ct: &[F], t1: F, key: F) -> F {
         let mut tag = F::zero();
 
-        for block in ct {
+        for block in ad.iter().chain(ct) {
             tag += block;
             tag *= key;
         }
 
-        tag += F::from(ct.len() as i32);
+        tag += lengths::<F>(ad.len(), ct.len());
         tag *= key;
         tag += t1;
 
         tag
     }
 
-    fn gen_keys(mk1: F, mk2: F, len: usize) -> Vec<F> {
+    fn gen_keys(parameters: &CiminionParameters<F>, mk1: F, mk2: F, len: usize) -> Vec<F> {
         let mut state = vec![F::one(), mk1, mk2];
 
         let mut number_keys = len + 3;
-        if len % 2 != 0 {
+        if !len.is_multiple_of(2) {
             number_keys += 1
         };
 
         let mut keys = Vec::with_capacity(number_keys);
 
         for _i in 0..number_keys {
-            Self::pc(&mut state);
+            Self::pc(parameters, &mut state);
             keys.push(state[0]);
         }
 
@@ -99,28 +217,28 @@ impl CiminionPrimitiveBlsFr {
         state[0] = tmp;
     }
 
-    fn pc(state: &mut [F]) {
-        for i in 0..NB_R_PC {
-            Self::permutation(state, i);
+    fn pc(parameters: &CiminionParameters<F>, state: &mut [F]) {
+        for i in 0..parameters.nb_rounds_pc {
+            Self::permutation(parameters, state, i);
         }
     }
 
-    fn pe(mut state: [F; 3]) -> (F, F) {
-        for i in NB_R_PC - NB_R_PE_C..NB_R_PC {
-            Self::permutation(&mut state, i);
+    fn pe(parameters: &CiminionParameters<F>, mut state: [F; 3]) -> (F, F) {
+        let (pc, pe) = (parameters.nb_rounds_pc, parameters.nb_rounds_pe);
+        for i in pc - pe..pc {
+            Self::permutation(parameters, &mut state, i);
         }
 
         (state[0], state[1])
     }
 
-    fn permutation(state: &mut [F], index: usize) {
+    fn permutation(parameters: &CiminionParameters<F>, state: &mut [F], index: usize) {
+        let rc = |j: usize| parameters.round_constants[4 * index + j].value().unwrap();
         let tmp = state[2] + state[1] * state[0] + state[1];
 
-        let a = state[2] + state[1] * state[0] + F::from(I256(ROUND_CONSTANTS_BLS[4 * index + 2]));
-        let b = state[0]
-            + F::from(I256(ROUND_CONSTANTS_BLS[4 * index + 3])) * tmp
-            + F::from(I256(ROUND_CONSTANTS_BLS[4 * index]));
-        let c = tmp + F::from(I256(ROUND_CONSTANTS_BLS[4 * index + 1]));
+        let a = state[2] + state[1] * state[0] + rc(2);
+        let b = state[0] + rc(3) * tmp + rc(0);
+        let c = tmp + rc(1);
 
         state[0] = a;
         state[1] = b;
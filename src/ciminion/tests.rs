@@ -17,30 +17,46 @@
 
 #![cfg(test)]
 
+use ark_bls12_377::Fr as Bls377Fr;
 use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::{Bn254, Fr as BnFr};
+use ark_pallas::Fr as PallasFr;
+use ark_vesta::Fr as VestaFr;
 
-use ark_ff::UniformRand;
+use ark_ff::{One, PrimeField, UniformRand, Zero};
 use ark_groth16::Groth16;
 use ark_marlin::{AHPForR1CS, Marlin};
 use ark_poly::univariate::DensePolynomial;
 use ark_poly_commit::marlin_pc::MarlinKZG10;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, Boolean, EqGadget},
+    R1CSVar,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
 use ark_snark::SNARK;
 use blake2::Blake2s;
 
-use crate::common::test_utils::to_bls;
+use crate::common::{
+    rounds::{ciminion_rounds, SECURITY_LEVEL},
+    test_utils::{is_satisfied, num_constraints},
+};
 
 use super::chip::*;
-use super::circuit::CiminionCircuit;
+use super::circuit::{
+    CiminionAEADCircuit, CiminionBatchCircuit, CiminionCircuit, CiminionKeyScheduleCircuit,
+    CiminionMACCircuit, CiminionSubkeysCircuit,
+};
 use super::params::*;
-use super::primitives::CiminionPrimitiveBlsFr;
+use super::primitives::{CiminionPrimitive, CiminionPrimitiveBlsFr};
+use crate::{
+    api::{Permutation, Sponge},
+    neptune::{self, chip::NeptuneChip},
+};
 
 #[test]
 fn marlin_ae_bls() {
-    let parameters = CiminionParameters {
-        nb_rounds_pe: NB_R_PE_C,
-        nb_rounds_pc: NB_R_PC,
-        round_constants: to_bls(&ROUND_CONSTANTS_BLS),
-    };
+    let parameters = bls12_381();
     let chip = CiminionChip::new(parameters);
 
     let rng = &mut ark_std::test_rng();
@@ -80,11 +96,7 @@ fn marlin_ae_bls() {
 
 #[test]
 fn groth16_ae_bls() {
-    let parameters = CiminionParameters {
-        nb_rounds_pe: NB_R_PE_C,
-        nb_rounds_pc: NB_R_PC,
-        round_constants: to_bls(&ROUND_CONSTANTS_BLS),
-    };
+    let parameters = bls12_381();
     let chip = CiminionChip::new(parameters);
 
     let rng = &mut ark_std::test_rng();
@@ -119,3 +131,619 @@ fn groth16_ae_bls() {
     let res = GrothSetup::verify(&vk, &public, &proof).unwrap();
     assert!(res);
 }
+
+#[test]
+fn rounds_bls() {
+    assert_eq!(
+        ciminion_rounds::<BlsFr>(SECURITY_LEVEL).unwrap(),
+        (NB_R_PC, NB_R_PE_S)
+    );
+    // The security level must be below log2(p)
+    assert!(ciminion_rounds::<BlsFr>(BlsFr::size_in_bits()).is_err());
+
+    let mut parameters = bls12_381();
+    parameters.nb_rounds_pe = NB_R_PE_S - 1;
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_err());
+}
+
+#[test]
+fn validation_bls() {
+    let parameters = bls12_381();
+    assert!(CiminionChip::try_new(parameters.clone()).is_ok());
+
+    let mut invalid = parameters.clone();
+    invalid.nb_rounds_pc += 1;
+    assert!(CiminionChip::try_new(invalid).is_err());
+
+    let mut invalid = parameters;
+    invalid.round_constants[3] = FpVar::Constant(BlsFr::one());
+    assert!(invalid.validate().is_err());
+}
+
+/// Checks the circuit against the native primitive on the field F
+fn native_vs_circuit<F: PrimeField>() {
+    let chip = CiminionChip::try_new(bundled::<F>().unwrap()).unwrap();
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![F::rand(rng), F::rand(rng), F::rand(rng)];
+    let keys = (F::rand(rng), F::rand(rng));
+    let nonce = F::rand(rng);
+
+    let ciphertext = CiminionPrimitive::<F>::encrypt(&message, keys, nonce);
+
+    let circuit = CiminionCircuit {
+        chip,
+        message,
+        ciphertext,
+        keys,
+        nonce,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit;
+    invalid.ciphertext[0] += F::one();
+    assert!(!is_satisfied(invalid));
+}
+
+/// Checks that a permutation gives the same output natively and as a gadget
+fn gadget_vs_native<P>(permutation: &P)
+where
+    P: Permutation<BlsFr> + Permutation<FpVar<BlsFr>>,
+{
+    let rng = &mut ark_std::test_rng();
+    let input = [BlsFr::rand(rng), BlsFr::rand(rng), BlsFr::rand(rng)];
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let mut vars = input
+        .iter()
+        .map(|x| FpVar::new_witness(cs.clone(), || Ok(*x)).unwrap())
+        .collect::<Vec<_>>();
+    permutation.permute(&mut vars).unwrap();
+
+    let mut expected = input;
+    permutation.permute(&mut expected).unwrap();
+    assert_eq!(vars.value().unwrap(), expected.to_vec());
+    assert!(cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn permutations_bls() {
+    let chip = CiminionChip::new(bls12_381());
+    let (pc, pe) = (chip.pc_permutation(), chip.pe_permutation());
+    assert_eq!(Permutation::<BlsFr>::width(&pc), STATE_SIZE);
+
+    let rng = &mut ark_std::test_rng();
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+
+    // The first subkey is the first element of pC([1, MK1, MK2])
+    let mut state = [BlsFr::one(), keys.0, keys.1];
+    pc.permute(&mut state).unwrap();
+    assert_eq!(state[0], CiminionPrimitive::<BlsFr>::subkeys(keys, 0)[0]);
+
+    // The gadgets match the native permutations
+    gadget_vs_native(&pc);
+    gadget_vs_native(&pe);
+
+    // pE is only the last rounds of pC
+    let mut state = [BlsFr::one(), keys.0, keys.1];
+    let mut truncated = state;
+    pc.permute(&mut state).unwrap();
+    pe.permute(&mut truncated).unwrap();
+    assert_ne!(state, truncated);
+
+    let mut wrong_width = vec![BlsFr::one(); STATE_SIZE + 1];
+    assert!(pc.permute(&mut wrong_width).is_err());
+}
+
+#[test]
+fn other_fields() {
+    native_vs_circuit::<BnFr>();
+    native_vs_circuit::<Bls377Fr>();
+    native_vs_circuit::<PallasFr>();
+    native_vs_circuit::<VestaFr>();
+}
+
+#[test]
+fn groth16_ae_bn254() {
+    let chip = CiminionChip::try_new(bundled::<BnFr>().unwrap()).unwrap();
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BnFr::rand(rng)];
+    let keys = (BnFr::rand(rng), BnFr::rand(rng));
+    let nonce = BnFr::rand(rng);
+
+    let ciphertext = CiminionPrimitive::<BnFr>::encrypt(&message, keys, nonce);
+
+    let circuit = CiminionCircuit {
+        chip,
+        message,
+        ciphertext: ciphertext.clone(),
+        keys,
+        nonce,
+    };
+
+    type GrothSetup = Groth16<Bn254>;
+
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    let mut public = ciphertext;
+    public.push(nonce);
+
+    let res = GrothSetup::verify(&vk, &public, &proof).unwrap();
+    assert!(res);
+}
+
+#[test]
+fn aead_bls() {
+    let chip = CiminionChip::try_new(bls12_381()).unwrap();
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BlsFr::rand(rng), BlsFr::rand(rng), BlsFr::rand(rng)];
+    let ad = vec![BlsFr::rand(rng), BlsFr::rand(rng)];
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let nonce = BlsFr::rand(rng);
+
+    // Without associated data, this is the AE
+    assert_eq!(
+        CiminionPrimitiveBlsFr::encrypt_with_ad(&message, keys, nonce, &[]),
+        CiminionPrimitiveBlsFr::encrypt(&message, keys, nonce)
+    );
+
+    // The associated data only changes the tag, and moving a block out of it is detected
+    let ciphertext = CiminionPrimitiveBlsFr::encrypt_with_ad(&message, keys, nonce, &ad);
+    let plain = CiminionPrimitiveBlsFr::encrypt(&message, keys, nonce);
+    assert_eq!(ciphertext[..3], plain[..3]);
+    assert_ne!(ciphertext[3], plain[3]);
+    assert_ne!(
+        CiminionPrimitiveBlsFr::encrypt_with_ad(&message, keys, nonce, &ad[..1])[3],
+        ciphertext[3]
+    );
+
+    let circuit = CiminionAEADCircuit {
+        chip,
+        message,
+        ciphertext,
+        keys,
+        nonce,
+        ad,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit.clone();
+    invalid.ad[1] += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.ciphertext[3] += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn decrypt_bls() {
+    let rng = &mut ark_std::test_rng();
+    let ad = vec![BlsFr::rand(rng), BlsFr::rand(rng)];
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let nonce = BlsFr::rand(rng);
+
+    // Messages of odd and even lengths, with and without associated data
+    for len in [1, 2, 3] {
+        let message: Vec<_> = (0..len).map(|_| BlsFr::rand(rng)).collect();
+        let plain = CiminionPrimitiveBlsFr::encrypt(&message, keys, nonce);
+        assert_eq!(
+            CiminionPrimitiveBlsFr::decrypt(&plain, keys, nonce).unwrap(),
+            message
+        );
+
+        let ciphertext = CiminionPrimitiveBlsFr::encrypt_with_ad(&message, keys, nonce, &ad);
+        assert_eq!(
+            CiminionPrimitiveBlsFr::decrypt_with_ad(&ciphertext, keys, nonce, &ad).unwrap(),
+            message
+        );
+        assert!(
+            CiminionPrimitiveBlsFr::decrypt_with_ad(&ciphertext, keys, nonce, &ad[..1]).is_err()
+        );
+        assert!(CiminionPrimitiveBlsFr::decrypt(&ciphertext, keys, nonce).is_err());
+
+        let mut invalid = ciphertext.clone();
+        invalid[0] += BlsFr::one();
+        assert!(CiminionPrimitiveBlsFr::decrypt_with_ad(&invalid, keys, nonce, &ad).is_err());
+
+        // The chip decrypts the same message and only flags the tag, the caller enforces it
+        let cs = ConstraintSystem::<BlsFr>::new_ref();
+        let mut chip = CiminionChip::new(bls12_381());
+        chip.init(
+            FpVar::new_witness(cs.clone(), || Ok(keys.0)).unwrap(),
+            FpVar::new_witness(cs.clone(), || Ok(keys.1)).unwrap(),
+            len,
+        );
+        let ad_vars = Vec::new_input(cs.clone(), || Ok(ad.clone())).unwrap();
+        let nonce_var = FpVar::new_input(cs.clone(), || Ok(nonce)).unwrap();
+
+        let ct = Vec::new_input(cs.clone(), || Ok(ciphertext.clone())).unwrap();
+        let (decrypted, is_valid) = chip.ad(&ct, nonce_var.clone(), &ad_vars).unwrap();
+        assert_eq!(decrypted.value().unwrap(), message);
+        assert!(is_valid.value().unwrap());
+
+        let ct = Vec::new_input(cs.clone(), || Ok(invalid.clone())).unwrap();
+        let (_, is_valid) = chip.ad(&ct, nonce_var, &ad_vars).unwrap();
+        assert!(!is_valid.value().unwrap());
+        is_valid.enforce_equal(&Boolean::TRUE).unwrap();
+        assert!(!cs.is_satisfied().unwrap());
+    }
+}
+
+#[test]
+fn mac_bls() {
+    let chip = CiminionChip::try_new(bls12_381()).unwrap();
+
+    let rng = &mut ark_std::test_rng();
+    let data = vec![BlsFr::rand(rng), BlsFr::rand(rng), BlsFr::rand(rng)];
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let nonce = BlsFr::rand(rng);
+
+    let tag = CiminionPrimitiveBlsFr::mac(&data, keys, nonce);
+    assert_ne!(tag, CiminionPrimitiveBlsFr::mac(&data[..2], keys, nonce));
+    assert_ne!(
+        tag,
+        CiminionPrimitiveBlsFr::mac(&data, keys, nonce + BlsFr::one())
+    );
+    assert_ne!(
+        tag,
+        CiminionPrimitiveBlsFr::mac(&data, (keys.1, keys.0), nonce)
+    );
+
+    // The MAC isn't keyed by a subkey, so it isn't the tag of an empty message with the data as
+    // associated data, and it doesn't depend on the length of the key schedule
+    assert_ne!(
+        tag,
+        CiminionPrimitiveBlsFr::encrypt_with_ad(&[], keys, nonce, &data)[0]
+    );
+    let mut long = chip.clone();
+    long.init(FpVar::Constant(keys.0), FpVar::Constant(keys.1), 6);
+    assert_eq!(
+        long.mac(
+            &data.iter().map(|x| FpVar::Constant(*x)).collect::<Vec<_>>(),
+            FpVar::Constant(nonce)
+        )
+        .unwrap()
+        .value()
+        .unwrap(),
+        tag
+    );
+
+    let circuit = CiminionMACCircuit {
+        chip,
+        data,
+        keys,
+        nonce,
+        tag,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit.clone();
+    invalid.data[0] += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.keys.0 += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_mac_bn254() {
+    let chip = CiminionChip::try_new(bundled::<BnFr>().unwrap()).unwrap();
+
+    let rng = &mut ark_std::test_rng();
+    let data = vec![BnFr::rand(rng), BnFr::rand(rng)];
+    let keys = (BnFr::rand(rng), BnFr::rand(rng));
+    let nonce = BnFr::rand(rng);
+    let tag = CiminionPrimitive::<BnFr>::mac(&data, keys, nonce);
+
+    let circuit = CiminionMACCircuit {
+        chip,
+        data: data.clone(),
+        keys,
+        nonce,
+        tag,
+    };
+
+    type GrothSetup = Groth16<Bn254>;
+
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    let mut public = data;
+    public.push(nonce);
+    public.push(tag);
+
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+    public[0] += BnFr::one();
+    assert!(!GrothSetup::verify(&vk, &public, &proof).unwrap());
+}
+
+#[test]
+fn batch_bls() {
+    let chip = CiminionChip::try_new(bls12_381()).unwrap();
+
+    let rng = &mut ark_std::test_rng();
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let messages: Vec<Vec<BlsFr>> = [3, 0, 4, 1]
+        .iter()
+        .map(|len| (0..*len).map(|_| BlsFr::rand(rng)).collect())
+        .collect();
+    let nonces: Vec<BlsFr> = (0..4).map(|_| BlsFr::rand(rng)).collect();
+    let ciphertexts: Vec<Vec<BlsFr>> = messages
+        .iter()
+        .zip(&nonces)
+        .map(|(m, n)| CiminionPrimitiveBlsFr::encrypt(m, keys, *n))
+        .collect();
+
+    let circuit = CiminionBatchCircuit {
+        chip: chip.clone(),
+        messages: messages.clone(),
+        ciphertexts: ciphertexts.clone(),
+        keys,
+        nonces: nonces.clone(),
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    // The key schedule is only derived once
+    let separate: usize = messages
+        .iter()
+        .zip(&ciphertexts)
+        .zip(&nonces)
+        .map(|((m, ct), n)| {
+            num_constraints(CiminionCircuit {
+                chip: chip.clone(),
+                message: m.clone(),
+                ciphertext: ct.clone(),
+                keys,
+                nonce: *n,
+            })
+        })
+        .sum();
+    assert!(num_constraints(circuit.clone()) < separate);
+
+    let mut invalid = circuit.clone();
+    invalid.ciphertexts[3][1] += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit.clone();
+    invalid.nonces.swap(0, 2);
+    assert!(!is_satisfied(invalid));
+
+    // Valid encryptions under a repeated nonce can't be proven, there is no witness for the
+    // inverse of the difference of the nonces
+    let mut repeated = circuit.clone();
+    repeated.nonces[1] = nonces[0];
+    repeated.ciphertexts[1] = CiminionPrimitiveBlsFr::encrypt(&messages[1], keys, nonces[0]);
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    assert!(repeated.generate_constraints(cs).is_err());
+
+    let mut invalid = circuit;
+    invalid.nonces.pop();
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    assert!(invalid.generate_constraints(cs).is_err());
+}
+
+#[test]
+fn groth16_batch_bn254() {
+    let chip = CiminionChip::try_new(bundled::<BnFr>().unwrap()).unwrap();
+
+    let rng = &mut ark_std::test_rng();
+    let keys = (BnFr::rand(rng), BnFr::rand(rng));
+    let messages = vec![
+        vec![BnFr::rand(rng), BnFr::rand(rng)],
+        vec![BnFr::rand(rng)],
+    ];
+    let nonces = vec![BnFr::rand(rng), BnFr::rand(rng)];
+    let ciphertexts: Vec<Vec<BnFr>> = messages
+        .iter()
+        .zip(&nonces)
+        .map(|(m, n)| CiminionPrimitive::<BnFr>::encrypt(m, keys, *n))
+        .collect();
+
+    let circuit = CiminionBatchCircuit {
+        chip,
+        messages,
+        ciphertexts: ciphertexts.clone(),
+        keys,
+        nonces: nonces.clone(),
+    };
+
+    type GrothSetup = Groth16<Bn254>;
+
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    let public: Vec<BnFr> = ciphertexts
+        .into_iter()
+        .zip(nonces)
+        .flat_map(|(mut ct, n)| {
+            ct.push(n);
+            ct
+        })
+        .collect();
+
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+}
+
+/// A Neptune sponge at full rate, which absorbs 3 subkeys per permutation
+fn neptune_sponge<F: PrimeField>() -> Sponge<NeptuneChip<F>> {
+    let chip = NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap();
+    Sponge::with_rate(chip, 3)
+}
+
+#[test]
+fn keystream_bls() {
+    let rng = &mut ark_std::test_rng();
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let nonce = BlsFr::rand(rng);
+
+    // The keystream is the ciphertext of zeros without its tag
+    let keystream = CiminionPrimitiveBlsFr::keystream(keys, nonce, 3).unwrap();
+    let zeros = CiminionPrimitiveBlsFr::encrypt(&[BlsFr::zero(); 3], keys, nonce);
+    assert_eq!(keystream, zeros[..3]);
+
+    let mut chip = CiminionChip::try_new(bls12_381()).unwrap();
+    chip.init(FpVar::Constant(keys.0), FpVar::Constant(keys.1), 3);
+    let result = chip.keystream(FpVar::Constant(nonce), 3).unwrap();
+    assert_eq!(result.value().unwrap(), keystream);
+
+    // The chip must hold the subkeys of the length
+    assert!(chip.keystream(FpVar::Constant(nonce), 5).is_err());
+}
+
+#[test]
+fn subkeys_bls() {
+    let chip = CiminionChip::try_new(bls12_381()).unwrap();
+    let sponge = neptune_sponge::<BlsFr>();
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BlsFr::rand(rng), BlsFr::rand(rng), BlsFr::rand(rng)];
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let nonce = BlsFr::rand(rng);
+    let ciphertext = CiminionPrimitiveBlsFr::encrypt(&message, keys, nonce);
+
+    // A schedule for longer messages also encrypts this one
+    let subkeys = CiminionPrimitiveBlsFr::subkeys(keys, 6);
+    assert_eq!(subkeys.len(), 9);
+    let commitment = CiminionPrimitiveBlsFr::commit_subkeys(&sponge, &subkeys).unwrap();
+
+    let circuit = CiminionSubkeysCircuit {
+        chip: chip.clone(),
+        sponge: sponge.clone(),
+        message: message.clone(),
+        ciphertext: ciphertext.clone(),
+        subkeys: subkeys.clone(),
+        commitment,
+        nonce,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    // With the schedule of the message, hashing the subkeys is cheaper than deriving them
+    let derived = num_constraints(CiminionCircuit {
+        chip: chip.clone(),
+        message: message.clone(),
+        ciphertext: ciphertext.clone(),
+        keys,
+        nonce,
+    });
+    let subkeys_exact = CiminionPrimitiveBlsFr::subkeys(keys, message.len());
+    let witnessed = num_constraints(CiminionSubkeysCircuit {
+        chip: chip.clone(),
+        sponge: sponge.clone(),
+        message,
+        ciphertext,
+        commitment: CiminionPrimitiveBlsFr::commit_subkeys(&sponge, &subkeys_exact).unwrap(),
+        subkeys: subkeys_exact,
+        nonce,
+    });
+    println!(
+        "Number of constraints for R1CS - Ciminion derived subkeys {}, witnessed subkeys {}",
+        derived, witnessed
+    );
+    assert!(witnessed < derived);
+
+    let mut invalid = circuit.clone();
+    invalid.subkeys[2] += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.subkeys.pop();
+    assert!(invalid
+        .generate_constraints(ConstraintSystem::<BlsFr>::new_ref())
+        .is_err());
+
+    let circuit = CiminionKeyScheduleCircuit {
+        chip,
+        sponge,
+        keys,
+        max_len: 6,
+        commitment,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit.clone();
+    invalid.max_len = 4;
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.keys.1 += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_subkeys_bn254() {
+    let chip = CiminionChip::try_new(bundled::<BnFr>().unwrap()).unwrap();
+    let sponge = neptune_sponge::<BnFr>();
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BnFr::rand(rng), BnFr::rand(rng)];
+    let keys = (BnFr::rand(rng), BnFr::rand(rng));
+    let nonce = BnFr::rand(rng);
+    let ciphertext = CiminionPrimitive::<BnFr>::encrypt(&message, keys, nonce);
+    let subkeys = CiminionPrimitive::<BnFr>::subkeys(keys, message.len());
+    let commitment = CiminionPrimitive::<BnFr>::commit_subkeys(&sponge, &subkeys).unwrap();
+
+    let circuit = CiminionSubkeysCircuit {
+        chip,
+        sponge,
+        message,
+        ciphertext: ciphertext.clone(),
+        subkeys,
+        commitment,
+        nonce,
+    };
+
+    type GrothSetup = Groth16<Bn254>;
+
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    let mut public = ciphertext;
+    public.push(nonce);
+    public.push(commitment);
+
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+    public[3] += BnFr::one();
+    assert!(!GrothSetup::verify(&vk, &public, &proof).unwrap());
+}
+
+/// Pins the tags over BLS12-381: the polynomial MAC of `aead` is keyed by the last subkey of the
+/// length of the message, whatever the length of the schedule of the chip
+#[test]
+fn known_answer_bls() {
+    use std::str::FromStr;
+
+    let keys = (BlsFr::from(1u64), BlsFr::from(2u64));
+    let nonce = BlsFr::from(3u64);
+    let message = [BlsFr::from(4u64), BlsFr::from(5u64)];
+
+    let ciphertext = CiminionPrimitiveBlsFr::encrypt(&message, keys, nonce);
+    let mut chip = CiminionChip::new(bls12_381());
+    chip.init(FpVar::Constant(keys.0), FpVar::Constant(keys.1), 6);
+    let message_var: Vec<_> = message.iter().map(|x| FpVar::Constant(*x)).collect();
+    let result = chip
+        .ae(&message_var, FpVar::Constant(nonce))
+        .unwrap()
+        .value()
+        .unwrap();
+    assert_eq!(result, ciphertext);
+    assert_eq!(
+        ciphertext[2],
+        BlsFr::from_str(
+            "16187775930644398606793545664018936036645902362842447472092428036267345959194"
+        )
+        .unwrap()
+    );
+
+    assert_eq!(
+        CiminionPrimitiveBlsFr::mac(&message, keys, nonce),
+        BlsFr::from_str(
+            "6593031662557032464278021680135278962728729956557904609468522643333769390678"
+        )
+        .unwrap()
+    );
+}
@@ -0,0 +1,63 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, EqGadget},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use super::commit;
+use crate::api::{ChipAPI, Sponge};
+
+/// Enforces that the message and the randomness open the commitment
+pub fn open<F, C>(
+    sponge: &Sponge<C>,
+    message: &[FpVar<F>],
+    randomness: &FpVar<F>,
+    commitment: &FpVar<F>,
+) -> Result<(), SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    commit(sponge, message, randomness)?.enforce_equal(commitment)
+}
+
+/// Proves the knowledge of an opening of a commitment, the only public input
+#[derive(Clone)]
+pub struct CommitmentOpeningCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> {
+    pub sponge: Sponge<C>,
+    pub message: Vec<F>,
+    pub randomness: F,
+    pub commitment: F,
+}
+
+impl<F, C> ConstraintSynthesizer<F> for CommitmentOpeningCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let message = Vec::new_witness(cs.clone(), || Ok(self.message))?;
+        let randomness = FpVar::new_witness(cs.clone(), || Ok(self.randomness))?;
+        let commitment = FpVar::new_input(cs, || Ok(self.commitment))?;
+
+        open(&self.sponge, &message, &randomness, &commitment)
+    }
+}
@@ -0,0 +1,65 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::marker::PhantomData;
+
+use ark_crypto_primitives::commitment::CommitmentGadget;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, uint8::UInt8};
+use ark_relations::r1cs::SynthesisError;
+
+use super::{commit, SpongeCommitment};
+use crate::{
+    api::ChipAPI,
+    crh::{
+        gadget::{pack_bytes, CRHParametersVar},
+        BundledSponge,
+    },
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+/// The gadget of `SpongeCommitment`
+pub struct SpongeCommitmentGadget<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> {
+    _field: PhantomData<F>,
+    _chip: PhantomData<C>,
+}
+
+pub type GriffinCommitmentGadget<F> = SpongeCommitmentGadget<F, GriffinChip<F>>;
+pub type NeptuneCommitmentGadget<F> = SpongeCommitmentGadget<F, NeptuneChip<F>>;
+pub type RescuePrimeCommitmentGadget<F> = SpongeCommitmentGadget<F, RescuePrimeChip<F>>;
+
+impl<F, C> CommitmentGadget<SpongeCommitment<F, C>, F> for SpongeCommitmentGadget<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type OutputVar = FpVar<F>;
+    type ParametersVar = CRHParametersVar<C>;
+    type RandomnessVar = FpVar<F>;
+
+    fn commit(
+        parameters: &Self::ParametersVar,
+        input: &[UInt8<F>],
+        r: &Self::RandomnessVar,
+    ) -> Result<Self::OutputVar, SynthesisError> {
+        let message = pack_bytes(input)?;
+
+        commit(&parameters.sponge, &message, r)
+    }
+}
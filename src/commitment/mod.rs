@@ -0,0 +1,115 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Hiding commitments over the SAFE sponges
+//!
+//! `commit(message, randomness)` absorbs the randomness and the message with the domain separator
+//! of the commitments, then squeezes one element: it is binding since the hash is collision
+//! resistant, and hiding as long as the randomness is a uniform field element kept secret. The
+//! separator makes the commitments independent from the plain hashes of the same elements.
+//!
+//! `SpongeCommitment` implements the `CommitmentScheme` trait of ark-crypto-primitives, whose
+//! inputs are bytes packed into field elements followed by their number (with `crh::pack_bytes`,
+//! as in `SpongeCRH`, so trailing zeros change the commitment), and `SpongeCommitmentGadget`
+//! the matching `CommitmentGadget`. `CommitmentOpeningCircuit` proves the knowledge of an opening.
+
+use std::marker::PhantomData;
+
+use ark_crypto_primitives::{commitment::CommitmentScheme, Error};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::SynthesisError;
+use ark_std::rand::Rng;
+
+use crate::{
+    api::{constants, ChipAPI, Sponge},
+    common::domain,
+    crh::{hash_with_domain, pack_bytes, BundledSponge, CRHParameters},
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+pub mod circuit;
+pub mod gadget;
+pub mod tests;
+
+/// Commits to a message with the given randomness, natively (on constants) and in a circuit
+pub fn commit<F, C>(
+    sponge: &Sponge<C>,
+    message: &[FpVar<F>],
+    randomness: &FpVar<F>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let input = [std::slice::from_ref(randomness), message].concat();
+
+    hash_with_domain(sponge, &input, Some(domain::COMMITMENT))
+}
+
+/// Commits to a message natively
+pub fn commit_native<F, C>(
+    sponge: &Sponge<C>,
+    message: &[F],
+    randomness: F,
+) -> Result<F, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    Ok(commit(sponge, &constants(message), &FpVar::Constant(randomness))?.value()?)
+}
+
+/// A hiding commitment over a SAFE sponge
+pub struct SpongeCommitment<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> {
+    _field: PhantomData<F>,
+    _chip: PhantomData<C>,
+}
+
+pub type GriffinCommitment<F> = SpongeCommitment<F, GriffinChip<F>>;
+pub type NeptuneCommitment<F> = SpongeCommitment<F, NeptuneChip<F>>;
+pub type RescuePrimeCommitment<F> = SpongeCommitment<F, RescuePrimeChip<F>>;
+
+impl<F, C> CommitmentScheme for SpongeCommitment<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type Output = F;
+    type Parameters = CRHParameters<C>;
+    type Randomness = F;
+
+    /// Loads the bundled parameters of the field, which are public
+    ///
+    /// The setup doesn't use the RNG: the commitments are hiding thanks to the randomness `r`
+    /// drawn for each of them, which must be uniform and kept secret until the opening.
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(CRHParameters::new(C::bundled_sponge()?))
+    }
+
+    fn commit(
+        parameters: &Self::Parameters,
+        input: &[u8],
+        r: &Self::Randomness,
+    ) -> Result<Self::Output, Error> {
+        let message = pack_bytes(input)?;
+
+        Ok(commit(parameters.sponge()?, &message, &FpVar::Constant(*r))?.value()?)
+    }
+}
@@ -0,0 +1,190 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_crypto_primitives::commitment::{CommitmentGadget, CommitmentScheme};
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, uint8::UInt8, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_snark::SNARK;
+
+use super::{
+    circuit::CommitmentOpeningCircuit, commit, commit_native, gadget::GriffinCommitmentGadget,
+    GriffinCommitment, NeptuneCommitment, RescuePrimeCommitment,
+};
+use crate::{
+    api::Sponge,
+    common::test_utils::is_satisfied,
+    crh::{self, gadget::CRHParametersVar, BundledSponge, CRHParameters},
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+/// Checks the gadget against the native commitment, the hiding randomness and the separation
+fn native_and_gadget<F, C>()
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>> + Clone,
+{
+    let rng = &mut ark_std::test_rng();
+    let sponge: Sponge<C> = C::bundled_sponge().unwrap();
+    let message: Vec<F> = (0..3).map(|_| F::rand(rng)).collect();
+    let randomness = F::rand(rng);
+
+    let commitment = commit_native(&sponge, &message, randomness).unwrap();
+    let cs = ConstraintSystem::<F>::new_ref();
+    let message_var = Vec::new_witness(cs.clone(), || Ok(message.clone())).unwrap();
+    let randomness_var = FpVar::new_witness(cs.clone(), || Ok(randomness)).unwrap();
+    let commitment_var = commit(&sponge, &message_var, &randomness_var).unwrap();
+    assert_eq!(commitment_var.value().unwrap(), commitment);
+    assert!(cs.is_satisfied().unwrap());
+
+    // Another randomness hides the same message, and the commitment isn't the plain hash
+    assert_ne!(
+        commit_native(&sponge, &message, F::rand(rng)).unwrap(),
+        commitment
+    );
+    let elements: Vec<_> = [vec![randomness], message.clone()]
+        .concat()
+        .into_iter()
+        .map(FpVar::Constant)
+        .collect();
+    assert_ne!(
+        crh::hash(&sponge, &elements).unwrap().value().unwrap(),
+        commitment
+    );
+    assert_ne!(
+        commit_native(&sponge, &message[..2], randomness).unwrap(),
+        commitment
+    );
+}
+
+#[test]
+fn native_and_gadget_griffin() {
+    native_and_gadget::<BlsFr, GriffinChip<BlsFr>>();
+    native_and_gadget::<BnFr, GriffinChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_neptune() {
+    native_and_gadget::<BlsFr, NeptuneChip<BlsFr>>();
+    native_and_gadget::<BnFr, NeptuneChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_rescue_prime() {
+    native_and_gadget::<BlsFr, RescuePrimeChip<BlsFr>>();
+}
+
+#[test]
+fn commitment_scheme() {
+    let rng = &mut ark_std::test_rng();
+    let input = (0..100).collect::<Vec<u8>>();
+    let randomness = BlsFr::rand(rng);
+
+    let parameters = <GriffinCommitment<BlsFr> as CommitmentScheme>::setup(rng).unwrap();
+    let commitment =
+        <GriffinCommitment<BlsFr> as CommitmentScheme>::commit(&parameters, &input, &randomness)
+            .unwrap();
+    // Each hash gives its own commitments
+    let neptune = CRHParameters::new(NeptuneChip::bundled_sponge().unwrap());
+    let rescue_prime = <RescuePrimeCommitment<BlsFr> as CommitmentScheme>::setup(rng).unwrap();
+    let other =
+        <NeptuneCommitment<BlsFr> as CommitmentScheme>::commit(&neptune, &input, &randomness);
+    assert_ne!(other.unwrap(), commitment);
+    let other = <RescuePrimeCommitment<BlsFr> as CommitmentScheme>::commit(
+        &rescue_prime,
+        &input,
+        &randomness,
+    );
+    assert_ne!(other.unwrap(), commitment);
+    assert!(<GriffinCommitment<BlsFr> as CommitmentScheme>::commit(
+        &CRHParameters::default(),
+        &input,
+        &randomness
+    )
+    .is_err());
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let parameters_var = CRHParametersVar::new_constant(cs.clone(), &parameters).unwrap();
+    let input_var = UInt8::new_witness_vec(cs.clone(), &input).unwrap();
+    let randomness_var = FpVar::new_witness(cs.clone(), || Ok(randomness)).unwrap();
+    let commitment_var =
+        GriffinCommitmentGadget::commit(&parameters_var, &input_var, &randomness_var).unwrap();
+    assert_eq!(commitment_var.value().unwrap(), commitment);
+    assert!(cs.is_satisfied().unwrap());
+}
+
+/// The number of bytes is committed, so trailing zeros give another commitment
+#[test]
+fn trailing_zeros() {
+    let rng = &mut ark_std::test_rng();
+    let randomness = BlsFr::rand(rng);
+    let parameters = <GriffinCommitment<BlsFr> as CommitmentScheme>::setup(rng).unwrap();
+
+    let commit = |input: &[u8]| {
+        <GriffinCommitment<BlsFr> as CommitmentScheme>::commit(&parameters, input, &randomness)
+            .unwrap()
+    };
+    let commitment = commit(&[1]);
+    assert_ne!(commit(&[1, 0]), commitment);
+    assert_ne!(commit(&[1, 0, 0]), commitment);
+    assert_ne!(commit(&[]), commit(&[0]));
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let parameters_var = CRHParametersVar::new_constant(cs.clone(), &parameters).unwrap();
+    let randomness_var = FpVar::new_witness(cs.clone(), || Ok(randomness)).unwrap();
+    for input in [vec![1u8], vec![1, 0]] {
+        let input_var = UInt8::new_witness_vec(cs.clone(), &input).unwrap();
+        let commitment_var =
+            GriffinCommitmentGadget::commit(&parameters_var, &input_var, &randomness_var).unwrap();
+        assert_eq!(commitment_var.value().unwrap(), commit(&input));
+    }
+    assert!(cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn opening_circuit() {
+    let rng = &mut ark_std::test_rng();
+    let sponge = NeptuneChip::<BlsFr>::bundled_sponge().unwrap();
+    let message: Vec<BlsFr> = (0..4).map(|_| BlsFr::rand(rng)).collect();
+    let randomness = BlsFr::rand(rng);
+    let commitment = commit_native(&sponge, &message, randomness).unwrap();
+
+    let circuit = CommitmentOpeningCircuit {
+        sponge,
+        message,
+        randomness,
+        commitment,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let (pk, vk) = Groth16::<Bls381>::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = Groth16::prove(&pk, circuit.clone(), rng).unwrap();
+    assert!(Groth16::verify(&vk, &[commitment], &proof).unwrap());
+    assert!(!Groth16::verify(&vk, &[commitment + BlsFr::from(1u64)], &proof).unwrap());
+
+    // Another randomness doesn't open the commitment
+    let mut wrong = circuit;
+    wrong.randomness = BlsFr::rand(rng);
+    assert!(!is_satisfied(wrong));
+}
@@ -17,29 +17,20 @@
 
 #![cfg(test)]
 
-use std::{mem::size_of_val, time::Instant};
+use std::mem::size_of_val;
 
 use ark_ec::bls12::Bls12;
-use ark_ff::{BigInteger256 as I256, Fp256, PrimeField, UniformRand, Zero};
+use ark_ff::{Fp256, PrimeField, UniformRand, Zero};
 
 use crate::{
     api::Sponge,
-    ciminion::{
-        self,
-        chip::CiminionChip,
-        params::{NB_R_PC, NB_R_PE_C},
-    },
-    common::test_utils::to_bls,
+    ciminion,
     neptune::{
-        ae_circuit::NeptuneAECircuit,
-        chip::{NeptuneChip, NeptuneParameters},
-        hash_circuit::NeptuneHashCircuit,
-        params::*,
+        self, ae_circuit::NeptuneAECircuit, chip::NeptuneChip, hash_circuit::NeptuneHashCircuit,
         primitives::NeptunePrimitivesBlsFr,
     },
     rescue_prime::{
-        chip::{RescuePrimeChip, RescuePrimeParameters},
-        circuit::RescuePrimeHashCircuit,
+        self, chip::RescuePrimeChip, circuit::RescuePrimeHashCircuit,
         primitives::RescuePrimePrimitivesBlsFr,
     },
 };
@@ -48,16 +39,11 @@ use ark_groth16::Groth16;
 use ark_marlin::{ahp::prover::ProverMsg, Marlin, Proof};
 use ark_poly::univariate::DensePolynomial;
 use ark_poly_commit::{marlin_pc::MarlinKZG10, PCCommitment, PCProof};
-use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_r1cs_std::R1CSVar;
 use ark_snark::SNARK;
 use blake2::Blake2s;
 
-use rand::rngs::OsRng;
-
-use crate::rescue_prime::params::*;
-
 const MAX_SIZE: usize = 2;
-const MAX_SIZE_HALO2: usize = 5;
 
 #[test]
 #[ignore = "Benchmark test"]
@@ -74,12 +60,7 @@ fn bench_proof_size_marlin() {
             .unwrap()
             .value()
             .unwrap();
-        let parameters = RescuePrimeParameters {
-            round_constants: to_bls(&ROUND_CONSTANTS),
-            mds: to_bls(&MDS),
-            alpha_inv: ALPHAINV_BLS381,
-            alpha: ALPHA_BLS381,
-        };
+        let parameters = rescue_prime::params::bls12_381();
         let chip = RescuePrimeChip::new(parameters);
 
         type RpSpnBls381 = Sponge<RescuePrimeChip<BlsFr>>;
@@ -93,7 +74,7 @@ fn bench_proof_size_marlin() {
         let nc = 20000 * MAX_SIZE;
         let nv = 1;
         let srs = MarlinSetup::universal_setup(nc, nv, nv, rng).unwrap();
-        let (pk, vk) = MarlinSetup::index(&srs, circuit.clone()).unwrap();
+        let (pk, _vk) = MarlinSetup::index(&srs, circuit.clone()).unwrap();
         let rescue_prime_proof = MarlinSetup::prove(&pk, circuit, rng).unwrap();
 
         println!("Proof size for Rescue Prime for a message of len {}.", size);
@@ -103,16 +84,8 @@ fn bench_proof_size_marlin() {
         type NepSpnBls381 = Sponge<NepChpBls381>;
         type NepHshCrcBls381 = NeptuneHashCircuit<BlsFr>;
         type NepAECrcBls381 = NeptuneAECircuit<BlsFr>;
-        type NepPrmBls381 = NeptuneParameters<BlsFr>;
-
-        let parameters = NepPrmBls381 {
-            nb_rounds_ext: [NEB, NEE],
-            nb_rounds_int: NI,
-            round_constants: to_bls(&ROUND_CONSTANTS_BLS),
-            gamma: FpVar::Constant(BlsFr::from(I256(GAMMA_BLS))),
-            d: D,
-            matrix_int: to_bls(&INTERNAL_MATRIX_BLS),
-        };
+
+        let parameters = neptune::params::bls12_381();
         let chip = NepChpBls381::new(parameters);
 
         let sponge = NepSpnBls381::new(chip);
@@ -133,7 +106,7 @@ fn bench_proof_size_marlin() {
 
         let srs = MarlinSetup::universal_setup(nc, nv, nv, rng).unwrap();
 
-        let (pk, vk) = MarlinSetup::index(&srs, circuit.clone()).unwrap();
+        let (pk, _vk) = MarlinSetup::index(&srs, circuit.clone()).unwrap();
         let neptune_hash_proof = MarlinSetup::prove(&pk, circuit, rng).unwrap();
 
         let ciphertext = NeptunePrimitivesBlsFr::ae(message.clone(), key, nonce)
@@ -154,7 +127,7 @@ fn bench_proof_size_marlin() {
 
         let srs = MarlinSetup::universal_setup(nc, nv, nv, rng).unwrap();
 
-        let (pk, vk) = MarlinSetup::index(&srs, circuit.clone()).unwrap();
+        let (pk, _vk) = MarlinSetup::index(&srs, circuit.clone()).unwrap();
         let neptune_ae_proof = MarlinSetup::prove(&pk, circuit, rng).unwrap();
 
         println!("Proof size for Neptune Hash for a message of len {}.", size);
@@ -173,18 +146,9 @@ fn bench_proof_size_groth16() {
     let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
     let nonce = BlsFr::rand(rng);
 
-    let parameters_cim = ciminion::chip::CiminionParameters {
-        nb_rounds_pe: NB_R_PE_C,
-        nb_rounds_pc: NB_R_PC,
-        round_constants: to_bls(&ciminion::params::ROUND_CONSTANTS_BLS),
-    };
+    let parameters_cim = ciminion::params::bls12_381();
 
-    let parameters = RescuePrimeParameters {
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        mds: to_bls(&MDS),
-        alpha_inv: ALPHAINV_BLS381,
-        alpha: ALPHA_BLS381,
-    };
+    let parameters = rescue_prime::params::bls12_381();
 
     let cim_chip = crate::ciminion::chip::CiminionChip::new(parameters_cim);
 
@@ -225,7 +189,7 @@ fn bench_proof_size_groth16() {
             nonce,
         };
 
-        let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+        let (pk, _vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
 
         let ciminion_proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
 
@@ -237,24 +201,20 @@ fn bench_proof_size_groth16() {
     }
 }
 
-pub fn get_size(
-    proof: Proof<
-        Fp256<FrParameters>,
-        MarlinKZG10<Bls12<Parameters>, DensePolynomial<Fp256<FrParameters>>>,
-    >,
-) {
+type MarlinProof = Proof<
+    Fp256<FrParameters>,
+    MarlinKZG10<Bls12<Parameters>, DensePolynomial<Fp256<FrParameters>>>,
+>;
+
+pub fn get_size(proof: MarlinProof) {
     let size_of_fe_in_bytes = Fp256::<FrParameters>::zero().into_repr().as_ref().len() * 8;
-    let mut num_comms_without_degree_bounds = 0;
-    let mut num_comms_with_degree_bounds = 0;
     let mut size_bytes_comms_without_degree_bounds = 0;
     let mut size_bytes_comms_with_degree_bounds = 0;
     let mut size_bytes_proofs = 0;
-    for c in proof.commitments.iter().flat_map(|c| c) {
+    for c in proof.commitments.iter().flatten() {
         if !c.has_degree_bound() {
-            num_comms_without_degree_bounds += 1;
             size_bytes_comms_without_degree_bounds += c.size_in_bytes();
         } else {
-            num_comms_with_degree_bounds += 1;
             size_bytes_comms_with_degree_bounds += c.size_in_bytes();
         }
     }
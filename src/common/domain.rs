@@ -0,0 +1,47 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Domain separators of the sponge based constructions
+//!
+//! Each construction starts its sponges with its own separator, so that two constructions using
+//! the same IOPattern still give independent outputs. The hashes, the AE and the Merkle trees keep
+//! the default separator (0).
+
+/// The synthetic IV of `siv`
+pub const SIV_IV: u32 = 1;
+/// The keystream of `siv`
+pub const SIV_KEYSTREAM: u32 = 2;
+/// The key derivation of `pke`
+pub const PKE_KDF: u32 = 3;
+/// The PRF of `prf`
+pub const PRF: u32 = 4;
+/// The KDF of `prf`
+pub const KDF: u32 = 5;
+/// The commitments of `commitment`
+pub const COMMITMENT: u32 = 6;
+/// The note commitments of `note`
+pub const NOTE_COMMITMENT: u32 = 7;
+/// The nullifiers of `note`
+pub const NULLIFIER: u32 = 8;
+/// The owner keys of `note`
+pub const OWNER: u32 = 9;
+/// The chains of the WOTS+ signatures of `signature`
+pub const WOTS_CHAIN: u32 = 10;
+/// The public keys of the WOTS+ signatures of `signature`
+pub const WOTS_PUBLIC_KEY: u32 = 11;
+/// The context of the subkeys of `siv`, derived with the KDF of `prf`
+pub const SIV_SUBKEYS: u32 = 12;
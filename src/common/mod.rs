@@ -16,7 +16,27 @@
 */
 
 mod constraints;
+pub mod domain;
 // pub mod new_sm;
+pub mod params_file;
 pub mod pattern;
+pub mod rounds;
 // pub mod sm;
 pub mod test_utils;
+pub mod validation;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::EqGadget};
+use ark_relations::r1cs::SynthesisError;
+
+/// Enforces that the elements are pairwise distinct, e.g. the nonces of a batch
+///
+/// It costs one constraint per pair, i.e. n(n-1)/2 for n elements.
+pub fn enforce_distinct<F: PrimeField>(elements: &[FpVar<F>]) -> Result<(), SynthesisError> {
+    for (i, x) in elements.iter().enumerate() {
+        for y in &elements[i + 1..] {
+            x.enforce_not_equal(y)?;
+        }
+    }
+    Ok(())
+}
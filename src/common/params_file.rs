@@ -0,0 +1,292 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Parameter files, allowing to load a parameter set at runtime for any field
+//!
+//! A parameter file is a JSON (or TOML) document with the following fields:
+//!
+//! - `primitive`: one of `griffin`, `neptune`, `rescue_prime` or `ciminion`
+//! - `field_modulus`: the prime p of the field, it must match the field used to load the file
+//! - `width`: the size of the state of the permutation
+//! - `rounds`: the number(s) of rounds, by name
+//! - `constants`: the constants, by name, either a single value or a list of values
+//!
+//! Every integer in `field_modulus` and `constants` is a string, written in decimal
+//! or in hexadecimal with the `0x` prefix. For instance, for Griffin:
+//!
+//! ```json
+//! {
+//!     "primitive": "griffin",
+//!     "field_modulus": "0x73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001",
+//!     "width": 3,
+//!     "rounds": { "rounds": 12 },
+//!     "constants": {
+//!         "d": "5",
+//!         "d_inv": "0x2e5f0fbadd72321ce14a56699d73f002217f0e679998f19933333332cccccccd",
+//!         "alpha": "0x...",
+//!         "beta": "0x...",
+//!         "round_constants": ["0x...", "0x..."]
+//!     }
+//! }
+//! ```
+//!
+//! The names of the rounds and constants expected for each primitive are documented
+//! on their `from_file` loader. The parameter sets shipped with zekrom are in `params/`,
+//! they are generated by `parameters.py`.
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::{BTreeMap, HashMap},
+    path::Path,
+    sync::OnceLock,
+};
+
+use ark_ff::{FpParameters, PrimeField};
+use ark_r1cs_std::fields::fp::FpVar;
+use num_bigint::BigUint;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Constants {
+    Single(String),
+    Vector(Vec<String>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParameterFile {
+    pub primitive: String,
+    pub field_modulus: String,
+    pub width: usize,
+    pub rounds: BTreeMap<String, usize>,
+    pub constants: BTreeMap<String, Constants>,
+}
+
+impl ParameterFile {
+    pub fn from_json(s: &str) -> Result<Self, anyhow::Error> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    pub fn from_toml(s: &str) -> Result<Self, anyhow::Error> {
+        Ok(toml::from_str(s)?)
+    }
+
+    /// Reads a parameter file, the format is deduced from the extension (JSON by default)
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, anyhow::Error> {
+        let content = std::fs::read_to_string(&path)?;
+
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            _ => Self::from_json(&content),
+        }
+    }
+
+    /// Checks that the file describes the expected primitive over the field F
+    pub fn check<F: PrimeField>(&self, primitive: &str, width: usize) -> Result<(), anyhow::Error> {
+        self.check_field::<F>(primitive)?;
+
+        if self.width != width {
+            return Err(anyhow::Error::msg(format!(
+                "Expected a width of {}, got {}",
+                width, self.width
+            )));
+        }
+        Ok(())
+    }
+
+    /// Checks the primitive and the field, for the primitives which allow any width
+    pub fn check_field<F: PrimeField>(&self, primitive: &str) -> Result<(), anyhow::Error> {
+        if self.primitive != primitive {
+            return Err(anyhow::Error::msg(format!(
+                "Expected parameters for {}, got {}",
+                primitive, self.primitive
+            )));
+        }
+
+        let modulus: BigUint = F::Params::MODULUS.into();
+        if parse_integer(&self.field_modulus)? != modulus {
+            return Err(anyhow::Error::msg(format!(
+                "The field modulus {} doesn't match the field (0x{:x})",
+                self.field_modulus, modulus
+            )));
+        }
+        Ok(())
+    }
+
+    pub fn rounds(&self, name: &str) -> Result<usize, anyhow::Error> {
+        self.rounds
+            .get(name)
+            .copied()
+            .ok_or_else(|| anyhow::Error::msg(format!("Missing number of rounds {}", name)))
+    }
+
+    /// Reads a single element of F
+    pub fn element<F: PrimeField>(&self, name: &str) -> Result<F, anyhow::Error> {
+        match self.get(name)? {
+            Constants::Single(value) => to_field(value),
+            Constants::Vector(_) => Err(anyhow::Error::msg(format!(
+                "{} should be a single value",
+                name
+            ))),
+        }
+    }
+
+    /// Reads a list of elements of F
+    pub fn elements<F: PrimeField>(&self, name: &str) -> Result<Vec<F>, anyhow::Error> {
+        match self.get(name)? {
+            Constants::Vector(values) => values.iter().map(|v| to_field(v)).collect(),
+            Constants::Single(_) => Err(anyhow::Error::msg(format!("{} should be a list", name))),
+        }
+    }
+
+    /// Reads a list of elements of F as constants to be used in the chips
+    pub fn constants<F: PrimeField>(&self, name: &str) -> Result<Vec<FpVar<F>>, anyhow::Error> {
+        Ok(self
+            .elements(name)?
+            .into_iter()
+            .map(FpVar::Constant)
+            .collect())
+    }
+
+    /// Reads an exponent, such as d or its inverse, as 64-bit limbs (least significant first)
+    pub fn exponent(&self, name: &str) -> Result<Vec<u64>, anyhow::Error> {
+        match self.get(name)? {
+            Constants::Single(value) => {
+                let limbs = parse_integer(value)?.to_u64_digits();
+                // Zero has no digits, but the exponent always has at least one limb
+                Ok(if limbs.is_empty() { vec![0] } else { limbs })
+            }
+            Constants::Vector(_) => Err(anyhow::Error::msg(format!(
+                "{} should be a single value",
+                name
+            ))),
+        }
+    }
+
+    fn get(&self, name: &str) -> Result<&Constants, anyhow::Error> {
+        self.constants
+            .get(name)
+            .ok_or_else(|| anyhow::Error::msg(format!("Missing constant {}", name)))
+    }
+}
+
+/// Picks the parameter file matching the field F among several parsed files (e.g. the bundled ones)
+pub fn select<F: PrimeField>(files: &[ParameterFile]) -> Result<&ParameterFile, anyhow::Error> {
+    let modulus: BigUint = F::Params::MODULUS.into();
+
+    for file in files {
+        if parse_integer(&file.field_modulus)? == modulus {
+            return Ok(file);
+        }
+    }
+
+    Err(anyhow::Error::msg(format!(
+        "No parameters available for the field 0x{:x}",
+        modulus
+    )))
+}
+
+/// A list of bundled JSON parameter files, parsed once on first use
+///
+/// The parameters built from a file hold `FpVar` constants, which can't be shared between threads:
+/// they are cached per thread and cloned on every load.
+pub struct Bundled {
+    files: &'static [&'static str],
+    parsed: OnceLock<Result<Vec<ParameterFile>, String>>,
+}
+
+thread_local! {
+    static LOADED: RefCell<HashMap<(usize, TypeId), Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+impl Bundled {
+    pub const fn new(files: &'static [&'static str]) -> Self {
+        Self {
+            files,
+            parsed: OnceLock::new(),
+        }
+    }
+
+    /// Picks the parsed file matching the field F
+    pub fn select<F: PrimeField>(&self) -> Result<&ParameterFile, anyhow::Error> {
+        let files = self
+            .parsed
+            .get_or_init(|| {
+                self.files
+                    .iter()
+                    .map(|content| ParameterFile::from_json(content))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| e.to_string())
+            })
+            .as_ref()
+            .map_err(|e| anyhow::Error::msg(e.clone()))?;
+
+        select::<F>(files)
+    }
+
+    /// Builds the parameters P over F with `from_file` the first time, and returns a clone afterwards
+    pub fn load<F: PrimeField, P: Clone + 'static>(
+        &'static self,
+        from_file: fn(&ParameterFile) -> Result<P, anyhow::Error>,
+    ) -> Result<P, anyhow::Error> {
+        let key = (self as *const Self as usize, TypeId::of::<P>());
+
+        if let Some(parameters) = LOADED.with(|loaded| {
+            loaded
+                .borrow()
+                .get(&key)
+                .and_then(|p| p.downcast_ref::<P>())
+                .cloned()
+        }) {
+            return Ok(parameters);
+        }
+
+        let parameters = from_file(self.select::<F>()?)?;
+        LOADED.with(|loaded| {
+            loaded
+                .borrow_mut()
+                .insert(key, Box::new(parameters.clone()))
+        });
+        Ok(parameters)
+    }
+}
+
+/// Parses an integer written in decimal, or in hexadecimal with the 0x prefix
+pub fn parse_integer(s: &str) -> Result<BigUint, anyhow::Error> {
+    let s = s.trim();
+    let value = match s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+        Some(hex) => BigUint::parse_bytes(hex.replace('_', "").as_bytes(), 16),
+        None => BigUint::parse_bytes(s.replace('_', "").as_bytes(), 10),
+    };
+
+    value.ok_or_else(|| anyhow::Error::msg(format!("{} isn't a valid integer", s)))
+}
+
+/// Converts an integer to an element of F, rejecting values that aren't reduced modulo p
+fn to_field<F: PrimeField>(s: &str) -> Result<F, anyhow::Error> {
+    let value = parse_integer(s)?;
+    let modulus: BigUint = F::Params::MODULUS.into();
+
+    if value >= modulus {
+        return Err(anyhow::Error::msg(format!(
+            "{} isn't an element of the field",
+            s
+        )));
+    }
+    Ok(F::from(value))
+}
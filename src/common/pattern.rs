@@ -147,6 +147,10 @@ impl IOPattern {
     pub fn len(&self) -> usize {
         self.0.len()
     }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
 }
 
 pub fn gen_hash_pattern(m_len: usize, d_len: usize) -> IOPattern {
@@ -157,10 +161,21 @@ pub fn gen_hash_pattern(m_len: usize, d_len: usize) -> IOPattern {
 }
 
 pub fn gen_ae_pattern(m_len: usize, k_len: usize, n_len: usize) -> IOPattern {
+    gen_aead_pattern(m_len, k_len, n_len, 0)
+}
+
+/// The pattern of the authenticated encryption with associated data
+///
+/// The associated data is absorbed after the key and the nonce, hence its length is bound
+/// into the tag. Without associated data, it is the pattern of `gen_ae_pattern`.
+pub fn gen_aead_pattern(m_len: usize, k_len: usize, n_len: usize, ad_len: usize) -> IOPattern {
     let mut pattern = vec![
         SpongeOp::Absorb(k_len as u32),
         SpongeOp::Absorb(n_len as u32),
     ];
+    if ad_len > 0 {
+        pattern.push(SpongeOp::Absorb(ad_len as u32));
+    }
     for _i in 0..m_len {
         pattern.push(SpongeOp::Squeeze(1));
         pattern.push(SpongeOp::Absorb(1));
@@ -0,0 +1,138 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Number of rounds recommended for each construction
+//!
+//! The rounds of Neptune and Rescue Prime follow the helpers of `parameters.sage`, which has no
+//! helper for Griffin and Ciminion: their rounds follow the papers. The functions take the width of
+//! the state, the S-box exponent and the targeted security level (in bits), and they already
+//! include the security margin recommended by the authors of each construction.
+
+use ark_ff::PrimeField;
+
+/// The security level (in bits) targeted by the parameters shipped with zekrom
+pub const SECURITY_LEVEL: usize = 128;
+
+/// The rounds are searched below this bound, as in `parameters.sage` (`range(1, 25)`)
+const MAX_ROUNDS_SEARCH: usize = 25;
+
+/// Error of a search that found no number of rounds below `MAX_ROUNDS_SEARCH`
+fn search_exhausted(name: &str, s: usize) -> anyhow::Error {
+    anyhow::Error::msg(format!(
+        "{} needs more than {} rounds for {} bits of security",
+        name,
+        MAX_ROUNDS_SEARCH - 1,
+        s
+    ))
+}
+
+/// Computes log2(binomial(n, k)) without building the (potentially huge) integer
+fn log2_binomial(n: f64, k: usize) -> f64 {
+    (0..k)
+        .map(|i| (n - i as f64).log2() - ((i + 1) as f64).log2())
+        .sum()
+}
+
+/// Number of rounds for [Griffin](https://eprint.iacr.org/2022/403)
+///
+/// The number of rounds is chosen to resist Gröbner basis attacks (R_GB),
+/// to which the authors add a margin of 20% with a minimum of 6 rounds.
+/// It fails if no number of rounds below 25 resists them, as for Rescue Prime.
+pub fn griffin_rounds(t: usize, d: u64, s: usize) -> Result<usize, anyhow::Error> {
+    let d = d as f64;
+    let r_gb = (1..MAX_ROUNDS_SEARCH)
+        .find(|&r| {
+            let left = log2_binomial(r as f64 * (d + t as f64) + 1.0, 1 + t * r);
+            let right = log2_binomial(d.powi(r as i32) + 1.0 + r as f64, 1 + r);
+            2.0 * left.min(right) >= s as f64
+        })
+        .ok_or_else(|| search_exhausted("Griffin", s))?;
+
+    Ok((1.2 * 6.max(1 + r_gb) as f64).ceil() as usize)
+}
+
+/// Number of rounds for [Neptune](https://eprint.iacr.org/2021/1695)
+///
+/// It returns the number of external and internal rounds (in that order).
+/// The external rounds are fixed to 6, while a margin of 12.5% is added to the internal ones.
+///
+/// Unlike `parameters.sage`, the bounds aren't rounded up before the margin is added, only the
+/// result is (as in the paper): the sage helper gives 69 internal rounds for BLS12-381 where the
+/// shipped parameters, generated with the paper formula, have 68.
+pub fn neptune_rounds<F: PrimeField>(t: usize, d: u64, s: usize) -> (usize, usize) {
+    let log2_p = F::size_in_bits() as f64;
+    let log2_d = (d as f64).log2();
+
+    let ri_interpolation =
+        ((s as f64).min(log2_p) - 6.0) / log2_d + 3.0 + t as f64 + (t as f64).log2() / log2_d;
+    let ri_groebner = s as f64 / 2.0 - 4.0 * t as f64 - 2.0;
+
+    (
+        6,
+        (1.125 * ri_interpolation.max(ri_groebner)).ceil() as usize,
+    )
+}
+
+/// Number of rounds for [Rescue Prime](https://eprint.iacr.org/2020/1143)
+///
+/// The number of rounds is chosen to resist Gröbner basis attacks,
+/// to which the authors add a margin of 50% with a minimum of 5 rounds.
+/// As in `parameters.sage`, the search stops at 24 rounds: it fails if none is enough.
+/// The capacity must leave some rate, i.e. capacity < t.
+pub fn rescue_prime_rounds(
+    t: usize,
+    capacity: usize,
+    d: u64,
+    s: usize,
+) -> Result<usize, anyhow::Error> {
+    if capacity >= t {
+        return Err(anyhow::Error::msg(format!(
+            "A capacity of {} leaves no rate in a state of {} elements",
+            capacity, t
+        )));
+    }
+
+    let rate = t - capacity;
+    let dcon = |n: usize| (0.5 * (d - 1) as f64 * t as f64 * (n - 1) as f64 + 2.0).floor();
+    let v = |n: usize| t * (n - 1) + rate;
+
+    let l1 = (1..MAX_ROUNDS_SEARCH)
+        .find(|&n| 2.0 * log2_binomial(v(n) as f64 + dcon(n), v(n)) > s as f64)
+        .ok_or_else(|| search_exhausted("Rescue Prime", s))?;
+
+    Ok((1.5 * 5.max(l1) as f64).ceil() as usize)
+}
+
+/// Number of rounds for [Ciminion](https://eprint.iacr.org/2021/267)
+///
+/// It returns the number of rounds of pC and pE (in that order).
+/// Ciminion doesn't rely on a power map, hence the lack of exponent.
+/// The paper requires s to be smaller than log2(p), i.e. 2^s < p.
+pub fn ciminion_rounds<F: PrimeField>(s: usize) -> Result<(usize, usize), anyhow::Error> {
+    if s >= F::size_in_bits() {
+        return Err(anyhow::Error::msg(format!(
+            "Ciminion can't reach {} bits of security over a field of {} bits",
+            s,
+            F::size_in_bits()
+        )));
+    }
+
+    let pc = s + 6;
+    let pe = 6.max((s + 37).div_ceil(12));
+
+    Ok((pc, pe))
+}
@@ -16,16 +16,51 @@
 */
 
 use ark_bls12_381::Fr as BlsFr;
-use ark_ff::BigInteger256 as I256;
+use ark_ff::{FpParameters, PrimeField};
 use ark_r1cs_std::fields::fp::FpVar;
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use num_bigint::BigUint;
+
+/// Converts constants given as little-endian limbs to constants of the field F
+///
+/// Any number of limbs is accepted, e.g. `[u64; 4]` for BLS12-381 or `[u64; 1]` for Goldilocks.
+/// Panics if a value isn't reduced modulo p, rather than silently reducing it
+pub fn to_field<F: PrimeField, L: AsRef<[u64]>>(array: &[L]) -> Vec<FpVar<F>> {
+    let modulus: BigUint = F::Params::MODULUS.into();
+
+    array
+        .iter()
+        .map(|limbs| {
+            let value = BigUint::from_slice(
+                &limbs
+                    .as_ref()
+                    .iter()
+                    .flat_map(|limb| [*limb as u32, (*limb >> 32) as u32])
+                    .collect::<Vec<_>>(),
+            );
+            assert!(value < modulus, "{} isn't an element of the field", value);
+            FpVar::Constant(F::from(value))
+        })
+        .collect()
+}
 
 /// Converts to the BLS field
 pub fn to_bls(array: &[[u64; 4]]) -> Vec<FpVar<BlsFr>> {
-    let mut ret = Vec::new();
+    to_field(array)
+}
 
-    for element in array {
-        ret.push(FpVar::Constant(BlsFr::from(I256(*element))));
-    }
+/// Synthesizes a circuit and checks that its witness satisfies the constraints
+///
+/// This allows to test the circuits on fields without a pairing-friendly curve (e.g. Pasta)
+pub fn is_satisfied<F: PrimeField, C: ConstraintSynthesizer<F>>(circuit: C) -> bool {
+    let cs = ConstraintSystem::<F>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    cs.is_satisfied().unwrap()
+}
 
-    ret
+/// Synthesizes a circuit and returns its number of constraints
+pub fn num_constraints<F: PrimeField, C: ConstraintSynthesizer<F>>(circuit: C) -> usize {
+    let cs = ConstraintSystem::<F>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    cs.num_constraints()
 }
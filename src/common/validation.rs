@@ -0,0 +1,189 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Helpers used to validate a set of parameters before building a chip
+//!
+//! They only check properties that can be verified on the parameters themselves,
+//! the number of rounds is checked separately via `common::rounds`.
+
+use ark_ff::{FpParameters, PrimeField};
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use num_bigint::BigUint;
+
+/// Reads the value of a constant used as parameter
+pub fn value<F: PrimeField>(var: &FpVar<F>) -> Result<F, anyhow::Error> {
+    var.value()
+        .map_err(|_| anyhow::Error::msg("The parameters must be constants"))
+}
+
+/// Checks that a vector of parameters has the length required by the construction
+pub fn check_count(name: &str, actual: usize, expected: usize) -> Result<(), anyhow::Error> {
+    if actual != expected {
+        return Err(anyhow::Error::msg(format!(
+            "Expected {} {}, got {}",
+            expected, name, actual
+        )));
+    }
+    Ok(())
+}
+
+/// Reads an exponent (little-endian limbs) as a u64, as the round formulas expect
+pub fn small_exponent(name: &str, d: &[u64]) -> Result<u64, anyhow::Error> {
+    match d.split_first() {
+        Some((&low, high)) if high.iter().all(|&limb| limb == 0) => Ok(low),
+        Some(_) => Err(anyhow::Error::msg(format!(
+            "The exponent {} doesn't fit in 64 bits",
+            name
+        ))),
+        None => Err(anyhow::Error::msg(format!(
+            "The exponent {} is empty",
+            name
+        ))),
+    }
+}
+
+/// Checks that x -> x^d is a permutation of F (gcd(d, p-1) = 1)
+pub fn check_exponent<F: PrimeField>(d: &[u64]) -> Result<(), anyhow::Error> {
+    let p_minus_one = modulus::<F>() - 1u32;
+
+    if gcd(to_biguint(d), p_minus_one) != BigUint::from(1u32) {
+        return Err(anyhow::Error::msg("d must be coprime to p-1"));
+    }
+    Ok(())
+}
+
+/// Checks that d_inv is the inverse of d modulo p-1, i.e. that x^(d * d_inv) = x
+pub fn check_exponent_inverse<F: PrimeField>(
+    d: &[u64],
+    d_inv: &[u64],
+) -> Result<(), anyhow::Error> {
+    let p_minus_one = modulus::<F>() - 1u32;
+
+    if (to_biguint(d) * to_biguint(d_inv)) % p_minus_one != BigUint::from(1u32) {
+        return Err(anyhow::Error::msg("d * d_inv must be 1 modulo p-1"));
+    }
+    Ok(())
+}
+
+/// Euler's criterion, x is a quadratic non-residue iff x^((p-1)/2) = -1
+pub fn is_non_residue<F: PrimeField>(x: F) -> bool {
+    x.pow(F::Params::MODULUS_MINUS_ONE_DIV_TWO) == -F::one()
+}
+
+/// The largest width for which `check_mds` verifies every square submatrix
+///
+/// The check goes through 4^m pairs of subsets of rows and columns (and C(2m, m) - 1
+/// determinants), which stays below a second up to this width.
+pub const MAX_MDS_WIDTH: usize = 8;
+
+/// Checks that all the square submatrices of a (m x m) matrix are invertible
+///
+/// The matrix is given row by row, as done for the Rescue Prime parameters.
+/// Matrices wider than `MAX_MDS_WIDTH` are rejected, as the check is exponential in m.
+pub fn check_mds<F: PrimeField>(matrix: &[F], m: usize) -> Result<(), anyhow::Error> {
+    check_count("matrix elements", matrix.len(), m * m)?;
+    if m > MAX_MDS_WIDTH {
+        return Err(anyhow::Error::msg(format!(
+            "The MDS property can only be checked up to a width of {}, got {}",
+            MAX_MDS_WIDTH, m
+        )));
+    }
+
+    // Each submatrix is defined by a subset of rows and a subset of columns of the same size
+    for rows in 1..(1u32 << m) {
+        for cols in 1..(1u32 << m) {
+            if rows.count_ones() != cols.count_ones() {
+                continue;
+            }
+
+            let submatrix = (0..m)
+                .filter(|i| rows & (1 << i) != 0)
+                .map(|i| {
+                    (0..m)
+                        .filter(|j| cols & (1 << j) != 0)
+                        .map(|j| matrix[i * m + j])
+                        .collect()
+                })
+                .collect();
+
+            if determinant(submatrix).is_zero() {
+                return Err(anyhow::Error::msg("The matrix isn't MDS"));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that a (m x m) matrix is invertible
+pub fn check_invertible<F: PrimeField>(matrix: Vec<Vec<F>>) -> Result<(), anyhow::Error> {
+    if determinant(matrix).is_zero() {
+        return Err(anyhow::Error::msg("The matrix isn't invertible"));
+    }
+    Ok(())
+}
+
+fn modulus<F: PrimeField>() -> BigUint {
+    F::Params::MODULUS.into()
+}
+
+fn to_biguint(limbs: &[u64]) -> BigUint {
+    BigUint::from_bytes_le(
+        &limbs
+            .iter()
+            .flat_map(|limb| limb.to_le_bytes())
+            .collect::<Vec<_>>(),
+    )
+}
+
+fn gcd(mut a: BigUint, mut b: BigUint) -> BigUint {
+    while b != BigUint::from(0u32) {
+        let r = a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Computes the determinant via Gaussian elimination
+fn determinant<F: PrimeField>(mut matrix: Vec<Vec<F>>) -> F {
+    let n = matrix.len();
+    let mut det = F::one();
+
+    for col in 0..n {
+        let pivot = match (col..n).find(|&row| !matrix[row][col].is_zero()) {
+            Some(pivot) => pivot,
+            None => return F::zero(),
+        };
+        if pivot != col {
+            matrix.swap(pivot, col);
+            det = -det;
+        }
+
+        det *= matrix[col][col];
+        let inv = matrix[col][col].inverse().unwrap();
+
+        for row in col + 1..n {
+            let factor = matrix[row][col] * inv;
+            let (top, bottom) = matrix.split_at_mut(row);
+            for (x, y) in bottom[0][col..].iter_mut().zip(&top[col][col..]) {
+                *x -= factor * y;
+            }
+        }
+    }
+
+    det
+}
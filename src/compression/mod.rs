@@ -0,0 +1,108 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! 2-to-1 compression functions over the permutations of the chips
+//!
+//! The SAFE sponge at rate 1 needs two permutations to hash two elements, a compression
+//! function needs only one: the inputs fill the state `[left, right, 0, ..., 3]` and a single
+//! element of the permuted state is kept. Following the Poseidon paper for Merkle trees, the
+//! last element holds 2^arity - 1 = 3, which also separates the compression from the sponges
+//! (whose state starts with zeros after the tag).
+//!
+//! Security assumptions: the permutation is assumed to behave like a random permutation, the
+//! elements not set by the inputs (at least one) act as a capacity. With c such elements over
+//! a field of size p, the collision resistance is about min(c * log2(p), log2(p)) / 2 bits, i.e.
+//! ~127 bits for the 255 bits fields, and only 32 bits on Goldilocks where a single output
+//! element is too small. The truncated permutation can be inverted on the kept element by
+//! choosing the others, its preimage resistance relies on the capacity (which must stay equal
+//! to its initial value). The feed-forward adds the left input to the output, as Davies-Meyer
+//! does, so that an inversion of the permutation doesn't give a preimage directly.
+//!
+//! Both modes work natively (on constants) and in a circuit, they don't give the same results
+//! as the sponges.
+
+use std::marker::PhantomData;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::fp::FpVar;
+
+use crate::api::Permutation;
+
+pub mod tests;
+
+/// The value of the last element of the state, 2^arity - 1 for an arity of 2
+pub const CAPACITY_TAG: u64 = 3;
+
+/// How the output is derived from the permuted state
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompressionMode {
+    /// Keeps the first element of the permuted state
+    Truncation,
+    /// Keeps the first element of the permuted state, plus the left input
+    FeedForward,
+}
+
+/// A 2-to-1 compression function over the permutation of a chip
+#[derive(Clone)]
+pub struct Compression<F: PrimeField, P: Permutation<FpVar<F>>> {
+    permutation: P,
+    mode: CompressionMode,
+    _field: PhantomData<F>,
+}
+
+impl<F, P> Compression<F, P>
+where
+    F: PrimeField,
+    P: Permutation<FpVar<F>>,
+{
+    /// Creates the compression function, the state of the permutation must have a free element
+    pub fn new(permutation: P, mode: CompressionMode) -> Result<Self, anyhow::Error> {
+        if permutation.width() < 3 {
+            return Err(anyhow::Error::msg(format!(
+                "The compression needs a state of at least 3 elements, got {}",
+                permutation.width()
+            )));
+        }
+        Ok(Self {
+            permutation,
+            mode,
+            _field: PhantomData,
+        })
+    }
+
+    pub fn mode(&self) -> CompressionMode {
+        self.mode
+    }
+
+    /// Compresses two elements into one with a single permutation
+    pub fn compress(&self, left: &FpVar<F>, right: &FpVar<F>) -> Result<FpVar<F>, anyhow::Error> {
+        let width = self.permutation.width();
+
+        let mut state = vec![FpVar::Constant(F::zero()); width];
+        state[0] = left.clone();
+        state[1] = right.clone();
+        state[width - 1] = FpVar::Constant(F::from(CAPACITY_TAG));
+
+        self.permutation.permute(&mut state)?;
+        let output = state[0].clone();
+
+        Ok(match self.mode {
+            CompressionMode::Truncation => output,
+            CompressionMode::FeedForward => output + left,
+        })
+    }
+}
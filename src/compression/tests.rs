@@ -0,0 +1,205 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::Fr as BlsFr;
+use ark_bn254::Fr as BnFr;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+
+use super::{Compression, CompressionMode};
+use crate::{
+    api::{ChipAPI, Permutation, Sponge, SpongeAPI},
+    common::pattern::gen_hash_pattern,
+    fields::goldilocks::Goldilocks,
+    griffin::{self, chip::GriffinChip},
+    merkle::tree::hash_pair,
+    neptune::{self, chip::NeptuneChip},
+    rescue_prime::{self, chip::RescuePrimeChip},
+};
+
+fn griffin<F: PrimeField>() -> GriffinChip<F> {
+    GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap()
+}
+
+fn neptune<F: PrimeField>() -> NeptuneChip<F> {
+    NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap()
+}
+
+fn rescue_prime<F: PrimeField>() -> RescuePrimeChip<F> {
+    RescuePrimeChip::try_new(rescue_prime::params::bundled::<F>().unwrap()).unwrap()
+}
+
+/// Checks that both modes give the same results natively and in a circuit, and returns the
+/// number of constraints of a compression
+fn compress<F, C>(chip: C) -> usize
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let rng = &mut ark_std::test_rng();
+    let (left, right) = (F::rand(rng), F::rand(rng));
+    let truncation = Compression::new(chip.clone(), CompressionMode::Truncation).unwrap();
+    let feed_forward = Compression::new(chip, CompressionMode::FeedForward).unwrap();
+
+    let native = |compression: &Compression<F, C>, left: F, right: F| {
+        compression
+            .compress(&FpVar::Constant(left), &FpVar::Constant(right))
+            .unwrap()
+            .value()
+            .unwrap()
+    };
+    let output = native(&truncation, left, right);
+    assert_eq!(native(&feed_forward, left, right), output + left);
+    assert_ne!(native(&truncation, right, left), output);
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    let left_var = FpVar::new_witness(cs.clone(), || Ok(left)).unwrap();
+    let right_var = FpVar::new_witness(cs.clone(), || Ok(right)).unwrap();
+    let output_var = truncation.compress(&left_var, &right_var).unwrap();
+    assert_eq!(output_var.value().unwrap(), output);
+    let constraints = cs.num_constraints();
+
+    let output_var = feed_forward.compress(&left_var, &right_var).unwrap();
+    assert_eq!(output_var.value().unwrap(), output + left);
+    assert_eq!(cs.num_constraints(), 2 * constraints);
+    assert!(cs.is_satisfied().unwrap());
+
+    constraints
+}
+
+/// The number of constraints of the sponge hash of two elements
+fn sponge_constraints<F, C>(sponge: Sponge<C>) -> usize
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let cs = ConstraintSystem::<F>::new_ref();
+    let left = FpVar::new_witness(cs.clone(), || Ok(F::from(1u64))).unwrap();
+    let right = FpVar::new_witness(cs.clone(), || Ok(F::from(2u64))).unwrap();
+    let _ = hash_pair(&sponge, &left, &right).unwrap();
+    cs.num_constraints()
+}
+
+#[test]
+fn compression_bls() {
+    // A single permutation instead of two
+    let griffin_cost = compress::<BlsFr, _>(griffin());
+    let sponge = Sponge::<GriffinChip<BlsFr>>::new(griffin());
+    assert!(2 * griffin_cost <= sponge_constraints(sponge));
+
+    let neptune_cost = compress::<BlsFr, _>(neptune());
+    let sponge = Sponge::<NeptuneChip<BlsFr>>::new(neptune());
+    assert!(2 * neptune_cost <= sponge_constraints(sponge));
+
+    let rescue_cost = compress::<BlsFr, _>(rescue_prime());
+    let sponge = Sponge::<RescuePrimeChip<BlsFr>>::new(rescue_prime());
+    assert!(2 * rescue_cost <= sponge_constraints(sponge));
+}
+
+#[test]
+fn compression_other_fields() {
+    compress::<BnFr, _>(griffin());
+    compress::<BnFr, _>(neptune());
+    compress::<BnFr, _>(rescue_prime());
+    compress::<Goldilocks, _>(rescue_prime());
+}
+
+#[test]
+fn differs_from_sponge() {
+    let sponge = Sponge::<NeptuneChip<BnFr>>::new(neptune());
+    let compression = Compression::new(neptune::<BnFr>(), CompressionMode::Truncation).unwrap();
+    let (left, right) = (
+        FpVar::Constant(BnFr::from(1u64)),
+        FpVar::Constant(BnFr::from(2u64)),
+    );
+
+    assert_ne!(
+        compression
+            .compress(&left, &right)
+            .unwrap()
+            .value()
+            .unwrap(),
+        hash_pair(&sponge, &left, &right).unwrap().value().unwrap()
+    );
+    assert_eq!(compression.mode(), CompressionMode::Truncation);
+}
+
+/// Checks the permutation natively and as a gadget, and rebuilds the sponge hash of two
+/// elements from it
+fn permutation<F, C>(chip: C, sponge: Sponge<C>)
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Permutation<F> + Clone,
+{
+    let width = Permutation::<F>::width(&chip);
+    let (left, right) = (F::from(1u64), F::from(2u64));
+
+    let mut state = vec![F::zero(); width];
+    state[1] = F::from(gen_hash_pattern(2, 1).get_tag(None));
+    state[0] += left;
+    chip.permute(&mut state).unwrap();
+    state[0] += right;
+
+    let mut vars = state
+        .iter()
+        .map(|x| FpVar::Constant(*x))
+        .collect::<Vec<_>>();
+    chip.permute(&mut state).unwrap();
+    Permutation::<FpVar<F>>::permute(&chip, &mut vars).unwrap();
+    assert_eq!(vars.value().unwrap(), state);
+
+    let hash = hash_pair(&sponge, &FpVar::Constant(left), &FpVar::Constant(right)).unwrap();
+    assert_eq!(hash.value().unwrap(), state[0]);
+
+    // The state must have the width of the permutation
+    assert!(chip.permute(&mut vec![F::zero(); width + 1]).is_err());
+}
+
+#[test]
+fn permutations() {
+    permutation::<BlsFr, _>(griffin(), Sponge::<GriffinChip<BlsFr>>::new(griffin()));
+    permutation::<BlsFr, _>(neptune(), Sponge::<NeptuneChip<BlsFr>>::new(neptune()));
+    permutation::<BlsFr, _>(
+        rescue_prime(),
+        Sponge::<RescuePrimeChip<BlsFr>>::new(rescue_prime()),
+    );
+}
+
+#[test]
+fn sponge_errors() {
+    let chip = neptune::<BlsFr>();
+    assert!(Sponge::try_with_rate(chip.clone(), 0).is_err());
+    assert!(Sponge::try_with_rate(chip.clone(), 4).is_err());
+    let mut sponge = Sponge::try_with_rate(chip.clone(), 3).unwrap();
+
+    // A failed permutation (here on a state of the wrong width) is returned by finish
+    sponge.start(gen_hash_pattern(4, 1), None);
+    sponge.state.push(FpVar::Constant(BlsFr::from(1u64)));
+    sponge.absorb(4, &vec![FpVar::Constant(BlsFr::from(1u64)); 4]);
+    sponge.squeeze(1);
+    assert!(sponge.finish().is_err());
+
+    // The same chip still hashes once given a state of the right width
+    let mut sponge = Sponge::try_with_rate(chip, 3).unwrap();
+    sponge.start(gen_hash_pattern(1, 1), None);
+    sponge.absorb(1, &[FpVar::Constant(BlsFr::from(1u64))]);
+    sponge.squeeze(1);
+    assert!(sponge.finish().is_ok());
+}
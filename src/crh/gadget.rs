@@ -0,0 +1,133 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::{borrow::Borrow, marker::PhantomData};
+
+use ark_crypto_primitives::crh::{CRHGadget, TwoToOneCRHGadget};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, AllocationMode, Boolean},
+    uint8::UInt8,
+    ToBitsGadget, ToConstraintFieldGadget,
+};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+
+use crate::{
+    api::{ChipAPI, Sponge},
+    griffin::chip::GriffinChip,
+    merkle::tree::hash_pair,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+use super::{element_size, hash, BundledSponge, CRHParameters, SpongeCRH};
+
+/// The parameters of the hashes in the circuit
+///
+/// The parameters of the chips are constants, hence they are never allocated whatever the mode.
+#[derive(Clone)]
+pub struct CRHParametersVar<C: ChipAPI> {
+    pub sponge: Sponge<C>,
+}
+
+impl<F, C> AllocVar<CRHParameters<C>, F> for CRHParametersVar<C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn new_variable<T: Borrow<CRHParameters<C>>>(
+        _cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        _mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let parameters = f()?;
+        let sponge = parameters
+            .borrow()
+            .sponge()
+            .map_err(|_| SynthesisError::AssignmentMissing)?
+            .clone();
+
+        Ok(Self { sponge })
+    }
+}
+
+/// Reads the bytes of a digest as a field element, enforcing that it is canonical
+pub(crate) fn read_element<F: PrimeField>(bytes: &[UInt8<F>]) -> Result<FpVar<F>, SynthesisError> {
+    if bytes.len() > element_size::<F>() {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+    Boolean::le_bits_to_fp_var(&bytes.to_bits_le()?)
+}
+
+/// Packs bytes into field elements, followed by the number of bytes (see `crh::pack_bytes`)
+pub(crate) fn pack_bytes<F: PrimeField>(
+    input: &[UInt8<F>],
+) -> Result<Vec<FpVar<F>>, SynthesisError> {
+    let mut elements = input.to_constraint_field()?;
+    elements.push(FpVar::Constant(F::from(input.len() as u64)));
+
+    Ok(elements)
+}
+
+/// The gadget of `SpongeCRH`
+pub struct SpongeCRHGadget<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> {
+    _field: PhantomData<F>,
+    _chip: PhantomData<C>,
+}
+
+pub type GriffinCRHGadget<F> = SpongeCRHGadget<F, GriffinChip<F>>;
+pub type NeptuneCRHGadget<F> = SpongeCRHGadget<F, NeptuneChip<F>>;
+pub type RescuePrimeCRHGadget<F> = SpongeCRHGadget<F, RescuePrimeChip<F>>;
+
+impl<F, C> CRHGadget<SpongeCRH<F, C>, F> for SpongeCRHGadget<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type OutputVar = FpVar<F>;
+    type ParametersVar = CRHParametersVar<C>;
+
+    fn evaluate(
+        parameters: &Self::ParametersVar,
+        input: &[UInt8<F>],
+    ) -> Result<Self::OutputVar, SynthesisError> {
+        let elements = pack_bytes(input)?;
+
+        hash(&parameters.sponge, &elements)
+    }
+}
+
+impl<F, C> TwoToOneCRHGadget<SpongeCRH<F, C>, F> for SpongeCRHGadget<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type OutputVar = FpVar<F>;
+    type ParametersVar = CRHParametersVar<C>;
+
+    fn evaluate(
+        parameters: &Self::ParametersVar,
+        left_input: &[UInt8<F>],
+        right_input: &[UInt8<F>],
+    ) -> Result<Self::OutputVar, SynthesisError> {
+        let left = read_element(left_input)?;
+        let right = read_element(right_input)?;
+
+        hash_pair(&parameters.sponge, &left, &right)
+    }
+}
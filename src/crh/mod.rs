@@ -0,0 +1,229 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! The zekrom hashes as collision-resistant hashes of ark-crypto-primitives
+//!
+//! `SpongeCRH` implements the `CRH` and `TwoToOneCRH` traits over any SAFE sponge, and
+//! `SpongeCRHGadget` the matching gadget traits, hence Griffin, Neptune and Rescue Prime can be
+//! used in the generic arkworks code (e.g. as the hashes of a `merkle_tree::Config`).
+//!
+//! The inputs of `CRH` are bytes of any length, packed into field elements followed by the number
+//! of bytes (otherwise an input and the same input with trailing zeros would give the same
+//! elements). The inputs of `TwoToOneCRH` are
+//! the bytes of two digests, each read as a field element: it gives the same nodes as
+//! `merkle::tree::hash_pair`.
+
+use std::marker::PhantomData;
+
+use ark_crypto_primitives::{
+    crh::{TwoToOneCRH, CRH},
+    Error,
+};
+use ark_ff::{BigInteger, PrimeField, ToConstraintField};
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::SynthesisError;
+use ark_std::rand::Rng;
+
+use crate::{
+    api::{ChipAPI, Sponge, SpongeAPI},
+    common::pattern::gen_hash_pattern,
+    griffin::{self, chip::GriffinChip},
+    merkle::tree::hash_pair,
+    neptune::{self, chip::NeptuneChip},
+    rescue_prime::{self, chip::RescuePrimeChip},
+};
+
+pub mod gadget;
+pub mod tests;
+
+/// A chip which has bundled parameters for its field, used by the `setup` of the hashes
+pub trait BundledSponge: ChipAPI + Clone + Sized {
+    fn bundled_sponge() -> Result<Sponge<Self>, anyhow::Error>;
+}
+
+impl<F: PrimeField> BundledSponge for GriffinChip<F> {
+    fn bundled_sponge() -> Result<Sponge<Self>, anyhow::Error> {
+        let chip = GriffinChip::try_new(griffin::params::bundled::<F>()?)?;
+        Ok(Sponge::<GriffinChip<F>>::new(chip))
+    }
+}
+
+impl<F: PrimeField> BundledSponge for NeptuneChip<F> {
+    fn bundled_sponge() -> Result<Sponge<Self>, anyhow::Error> {
+        let chip = NeptuneChip::try_new(neptune::params::bundled::<F>()?)?;
+        Ok(Sponge::<NeptuneChip<F>>::new(chip))
+    }
+}
+
+impl<F: PrimeField> BundledSponge for RescuePrimeChip<F> {
+    fn bundled_sponge() -> Result<Sponge<Self>, anyhow::Error> {
+        let chip = RescuePrimeChip::try_new(rescue_prime::params::bundled::<F>()?)?;
+        Ok(Sponge::<RescuePrimeChip<F>>::new(chip))
+    }
+}
+
+/// The parameters of the hashes, i.e. the sponge (which holds the parameters of its chip)
+///
+/// The default parameters have no sponge and can't be used, as the ark-crypto-primitives
+/// traits require a default value. They are created by `setup` or `new`.
+#[derive(Clone)]
+pub struct CRHParameters<C: ChipAPI> {
+    sponge: Option<Sponge<C>>,
+}
+
+impl<C: ChipAPI> Default for CRHParameters<C> {
+    fn default() -> Self {
+        Self { sponge: None }
+    }
+}
+
+impl<C: ChipAPI> CRHParameters<C> {
+    /// Hashes with the given sponge, e.g. one created from a parameter file
+    pub fn new(sponge: Sponge<C>) -> Self {
+        Self {
+            sponge: Some(sponge),
+        }
+    }
+
+    pub fn sponge(&self) -> Result<&Sponge<C>, anyhow::Error> {
+        self.sponge
+            .as_ref()
+            .ok_or_else(|| anyhow::Error::msg("The hash parameters have no sponge"))
+    }
+}
+
+/// Hashes any number of elements, it works both natively (on constants) and in a circuit
+pub fn hash<F, C>(sponge: &Sponge<C>, elements: &[FpVar<F>]) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    hash_with_domain(sponge, elements, None)
+}
+
+/// Hashes any number of elements under an optional domain separator, e.g. for the commitments of
+/// `note`
+pub(crate) fn hash_with_domain<F, C>(
+    sponge: &Sponge<C>,
+    elements: &[FpVar<F>],
+    domain: Option<u32>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let mut sponge = sponge.clone();
+
+    sponge.start(gen_hash_pattern(elements.len(), 1), domain);
+    sponge.absorb(elements.len() as u32, elements);
+    let hash = sponge.squeeze(1)[0].clone();
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(hash)
+}
+
+/// Packs bytes into field elements, followed by the number of bytes
+pub(crate) fn pack_bytes<F: PrimeField>(input: &[u8]) -> Result<Vec<FpVar<F>>, anyhow::Error> {
+    let mut elements = input
+        .to_field_elements()
+        .ok_or_else(|| anyhow::Error::msg("The input can't be packed into field elements"))?;
+    elements.push(F::from(input.len() as u64));
+
+    Ok(elements.into_iter().map(FpVar::Constant).collect())
+}
+
+/// The size of the bytes of a field element, the input size of `TwoToOneCRH`
+pub(crate) fn element_size<F: PrimeField>() -> usize {
+    <F::BigInt as BigInteger>::NUM_LIMBS * 8
+}
+
+/// Reads the bytes of a digest, which must be a canonical field element
+fn read_element<F: PrimeField>(bytes: &[u8]) -> Result<F, anyhow::Error> {
+    let size = element_size::<F>();
+    if bytes.len() > size {
+        return Err(anyhow::Error::msg(format!(
+            "A digest has at most {} bytes, got {}",
+            size,
+            bytes.len()
+        )));
+    }
+
+    let mut padded = bytes.to_vec();
+    padded.resize(size, 0);
+    Ok(F::read(padded.as_slice())?)
+}
+
+/// A collision-resistant hash over a SAFE sponge
+pub struct SpongeCRH<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> {
+    _field: PhantomData<F>,
+    _chip: PhantomData<C>,
+}
+
+pub type GriffinCRH<F> = SpongeCRH<F, GriffinChip<F>>;
+pub type NeptuneCRH<F> = SpongeCRH<F, NeptuneChip<F>>;
+pub type RescuePrimeCRH<F> = SpongeCRH<F, RescuePrimeChip<F>>;
+
+impl<F, C> CRH for SpongeCRH<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    /// The size of the bytes of an element, the input may have any length as its size is hashed
+    const INPUT_SIZE_BITS: usize = <F::BigInt as BigInteger>::NUM_LIMBS * 64;
+
+    type Output = F;
+    type Parameters = CRHParameters<C>;
+
+    /// Loads the bundled parameters of the field, no randomness is needed
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(CRHParameters::new(C::bundled_sponge()?))
+    }
+
+    fn evaluate(parameters: &Self::Parameters, input: &[u8]) -> Result<Self::Output, Error> {
+        let elements = pack_bytes(input)?;
+
+        Ok(hash(parameters.sponge()?, &elements)?.value()?)
+    }
+}
+
+impl<F, C> TwoToOneCRH for SpongeCRH<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    const LEFT_INPUT_SIZE_BITS: usize = <F::BigInt as BigInteger>::NUM_LIMBS * 64;
+    const RIGHT_INPUT_SIZE_BITS: usize = <F::BigInt as BigInteger>::NUM_LIMBS * 64;
+
+    type Output = F;
+    type Parameters = CRHParameters<C>;
+
+    /// Loads the bundled parameters of the field, no randomness is needed
+    fn setup<R: Rng>(_rng: &mut R) -> Result<Self::Parameters, Error> {
+        Ok(CRHParameters::new(C::bundled_sponge()?))
+    }
+
+    fn evaluate(
+        parameters: &Self::Parameters,
+        left_input: &[u8],
+        right_input: &[u8],
+    ) -> Result<Self::Output, Error> {
+        let left = FpVar::Constant(read_element(left_input)?);
+        let right = FpVar::Constant(read_element(right_input)?);
+
+        Ok(hash_pair(parameters.sponge()?, &left, &right)?.value()?)
+    }
+}
@@ -0,0 +1,253 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use std::marker::PhantomData;
+
+use ark_bls12_381::Fr as BlsFr;
+use ark_bn254::Fr as BnFr;
+use ark_crypto_primitives::{
+    crh::{CRHGadget, TwoToOneCRH, TwoToOneCRHGadget, CRH},
+    merkle_tree::{constraints::PathVar, Config, MerkleTree},
+};
+use ark_ff::{PrimeField, ToBytes, UniformRand};
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, Boolean, EqGadget},
+    uint8::UInt8,
+    R1CSVar, ToBytesGadget,
+};
+use ark_relations::r1cs::ConstraintSystem;
+
+use super::{
+    gadget::{CRHParametersVar, SpongeCRHGadget},
+    BundledSponge, CRHParameters, GriffinCRH, NeptuneCRH, SpongeCRH,
+};
+use crate::{
+    aead,
+    api::{ChipAPI, Permutation, Sponge},
+    commitment,
+    griffin::chip::GriffinChip,
+    merkle::tree::hash_pair,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+/// A Merkle tree of ark-crypto-primitives hashing with the same sponge at each level
+struct SpongeConfig<F, C> {
+    _field: PhantomData<F>,
+    _chip: PhantomData<C>,
+}
+
+impl<F, C> Config for SpongeConfig<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type LeafHash = SpongeCRH<F, C>;
+    type TwoToOneHash = SpongeCRH<F, C>;
+}
+
+/// Builds a tree of ark-crypto-primitives, and verifies a path natively and in a circuit
+fn merkle_tree<F, C>()
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type Hash<F, C> = SpongeCRH<F, C>;
+    type Gadget<F, C> = SpongeCRHGadget<F, C>;
+
+    let rng = &mut ark_std::test_rng();
+    let parameters = <Hash<F, C> as CRH>::setup(rng).unwrap();
+    let leaves = (0..8u8).map(|i| [i; 40]).collect::<Vec<_>>();
+
+    let mut tree =
+        MerkleTree::<SpongeConfig<F, C>>::new(&parameters, &parameters, &leaves).unwrap();
+    let proof = tree.generate_proof(5).unwrap();
+    assert!(proof
+        .verify(&parameters, &parameters, &tree.root(), &leaves[5])
+        .unwrap());
+    assert!(!proof
+        .verify(&parameters, &parameters, &tree.root(), &leaves[4])
+        .unwrap());
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    let parameters_var = CRHParametersVar::new_constant(cs.clone(), &parameters).unwrap();
+    let root = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+    let leaf = UInt8::new_witness_vec(cs.clone(), &leaves[5]).unwrap();
+    let path = PathVar::<SpongeConfig<F, C>, Gadget<F, C>, Gadget<F, C>, F>::new_witness(
+        cs.clone(),
+        || Ok(&proof),
+    )
+    .unwrap();
+
+    let is_member = path
+        .verify_membership(&parameters_var, &parameters_var, &root, &leaf.as_slice())
+        .unwrap();
+    is_member.enforce_equal(&Boolean::TRUE).unwrap();
+    assert!(cs.is_satisfied().unwrap());
+
+    // The updated tree is verified against the new root
+    let new_leaf = UInt8::new_witness_vec(cs.clone(), &[9u8; 40]).unwrap();
+    tree.update(5, &[9u8; 40]).unwrap();
+    let new_root = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+    let is_updated = path
+        .update_and_check(
+            &parameters_var,
+            &parameters_var,
+            &root,
+            &new_root,
+            &leaf.as_slice(),
+            &new_leaf.as_slice(),
+        )
+        .unwrap();
+    assert!(is_updated.value().unwrap());
+    assert!(cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn merkle_tree_griffin() {
+    merkle_tree::<BlsFr, GriffinChip<BlsFr>>();
+}
+
+#[test]
+fn merkle_tree_neptune() {
+    merkle_tree::<BnFr, NeptuneChip<BnFr>>();
+}
+
+#[test]
+fn merkle_tree_rescue_prime() {
+    merkle_tree::<BlsFr, RescuePrimeChip<BlsFr>>();
+}
+
+#[test]
+fn native_and_gadget() {
+    let rng = &mut ark_std::test_rng();
+    let parameters = <NeptuneCRH<BlsFr> as CRH>::setup(rng).unwrap();
+    let sponge = parameters.sponge().unwrap();
+    let (left, right) = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let input = (0..100).collect::<Vec<u8>>();
+
+    let mut left_bytes = vec![];
+    left.write(&mut left_bytes).unwrap();
+    let mut right_bytes = vec![];
+    right.write(&mut right_bytes).unwrap();
+
+    // The two-to-one hash gives the nodes of the zekrom Merkle trees
+    let node = <NeptuneCRH<BlsFr> as TwoToOneCRH>::evaluate(&parameters, &left_bytes, &right_bytes)
+        .unwrap();
+    let expected = hash_pair(sponge, &FpVar::Constant(left), &FpVar::Constant(right)).unwrap();
+    assert_eq!(node, expected.value().unwrap());
+    let digest = <NeptuneCRH<BlsFr> as CRH>::evaluate(&parameters, &input).unwrap();
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let parameters_var = CRHParametersVar::new_constant(cs.clone(), &parameters).unwrap();
+    let left_var = FpVar::new_witness(cs.clone(), || Ok(left)).unwrap();
+    let right_var = FpVar::new_witness(cs.clone(), || Ok(right)).unwrap();
+    let node_var = <SpongeCRHGadget<_, _> as TwoToOneCRHGadget<NeptuneCRH<BlsFr>, _>>::evaluate(
+        &parameters_var,
+        &left_var.to_bytes().unwrap(),
+        &right_var.to_bytes().unwrap(),
+    )
+    .unwrap();
+    let input_var = UInt8::new_witness_vec(cs.clone(), &input).unwrap();
+    let digest_var = <SpongeCRHGadget<_, _> as CRHGadget<NeptuneCRH<BlsFr>, _>>::evaluate(
+        &parameters_var,
+        &input_var,
+    )
+    .unwrap();
+
+    assert_eq!(node_var.value().unwrap(), node);
+    assert_eq!(digest_var.value().unwrap(), digest);
+    assert!(cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn trailing_zeros() {
+    let rng = &mut ark_std::test_rng();
+    let parameters = <NeptuneCRH<BlsFr> as CRH>::setup(rng).unwrap();
+    let parameters_var =
+        CRHParametersVar::new_constant(ConstraintSystem::new_ref(), &parameters).unwrap();
+
+    // The number of bytes is hashed, so the same bytes with trailing zeros give another digest
+    let inputs: [&[u8]; 4] = [&[], &[0], &[1], &[1, 0]];
+    let digests = inputs
+        .iter()
+        .map(|input| <NeptuneCRH<BlsFr> as CRH>::evaluate(&parameters, input).unwrap())
+        .collect::<Vec<_>>();
+    for (i, digest) in digests.iter().enumerate() {
+        assert!(!digests[i + 1..].contains(digest));
+
+        let input_var = UInt8::constant_vec(inputs[i]);
+        let digest_var = <SpongeCRHGadget<_, _> as CRHGadget<NeptuneCRH<BlsFr>, _>>::evaluate(
+            &parameters_var,
+            &input_var,
+        )
+        .unwrap();
+        assert_eq!(digest_var.value().unwrap(), *digest);
+    }
+}
+
+#[test]
+fn invalid_parameters() {
+    let rng = &mut ark_std::test_rng();
+    let parameters = CRHParameters::default();
+    assert!(<GriffinCRH<BlsFr> as CRH>::evaluate(&parameters, &[1, 2, 3]).is_err());
+
+    // A digest must be a canonical field element
+    let parameters = <GriffinCRH<BlsFr> as CRH>::setup(rng).unwrap();
+    assert!(<GriffinCRH<BlsFr> as TwoToOneCRH>::evaluate(&parameters, &[0xff; 32], &[]).is_err());
+    assert!(<GriffinCRH<BlsFr> as TwoToOneCRH>::evaluate(&parameters, &[0; 33], &[]).is_err());
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    assert!(
+        CRHParametersVar::<GriffinChip<BlsFr>>::new_constant(cs, CRHParameters::default()).is_err()
+    );
+}
+
+/// A chip whose permutation always fails, e.g. one built from inconsistent parameters
+#[derive(Clone)]
+struct FailingChip;
+
+impl Permutation<FpVar<BlsFr>> for FailingChip {
+    fn width(&self) -> usize {
+        3
+    }
+
+    fn permute(&self, _state: &mut [FpVar<BlsFr>]) -> Result<(), anyhow::Error> {
+        Err(anyhow::Error::msg("The permutation failed"))
+    }
+}
+
+impl ChipAPI for FailingChip {
+    type Value = FpVar<BlsFr>;
+
+    fn constant(x: u128) -> FpVar<BlsFr> {
+        FpVar::Constant(BlsFr::from(x))
+    }
+}
+
+#[test]
+fn permutation_errors() {
+    // The helpers return the error of the permutation instead of panicking
+    let sponge = Sponge::with_rate(FailingChip, 2);
+    let elements = [FpVar::Constant(BlsFr::from(1u64))];
+    assert!(super::hash(&sponge, &elements).is_err());
+    assert!(aead::encrypt(&sponge, &elements[0], &elements[0], &[], &elements).is_err());
+    assert!(commitment::commit(&sponge, &elements, &elements[0]).is_err());
+}
@@ -0,0 +1,66 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, EqGadget},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::api::Permutation;
+
+use super::wrap::SpongeWrap;
+
+/// Proves the SpongeWrap encryption of a private message under a private key
+///
+/// The nonce, the associated data, the ciphertext and the tag are public inputs.
+#[derive(Clone)]
+pub struct SpongeWrapCircuit<F: PrimeField, P: Permutation<FpVar<F>> + Clone> {
+    pub wrap: SpongeWrap<F, P>,
+    pub key: Vec<F>,
+    pub nonce: Vec<F>,
+    pub ad: Vec<F>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+    pub tag: Vec<F>,
+}
+
+impl<F, P> ConstraintSynthesizer<F> for SpongeWrapCircuit<F, P>
+where
+    F: PrimeField,
+    P: Permutation<FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let nonce = Vec::new_input(cs.clone(), || Ok(self.nonce))?;
+        let ad = Vec::new_input(cs.clone(), || Ok(self.ad))?;
+        let ciphertext = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let tag = Vec::new_input(cs.clone(), || Ok(self.tag))?;
+        let key = Vec::new_witness(cs.clone(), || Ok(self.key))?;
+        let message = Vec::new_witness(cs, || Ok(self.message))?;
+
+        let (result, result_tag) = self
+            .wrap
+            .encrypt(&key, &nonce, &ad, &message)
+            .map_err(|_| SynthesisError::Unsatisfiable)?;
+
+        result.enforce_equal(&ciphertext)?;
+        result_tag.enforce_equal(&tag)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,101 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! The duplex object and the SpongeWrap authenticated encryption on top of it
+//!
+//! Based on "Duplexing the sponge: single-pass authenticated encryption and other applications"
+//! (Bertoni, Daemen, Peeters, Van Assche) - https://keccak.team/files/SpongeDuplex.pdf
+//!
+//! The state is split into a rate and a capacity of at least one element. Each duplexing call
+//! adds up to `rate` elements to the rate, and encodes the padding (the number of elements) and
+//! the frame bit into the first element of the capacity, so that blocks of different lengths
+//! or frames never collide. Then the state is permuted and the rate is returned.
+//!
+//! At rate `width - 1`, each permutation call processes `width - 1` elements. The generic
+//! security of the sponge is about c * log2(p) / 2 bits for a capacity of c elements, i.e. ~127
+//! bits with a single element on the 255 bits fields.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::fp::FpVar;
+
+use crate::api::Permutation;
+
+pub mod circuit;
+pub mod tests;
+pub mod wrap;
+
+/// A duplex object over a permutation, it works both natively (on constants) and in a circuit
+#[derive(Clone)]
+pub struct Duplex<F: PrimeField, P: Permutation<FpVar<F>>> {
+    permutation: P,
+    rate: usize,
+    state: Vec<FpVar<F>>,
+}
+
+impl<F, P> Duplex<F, P>
+where
+    F: PrimeField,
+    P: Permutation<FpVar<F>>,
+{
+    /// Creates a duplex object with an all zero state, the rate must leave some capacity
+    pub fn new(permutation: P, rate: usize) -> Result<Self, anyhow::Error> {
+        let width = permutation.width();
+        if rate == 0 || rate >= width {
+            return Err(anyhow::Error::msg(format!(
+                "The rate must be between 1 and {}, got {}",
+                width - 1,
+                rate
+            )));
+        }
+
+        Ok(Self {
+            state: vec![FpVar::Constant(F::zero()); width],
+            permutation,
+            rate,
+        })
+    }
+
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    /// Absorbs at most `rate` elements with a frame bit, and returns the new rate
+    pub fn duplex(
+        &mut self,
+        input: &[FpVar<F>],
+        frame: bool,
+    ) -> Result<Vec<FpVar<F>>, anyhow::Error> {
+        if input.len() > self.rate {
+            return Err(anyhow::Error::msg(format!(
+                "A block has at most {} elements, got {}",
+                self.rate,
+                input.len()
+            )));
+        }
+
+        for (element, x) in self.state.iter_mut().zip(input.iter()) {
+            *element += x;
+        }
+        // The padding and the frame bit, which is never zero
+        let padding = 2 * input.len() as u64 + frame as u64 + 1;
+        self.state[self.rate] += FpVar::Constant(F::from(padding));
+
+        self.permutation.permute(&mut self.state)?;
+
+        Ok(self.state[..self.rate].to_vec())
+    }
+}
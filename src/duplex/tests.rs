@@ -0,0 +1,246 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_snark::SNARK;
+
+use super::{circuit::SpongeWrapCircuit, wrap::SpongeWrap, Duplex};
+use crate::{
+    api::Permutation,
+    common::test_utils::is_satisfied,
+    fields::goldilocks::Goldilocks,
+    griffin::{self, chip::GriffinChip},
+    neptune::{self, chip::NeptuneChip},
+    rescue_prime::{self, chip::RescuePrimeChip},
+};
+
+fn griffin<F: PrimeField>() -> GriffinChip<F> {
+    GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap()
+}
+
+fn neptune<F: PrimeField>() -> NeptuneChip<F> {
+    NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap()
+}
+
+fn rescue_prime<F: PrimeField>() -> RescuePrimeChip<F> {
+    RescuePrimeChip::try_new(rescue_prime::params::bundled::<F>().unwrap()).unwrap()
+}
+
+fn random<F: PrimeField>(len: usize) -> Vec<F> {
+    let rng = &mut ark_std::test_rng();
+    (0..len).map(|_| F::rand(rng)).collect()
+}
+
+/// Encrypts and decrypts messages of several lengths, and checks that any change is detected
+fn round_trip<F, P>(wrap: SpongeWrap<F, P>)
+where
+    F: PrimeField,
+    P: Permutation<FpVar<F>> + Clone,
+{
+    let (key, nonce) = (random::<F>(2), vec![F::from(7u64)]);
+    let one = F::from(1u64);
+
+    for (ad_len, message_len) in [(0, 0), (0, 1), (1, 0), (2, 5), (7, 2 * wrap.rate())] {
+        let ad = random::<F>(ad_len);
+        let message = random::<F>(message_len);
+
+        let (ciphertext, tag) = wrap.encrypt_native(&key, &nonce, &ad, &message).unwrap();
+        assert_eq!(ciphertext.len(), message.len());
+        assert_eq!(tag.len(), wrap.tag_len());
+        assert_eq!(
+            wrap.decrypt_native(&key, &nonce, &ad, &ciphertext, &tag)
+                .unwrap(),
+            message
+        );
+
+        let mut invalid = tag.clone();
+        invalid[0] += one;
+        assert!(wrap
+            .decrypt_native(&key, &nonce, &ad, &ciphertext, &invalid)
+            .is_err());
+        assert!(wrap
+            .decrypt_native(&key, &[one], &ad, &ciphertext, &tag)
+            .is_err());
+        assert!(wrap
+            .decrypt_native(&[one, one], &nonce, &ad, &ciphertext, &tag)
+            .is_err());
+        // The associated data is authenticated, even the empty one against a single zero
+        let mut other = ad.clone();
+        match other.last_mut() {
+            Some(x) => *x += one,
+            None => other.push(F::zero()),
+        }
+        assert!(wrap
+            .decrypt_native(&key, &nonce, &other, &ciphertext, &tag)
+            .is_err());
+        if !ciphertext.is_empty() {
+            let mut invalid = ciphertext.clone();
+            invalid[ciphertext.len() - 1] += one;
+            assert!(wrap
+                .decrypt_native(&key, &nonce, &ad, &invalid, &tag)
+                .is_err());
+        }
+    }
+}
+
+#[test]
+fn duplex() {
+    let zero = FpVar::Constant(BlsFr::from(0u64));
+    assert!(Duplex::new(neptune::<BlsFr>(), 0).is_err());
+    assert!(Duplex::new(neptune::<BlsFr>(), 4).is_err());
+
+    let duplex = Duplex::new(neptune::<BlsFr>(), 3).unwrap();
+    let output = |input: &[FpVar<BlsFr>], frame| {
+        duplex
+            .clone()
+            .duplex(input, frame)
+            .unwrap()
+            .value()
+            .unwrap()
+    };
+
+    // The padding and the frame bit separate the blocks
+    assert_eq!(output(&[], false).len(), 3);
+    assert_ne!(
+        output(&[], false),
+        output(std::slice::from_ref(&zero), false)
+    );
+    assert_ne!(output(&[], false), output(&[], true));
+    assert_ne!(
+        output(std::slice::from_ref(&zero), true),
+        output(&[zero.clone(), zero.clone()], false)
+    );
+    assert!(duplex.clone().duplex(&vec![zero; 4], false).is_err());
+}
+
+#[test]
+fn spongewrap_bls() {
+    round_trip(SpongeWrap::new(griffin::<BlsFr>(), 2, 1).unwrap());
+    round_trip(SpongeWrap::new(neptune::<BlsFr>(), 3, 1).unwrap());
+    round_trip(SpongeWrap::new(rescue_prime::<BlsFr>(), 2, 2).unwrap());
+
+    assert!(SpongeWrap::new(neptune::<BlsFr>(), 3, 0).is_err());
+    assert!(SpongeWrap::new(neptune::<BlsFr>(), 3, 4).is_err());
+    assert!(SpongeWrap::new(neptune::<BlsFr>(), 4, 1).is_err());
+}
+
+#[test]
+fn spongewrap_other_fields() {
+    round_trip(SpongeWrap::new(griffin::<BnFr>(), 2, 1).unwrap());
+    // A single element is too small for a tag on Goldilocks
+    round_trip(SpongeWrap::new(rescue_prime::<Goldilocks>(), 2, 2).unwrap());
+}
+
+#[test]
+fn spongewrap_gadget() {
+    let wrap = SpongeWrap::new(griffin::<BlsFr>(), 2, 1).unwrap();
+    let (key, nonce, ad, message) = (random(1), random(1), random(3), random(3));
+    let (ciphertext, tag) = wrap.encrypt_native(&key, &nonce, &ad, &message).unwrap();
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let witness = |x: &Vec<BlsFr>| Vec::new_witness(cs.clone(), || Ok(x.clone())).unwrap();
+    let (decrypted, is_valid) = wrap
+        .decrypt(
+            &witness(&key),
+            &witness(&nonce),
+            &witness(&ad),
+            &witness(&ciphertext),
+            &witness(&tag),
+        )
+        .unwrap();
+    assert_eq!(decrypted.value().unwrap(), message);
+    assert!(is_valid.value().unwrap());
+
+    let (_, is_valid) = wrap
+        .decrypt(
+            &witness(&key),
+            &witness(&nonce),
+            &witness(&ad),
+            &witness(&ciphertext),
+            &witness(&message[..1].to_vec()),
+        )
+        .unwrap();
+    assert!(!is_valid.value().unwrap());
+    assert!(cs.is_satisfied().unwrap());
+}
+
+/// Encrypts a random message and builds the circuit of its encryption
+fn circuit<F, P>(wrap: SpongeWrap<F, P>, message_len: usize) -> SpongeWrapCircuit<F, P>
+where
+    F: PrimeField,
+    P: Permutation<FpVar<F>> + Clone,
+{
+    let (key, nonce, ad, message) = (random(1), random(1), random(2), random(message_len));
+    let (ciphertext, tag) = wrap.encrypt_native(&key, &nonce, &ad, &message).unwrap();
+
+    SpongeWrapCircuit {
+        wrap,
+        key,
+        nonce,
+        ad,
+        message,
+        ciphertext,
+        tag,
+    }
+}
+
+#[test]
+fn spongewrap_circuit() {
+    let circuit = circuit(SpongeWrap::new(neptune::<BnFr>(), 3, 1).unwrap(), 7);
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit.clone();
+    invalid.ad[1] += BnFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit.clone();
+    invalid.message[6] += BnFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.tag[0] += BnFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_spongewrap_bls() {
+    let circuit = circuit(SpongeWrap::new(neptune::<BlsFr>(), 3, 1).unwrap(), 5);
+    let rng = &mut ark_std::test_rng();
+    let inputs = [
+        circuit.nonce.clone(),
+        circuit.ad.clone(),
+        circuit.ciphertext.clone(),
+        circuit.tag.clone(),
+    ]
+    .concat();
+
+    type GrothSetup = Groth16<Bls381>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &inputs, &proof).unwrap());
+    let mut invalid = inputs;
+    invalid[0] += BlsFr::rand(rng);
+    assert!(!GrothSetup::verify(&vk, &invalid, &proof).unwrap());
+}
@@ -0,0 +1,211 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::marker::PhantomData;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{Boolean, EqGadget},
+    R1CSVar,
+};
+
+use crate::api::{constants, Permutation};
+
+use super::Duplex;
+
+/// A ciphertext and its tag
+pub type Encrypted<V> = (Vec<V>, Vec<V>);
+
+/// The SpongeWrap authenticated encryption with associated data
+///
+/// As in MonkeySpongeWrap, the key and the nonce are absorbed first. Then the associated data
+/// and the message are absorbed block by block: the frame bit marks the last block of the key
+/// and of the associated data, and all the blocks of the message but the last one, whose
+/// duplexing gives the tag. The ciphertext is the message plus the rate returned by the
+/// previous duplexing. A nonce must never be used twice with the same key.
+#[derive(Clone)]
+pub struct SpongeWrap<F: PrimeField, P: Permutation<FpVar<F>> + Clone> {
+    permutation: P,
+    rate: usize,
+    tag_len: usize,
+    _field: PhantomData<F>,
+}
+
+impl<F, P> SpongeWrap<F, P>
+where
+    F: PrimeField,
+    P: Permutation<FpVar<F>> + Clone,
+{
+    /// Creates the mode, the tag has between 1 and `rate` elements
+    pub fn new(permutation: P, rate: usize, tag_len: usize) -> Result<Self, anyhow::Error> {
+        // Checks the rate
+        Duplex::new(permutation.clone(), rate)?;
+        if tag_len == 0 || tag_len > rate {
+            return Err(anyhow::Error::msg(format!(
+                "The tag must have between 1 and {} elements, got {}",
+                rate, tag_len
+            )));
+        }
+
+        Ok(Self {
+            permutation,
+            rate,
+            tag_len,
+            _field: PhantomData,
+        })
+    }
+
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+
+    pub fn tag_len(&self) -> usize {
+        self.tag_len
+    }
+
+    /// Creates the duplex object and absorbs the key and the nonce
+    fn keyed(&self, key: &[FpVar<F>], nonce: &[FpVar<F>]) -> Result<Duplex<F, P>, anyhow::Error> {
+        let mut duplex = Duplex::new(self.permutation.clone(), self.rate)?;
+        Self::absorb_framed(&mut duplex, &[key, nonce].concat())?;
+        Ok(duplex)
+    }
+
+    /// Absorbs the blocks of some data, only the last one (maybe empty) has the frame bit
+    fn absorb_framed(
+        duplex: &mut Duplex<F, P>,
+        data: &[FpVar<F>],
+    ) -> Result<Vec<FpVar<F>>, anyhow::Error> {
+        let mut blocks = data.chunks(duplex.rate()).collect::<Vec<_>>();
+        let last = blocks.pop().unwrap_or_default();
+
+        for block in blocks {
+            duplex.duplex(block, false)?;
+        }
+        duplex.duplex(last, true)
+    }
+
+    /// Encrypts a message, returns the ciphertext (as long as the message) and the tag
+    pub fn encrypt(
+        &self,
+        key: &[FpVar<F>],
+        nonce: &[FpVar<F>],
+        ad: &[FpVar<F>],
+        message: &[FpVar<F>],
+    ) -> Result<Encrypted<FpVar<F>>, anyhow::Error> {
+        let mut duplex = self.keyed(key, nonce)?;
+        let mut keystream = Self::absorb_framed(&mut duplex, ad)?;
+        let mut ciphertext = Vec::with_capacity(message.len());
+
+        let blocks = message.chunks(self.rate).collect::<Vec<_>>();
+        let n = blocks.len();
+        for (i, block) in blocks.iter().enumerate() {
+            ciphertext.extend(block.iter().zip(keystream.iter()).map(|(m, z)| m + z));
+            if i + 1 < n {
+                keystream = duplex.duplex(block, true)?;
+            }
+        }
+
+        let last = blocks.last().copied().unwrap_or_default();
+        let tag = duplex.duplex(last, false)?[..self.tag_len].to_vec();
+
+        Ok((ciphertext, tag))
+    }
+
+    /// Decrypts a ciphertext, returns the message and whether the tag is valid
+    pub fn decrypt(
+        &self,
+        key: &[FpVar<F>],
+        nonce: &[FpVar<F>],
+        ad: &[FpVar<F>],
+        ciphertext: &[FpVar<F>],
+        tag: &[FpVar<F>],
+    ) -> Result<(Vec<FpVar<F>>, Boolean<F>), anyhow::Error> {
+        if tag.len() != self.tag_len {
+            return Err(anyhow::Error::msg(format!(
+                "The tag must have {} elements, got {}",
+                self.tag_len,
+                tag.len()
+            )));
+        }
+
+        let mut duplex = self.keyed(key, nonce)?;
+        let mut keystream = Self::absorb_framed(&mut duplex, ad)?;
+        let mut message = Vec::with_capacity(ciphertext.len());
+
+        let blocks = ciphertext.chunks(self.rate).collect::<Vec<_>>();
+        let n = blocks.len();
+        let mut last = vec![];
+        for (i, block) in blocks.iter().enumerate() {
+            last = block
+                .iter()
+                .zip(keystream.iter())
+                .map(|(c, z)| c - z)
+                .collect::<Vec<_>>();
+            message.extend(last.iter().cloned());
+            if i + 1 < n {
+                keystream = duplex.duplex(&last, true)?;
+            }
+        }
+
+        let expected = duplex.duplex(&last, false)?[..self.tag_len].to_vec();
+        let is_valid = expected.is_eq(tag)?;
+
+        Ok((message, is_valid))
+    }
+
+    /// Encrypts natively, returns the ciphertext and the tag
+    pub fn encrypt_native(
+        &self,
+        key: &[F],
+        nonce: &[F],
+        ad: &[F],
+        message: &[F],
+    ) -> Result<Encrypted<F>, anyhow::Error> {
+        let (ciphertext, tag) = self.encrypt(
+            &constants(key),
+            &constants(nonce),
+            &constants(ad),
+            &constants(message),
+        )?;
+
+        Ok((ciphertext.value()?, tag.value()?))
+    }
+
+    /// Decrypts natively, fails if the tag is invalid
+    pub fn decrypt_native(
+        &self,
+        key: &[F],
+        nonce: &[F],
+        ad: &[F],
+        ciphertext: &[F],
+        tag: &[F],
+    ) -> Result<Vec<F>, anyhow::Error> {
+        let (message, is_valid) = self.decrypt(
+            &constants(key),
+            &constants(nonce),
+            &constants(ad),
+            &constants(ciphertext),
+            &constants(tag),
+        )?;
+
+        if !is_valid.value()? {
+            return Err(anyhow::Error::msg("Invalid tag"));
+        }
+        Ok(message.value()?)
+    }
+}
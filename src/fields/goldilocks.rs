@@ -0,0 +1,504 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! The Goldilocks field, of prime p = 2^64 - 2^32 + 1
+//!
+//! The `Fp64` type of ark-ff 0.3 can't be used here: its addition and its Montgomery
+//! multiplication assume that the most significant bit of the modulus is unset,
+//! which isn't the case for Goldilocks. The elements are thus stored in canonical form
+//! (a `u64` smaller than p) and the arithmetic is done on `u128`.
+
+use std::{
+    fmt,
+    io::{Error, ErrorKind, Read, Result as IoResult, Write},
+    iter::{Product, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use ark_ff::{
+    biginteger::BigInteger64, FftField, FftParameters, Field, FpParameters, FromBytes, One,
+    PrimeField, ToBytes, Zero,
+};
+use ark_serialize::{
+    buffer_byte_size, CanonicalDeserialize, CanonicalDeserializeWithFlags, CanonicalSerialize,
+    CanonicalSerializeWithFlags, EmptyFlags, Flags, SerializationError,
+};
+use ark_std::rand::{
+    distributions::{Distribution, Standard},
+    Rng,
+};
+use num_bigint::BigUint;
+use zeroize::Zeroize;
+
+/// The modulus p = 2^64 - 2^32 + 1
+pub const MODULUS: u64 = 0xffff_ffff_0000_0001;
+
+static CHARACTERISTIC: [u64; 1] = [MODULUS];
+
+/// The parameters of the field
+///
+/// As the elements aren't in Montgomery form, `GENERATOR` and `TWO_ADIC_ROOT_OF_UNITY` are
+/// given in canonical form. `R`, `R2` and `INV` are the Montgomery constants, they are
+/// only given for completeness.
+pub struct GoldilocksParameters;
+
+impl FftParameters for GoldilocksParameters {
+    type BigInt = BigInteger64;
+
+    const TWO_ADICITY: u32 = 32;
+
+    /// 7^((p-1) / 2^32)
+    const TWO_ADIC_ROOT_OF_UNITY: BigInteger64 = BigInteger64([0x1856_29dc_da58_878c]);
+}
+
+impl FpParameters for GoldilocksParameters {
+    const MODULUS: BigInteger64 = BigInteger64([MODULUS]);
+
+    const MODULUS_BITS: u32 = 64;
+
+    const CAPACITY: u32 = Self::MODULUS_BITS - 1;
+
+    const REPR_SHAVE_BITS: u32 = 0;
+
+    const R: BigInteger64 = BigInteger64([0xffff_ffff]);
+
+    const R2: BigInteger64 = BigInteger64([0xffff_fffe_0000_0001]);
+
+    const INV: u64 = 0xffff_fffe_ffff_ffff;
+
+    const GENERATOR: BigInteger64 = BigInteger64([7]);
+
+    const MODULUS_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0x7fff_ffff_8000_0000]);
+
+    const T: BigInteger64 = BigInteger64([0xffff_ffff]);
+
+    const T_MINUS_ONE_DIV_TWO: BigInteger64 = BigInteger64([0x7fff_ffff]);
+}
+
+/// An element of the Goldilocks field, always reduced modulo p
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Goldilocks(u64);
+
+impl Goldilocks {
+    /// Creates an element from an integer, reducing it modulo p
+    pub const fn new(value: u64) -> Self {
+        Self(value % MODULUS)
+    }
+
+    /// The canonical representation of the element, in [0, p)
+    pub const fn value(&self) -> u64 {
+        self.0
+    }
+
+    fn reduce(value: u128) -> Self {
+        Self((value % MODULUS as u128) as u64)
+    }
+}
+
+impl fmt::Display for Goldilocks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Zeroize for Goldilocks {
+    fn zeroize(&mut self) {
+        self.0.zeroize();
+    }
+}
+
+/* ----- Arithmetic ----- */
+impl Zero for Goldilocks {
+    fn zero() -> Self {
+        Self(0)
+    }
+
+    fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+}
+
+impl One for Goldilocks {
+    fn one() -> Self {
+        Self(1)
+    }
+}
+
+impl Neg for Goldilocks {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        if self.0 == 0 {
+            self
+        } else {
+            Self(MODULUS - self.0)
+        }
+    }
+}
+
+impl AddAssign<&Self> for Goldilocks {
+    fn add_assign(&mut self, other: &Self) {
+        *self = Self::reduce(self.0 as u128 + other.0 as u128);
+    }
+}
+
+impl SubAssign<&Self> for Goldilocks {
+    fn sub_assign(&mut self, other: &Self) {
+        *self += &-*other;
+    }
+}
+
+impl MulAssign<&Self> for Goldilocks {
+    fn mul_assign(&mut self, other: &Self) {
+        *self = Self::reduce(self.0 as u128 * other.0 as u128);
+    }
+}
+
+impl DivAssign<&Self> for Goldilocks {
+    /// Panics when dividing by zero, as the other fields of arkworks
+    fn div_assign(&mut self, other: &Self) {
+        self.mul_assign(&other.inverse().expect("Division by zero"));
+    }
+}
+
+// The remaining operators are derived from the assignments above
+macro_rules! impl_operator {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident) => {
+        impl $OpAssign<Self> for Goldilocks {
+            fn $op_assign(&mut self, other: Self) {
+                self.$op_assign(&other);
+            }
+        }
+
+        impl<'a> $Op<&'a Self> for Goldilocks {
+            type Output = Self;
+
+            fn $op(mut self, other: &Self) -> Self {
+                self.$op_assign(other);
+                self
+            }
+        }
+
+        impl $Op<Self> for Goldilocks {
+            type Output = Self;
+
+            fn $op(mut self, other: Self) -> Self {
+                self.$op_assign(&other);
+                self
+            }
+        }
+    };
+}
+
+impl_operator!(Add, add, AddAssign, add_assign);
+impl_operator!(Sub, sub, SubAssign, sub_assign);
+impl_operator!(Mul, mul, MulAssign, mul_assign);
+impl_operator!(Div, div, DivAssign, div_assign);
+
+impl Sum<Self> for Goldilocks {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl<'a> Sum<&'a Self> for Goldilocks {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::zero(), Add::add)
+    }
+}
+
+impl Product<Self> for Goldilocks {
+    fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+impl<'a> Product<&'a Self> for Goldilocks {
+    fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.fold(Self::one(), Mul::mul)
+    }
+}
+
+/* ----- Conversions ----- */
+impl From<u128> for Goldilocks {
+    fn from(value: u128) -> Self {
+        Self::reduce(value)
+    }
+}
+
+impl From<u64> for Goldilocks {
+    fn from(value: u64) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<u32> for Goldilocks {
+    fn from(value: u32) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<u16> for Goldilocks {
+    fn from(value: u16) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<u8> for Goldilocks {
+    fn from(value: u8) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<bool> for Goldilocks {
+    fn from(value: bool) -> Self {
+        Self(value as u64)
+    }
+}
+
+impl From<BigInteger64> for Goldilocks {
+    fn from(value: BigInteger64) -> Self {
+        Self::new(value.0[0])
+    }
+}
+
+impl From<Goldilocks> for BigInteger64 {
+    fn from(value: Goldilocks) -> Self {
+        BigInteger64([value.0])
+    }
+}
+
+impl From<BigUint> for Goldilocks {
+    fn from(value: BigUint) -> Self {
+        let reduced = value % MODULUS;
+        Self(reduced.to_u64_digits().first().copied().unwrap_or(0))
+    }
+}
+
+impl From<Goldilocks> for BigUint {
+    fn from(value: Goldilocks) -> Self {
+        BigUint::from(value.0)
+    }
+}
+
+impl FromStr for Goldilocks {
+    type Err = ();
+
+    /// Parses an integer written in decimal, reducing it modulo p
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.parse::<BigUint>().map(Self::from).map_err(|_| ())
+    }
+}
+
+/* ----- Serialization ----- */
+impl ToBytes for Goldilocks {
+    fn write<W: Write>(&self, mut writer: W) -> IoResult<()> {
+        writer.write_all(&self.0.to_le_bytes())
+    }
+}
+
+impl FromBytes for Goldilocks {
+    fn read<R: Read>(mut reader: R) -> IoResult<Self> {
+        let mut bytes = [0u8; 8];
+        reader.read_exact(&mut bytes)?;
+
+        Self::from_repr(BigInteger64([u64::from_le_bytes(bytes)]))
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "The value isn't reduced"))
+    }
+}
+
+// The flags, if any, are written in an additional byte as the modulus takes the 64 bits
+impl CanonicalSerializeWithFlags for Goldilocks {
+    fn serialize_with_flags<W: Write, F: Flags>(
+        &self,
+        mut writer: W,
+        flags: F,
+    ) -> Result<(), SerializationError> {
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let size = self.serialized_size_with_flags::<F>();
+
+        let mut bytes = [0u8; 9];
+        bytes[..8].copy_from_slice(&self.0.to_le_bytes());
+        bytes[8] |= flags.u8_bitmask();
+
+        writer.write_all(&bytes[..size])?;
+        Ok(())
+    }
+
+    fn serialized_size_with_flags<F: Flags>(&self) -> usize {
+        buffer_byte_size(GoldilocksParameters::MODULUS_BITS as usize + F::BIT_SIZE)
+    }
+}
+
+impl CanonicalSerialize for Goldilocks {
+    fn serialize<W: Write>(&self, writer: W) -> Result<(), SerializationError> {
+        self.serialize_with_flags(writer, EmptyFlags)
+    }
+
+    fn serialized_size(&self) -> usize {
+        self.serialized_size_with_flags::<EmptyFlags>()
+    }
+}
+
+impl CanonicalDeserializeWithFlags for Goldilocks {
+    fn deserialize_with_flags<R: Read, F: Flags>(
+        mut reader: R,
+    ) -> Result<(Self, F), SerializationError> {
+        if F::BIT_SIZE > 8 {
+            return Err(SerializationError::NotEnoughSpace);
+        }
+        let size = buffer_byte_size(GoldilocksParameters::MODULUS_BITS as usize + F::BIT_SIZE);
+
+        let mut bytes = [0u8; 9];
+        reader.read_exact(&mut bytes[..size])?;
+        let flags =
+            F::from_u8_remove_flags(&mut bytes[8]).ok_or(SerializationError::UnexpectedFlags)?;
+        if bytes[8] != 0 {
+            return Err(SerializationError::InvalidData);
+        }
+
+        Ok((Self::read(&bytes[..8])?, flags))
+    }
+}
+
+impl CanonicalDeserialize for Goldilocks {
+    fn deserialize<R: Read>(reader: R) -> Result<Self, SerializationError> {
+        Self::deserialize_with_flags::<R, EmptyFlags>(reader).map(|(value, _)| value)
+    }
+}
+
+impl Distribution<Goldilocks> for Standard {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Goldilocks {
+        loop {
+            let value: u64 = rng.gen();
+            if value < MODULUS {
+                return Goldilocks(value);
+            }
+        }
+    }
+}
+
+/* ----- Field traits ----- */
+impl Field for Goldilocks {
+    type BasePrimeField = Self;
+
+    fn characteristic() -> &'static [u64] {
+        &CHARACTERISTIC
+    }
+
+    fn extension_degree() -> u64 {
+        1
+    }
+
+    fn from_base_prime_field_elems(elems: &[Self]) -> Option<Self> {
+        match elems {
+            [elem] => Some(*elem),
+            _ => None,
+        }
+    }
+
+    fn double(&self) -> Self {
+        *self + self
+    }
+
+    fn double_in_place(&mut self) -> &mut Self {
+        *self = self.double();
+        self
+    }
+
+    fn from_random_bytes_with_flags<F: Flags>(bytes: &[u8]) -> Option<(Self, F)> {
+        if F::BIT_SIZE > 8 {
+            return None;
+        }
+        let size = buffer_byte_size(GoldilocksParameters::MODULUS_BITS as usize + F::BIT_SIZE);
+
+        let mut buffer = [0u8; 9];
+        buffer
+            .iter_mut()
+            .zip(bytes.iter().take(size))
+            .for_each(|(b, byte)| *b = *byte);
+
+        // The flags are in the most significant bits of the additional byte
+        let flags_mask = u8::MAX.checked_shl(8 - F::BIT_SIZE as u32).unwrap_or(0);
+        let flags = F::from_u8(buffer[8] & flags_mask)?;
+
+        let mut limb = [0u8; 8];
+        limb.copy_from_slice(&buffer[..8]);
+        Self::from_repr(BigInteger64([u64::from_le_bytes(limb)])).map(|value| (value, flags))
+    }
+
+    fn square(&self) -> Self {
+        *self * self
+    }
+
+    fn square_in_place(&mut self) -> &mut Self {
+        *self = self.square();
+        self
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        if self.is_zero() {
+            None
+        } else {
+            // Fermat's little theorem, x^(p-2) = x^(-1)
+            Some(self.pow([MODULUS - 2]))
+        }
+    }
+
+    fn inverse_in_place(&mut self) -> Option<&mut Self> {
+        *self = self.inverse()?;
+        Some(self)
+    }
+
+    fn frobenius_map(&mut self, _power: usize) {}
+}
+
+impl FftField for Goldilocks {
+    type FftParams = GoldilocksParameters;
+
+    fn two_adic_root_of_unity() -> Self {
+        Self(GoldilocksParameters::TWO_ADIC_ROOT_OF_UNITY.0[0])
+    }
+
+    fn large_subgroup_root_of_unity() -> Option<Self> {
+        None
+    }
+
+    fn multiplicative_generator() -> Self {
+        Self(GoldilocksParameters::GENERATOR.0[0])
+    }
+}
+
+impl PrimeField for Goldilocks {
+    type Params = GoldilocksParameters;
+    type BigInt = BigInteger64;
+
+    fn from_repr(repr: BigInteger64) -> Option<Self> {
+        if repr.0[0] < MODULUS {
+            Some(Self(repr.0[0]))
+        } else {
+            None
+        }
+    }
+
+    fn into_repr(&self) -> BigInteger64 {
+        BigInteger64([self.0])
+    }
+}
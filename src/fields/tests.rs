@@ -0,0 +1,128 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_ff::{FftField, Field, FpParameters, One, PrimeField, UniformRand, Zero};
+use ark_r1cs_std::{
+    alloc::AllocVar,
+    eq::EqGadget,
+    fields::{fp::FpVar, FieldVar},
+    R1CSVar,
+};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use num_bigint::BigUint;
+
+use super::goldilocks::{Goldilocks, MODULUS};
+
+const P: u128 = MODULUS as u128;
+
+#[test]
+fn goldilocks_arithmetic() {
+    let rng = &mut ark_std::test_rng();
+
+    // The sums and products above 2^64 are the ones breaking ark-ff's Fp64
+    let edges = [0, 1, MODULUS - 1, MODULUS - 2, 1 << 32, u32::MAX as u64];
+    let mut values: Vec<u64> = edges.to_vec();
+    values.extend((0..100).map(|_| Goldilocks::rand(rng).value()));
+
+    for &a in values.iter() {
+        for &b in values.iter() {
+            let (x, y) = (Goldilocks::from(a), Goldilocks::from(b));
+            let (a, b) = (a as u128, b as u128);
+
+            assert_eq!((x + y).value() as u128, (a + b) % P);
+            assert_eq!((x - y).value() as u128, (a + P - b) % P);
+            assert_eq!((x * y).value() as u128, (a * b) % P);
+            if !y.is_zero() {
+                assert_eq!(x / y * y, x);
+            }
+        }
+    }
+
+    assert_eq!(Goldilocks::from(u64::MAX).value(), u32::MAX as u64 - 1);
+    assert_eq!(-Goldilocks::one() + Goldilocks::one(), Goldilocks::zero());
+    assert!(Goldilocks::zero().inverse().is_none());
+}
+
+#[test]
+fn goldilocks_constants() {
+    let p_minus_one = MODULUS - 1;
+
+    // The generator has order p-1 = 2^32 * 3 * 5 * 17 * 257 * 65537
+    let g = Goldilocks::multiplicative_generator();
+    for q in [2, 3, 5, 17, 257, 65537] {
+        assert_ne!(g.pow([p_minus_one / q]), Goldilocks::one());
+    }
+
+    // The root of unity has order 2^32
+    let root = Goldilocks::two_adic_root_of_unity();
+    assert_eq!(root.pow([1u64 << 32]), Goldilocks::one());
+    assert_eq!(root.pow([1u64 << 31]), -Goldilocks::one());
+    assert_eq!(
+        Goldilocks::get_root_of_unity(1 << 4).unwrap().pow([16]),
+        Goldilocks::one()
+    );
+
+    let modulus: BigUint = <Goldilocks as PrimeField>::Params::MODULUS.into();
+    assert_eq!(modulus, BigUint::from(MODULUS));
+    assert_eq!(Goldilocks::size_in_bits(), 64);
+}
+
+#[test]
+fn goldilocks_conversions() {
+    let rng = &mut ark_std::test_rng();
+    let x = Goldilocks::rand(rng);
+
+    let mut bytes = vec![];
+    x.serialize(&mut bytes).unwrap();
+    assert_eq!(bytes.len(), 8);
+    assert_eq!(Goldilocks::deserialize(&bytes[..]).unwrap(), x);
+
+    // p isn't reduced
+    let bytes = MODULUS.to_le_bytes();
+    assert!(Goldilocks::deserialize(&bytes[..]).is_err());
+    assert!(Goldilocks::from_random_bytes(&bytes).is_none());
+
+    let big: BigUint = x.into();
+    assert_eq!(Goldilocks::from(big.clone()), x);
+    assert_eq!(Goldilocks::from(big + BigUint::from(MODULUS)), x);
+    assert_eq!(x.to_string().parse::<Goldilocks>().unwrap(), x);
+    assert_eq!(Goldilocks::from_repr(x.into_repr()).unwrap(), x);
+    assert_eq!(
+        Goldilocks::from_le_bytes_mod_order(&u128::MAX.to_le_bytes()),
+        Goldilocks::from(u128::MAX)
+    );
+}
+
+#[test]
+fn goldilocks_constraints() {
+    let cs = ConstraintSystem::<Goldilocks>::new_ref();
+    let rng = &mut ark_std::test_rng();
+    let (a, b) = (Goldilocks::rand(rng), Goldilocks::rand(rng));
+
+    let x = FpVar::new_witness(cs.clone(), || Ok(a)).unwrap();
+    let y = FpVar::new_witness(cs.clone(), || Ok(b)).unwrap();
+    let z = FpVar::new_input(cs.clone(), || Ok(a * b + a.pow([7]) - b.inverse().unwrap())).unwrap();
+
+    let res = &x * &y + x.pow_by_constant([7]).unwrap() - y.inverse().unwrap();
+    res.enforce_equal(&z).unwrap();
+
+    assert_eq!(res.value().unwrap(), z.value().unwrap());
+    assert!(cs.is_satisfied().unwrap());
+}
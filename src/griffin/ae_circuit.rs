@@ -22,10 +22,7 @@ use ark_r1cs_std::{
 };
 use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
 
-use crate::{
-    api::{Sponge, SpongeAPI},
-    common::pattern::gen_ae_pattern,
-};
+use crate::{aead, api::Sponge, common::enforce_distinct, key_commitment::KeyedCircuit};
 
 use super::chip::GriffinChip;
 
@@ -44,42 +41,29 @@ impl<F: PrimeField> GriffinAECircuit<F> {
     /// This function encrypt a message over F (N blocks -> one field element in F)
     /// It takes a key and a nonce and will return a ciphertext made of N+1 elements in F
     /// The last of these elements is the tag, allowing authenticated encryption
-    /// It follows the [SAFE API specification](https://hackmd.io/bHgsH6mMStCVibM_wYvb2w)
+    /// It follows the [SAFE API specification](https://hackmd.io/bHgsH6mMStCVibM_wYvb2w), it is
+    /// [`aead::encrypt`] without associated data
     pub fn encrypt(
         self,
         message: &[FpVar<F>],
         key: FpVar<F>,
         nonce: FpVar<F>,
     ) -> Result<Vec<FpVar<F>>, SynthesisError> {
-        // Generate the AE pattern of the SAFE API using the helper function
-        let pattern = gen_ae_pattern(message.len(), 1, 1);
-
-        let mut ciphertext = Vec::with_capacity(message.len() + 1);
-        let mut sponge = self.sponge;
-
-        // Initialize the sponge with the pattern, then absord the key and nonce
-        sponge.start(pattern, None);
-        sponge.absorb(1, &[key]);
-        sponge.absorb(1, &[nonce]);
-
-        // Generates the ciphertext by iterating over the message
-        for block in message {
-            ciphertext.push(sponge.squeeze(1)[0].clone() + block);
-            sponge.absorb(1, &[block.clone()]);
-        }
-
-        // This is the tag of the message, we append it at the end of the ct
-        ciphertext.push(sponge.squeeze(1)[0].clone());
-
-        let res = sponge.finish();
-        assert!(res.is_ok(), "The sponge didn't finish properly!");
-
-        Ok(ciphertext)
+        aead::encrypt(&self.sponge, &key, &nonce, &[], message)
     }
 }
 
 impl<F: PrimeField> ConstraintSynthesizer<F> for GriffinAECircuit<F> {
     fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        self.generate_keyed_constraints(cs).map(|_| ())
+    }
+}
+
+impl<F: PrimeField> KeyedCircuit<F> for GriffinAECircuit<F> {
+    fn generate_keyed_constraints(
+        self,
+        cs: ConstraintSystemRef<F>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
         let mut m = Vec::with_capacity(self.message.len());
         let mut ct = Vec::with_capacity(self.message.len() + 1);
 
@@ -94,10 +78,82 @@ impl<F: PrimeField> ConstraintSynthesizer<F> for GriffinAECircuit<F> {
         let k = FpVar::new_witness(cs.clone(), || Ok(self.key))?;
         let n = FpVar::new_input(cs, || Ok(self.nonce))?;
 
-        let result = self.encrypt(&m, k, n)?;
+        let result = self.encrypt(&m, k.clone(), n)?;
+
+        result.enforce_equal(&ct)?;
+
+        Ok(vec![k])
+    }
+}
+
+/// Authenticated encryption with associated data, see [`crate::aead`]
+///
+/// The ciphertext (followed by the tag), the nonce and the associated data are public inputs.
+#[derive(Clone)]
+pub struct GriffinAEADCircuit<F: PrimeField> {
+    pub sponge: Sponge<GriffinChip<F>>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+    pub key: F,
+    pub nonce: F,
+    pub ad: Vec<F>,
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for GriffinAEADCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let k = FpVar::new_witness(cs.clone(), || Ok(self.key))?;
+        let n = FpVar::new_input(cs.clone(), || Ok(self.nonce))?;
+        let ad = Vec::new_input(cs, || Ok(self.ad))?;
+
+        let result = aead::encrypt(&self.sponge, &k, &n, &ad, &m)?;
 
         result.enforce_equal(&ct)?;
 
         Ok(())
     }
 }
+
+/// Encryption of several messages under the same key, each with its own nonce
+///
+/// The ciphertexts and the nonces are public inputs, in the order ciphertext then nonce for each
+/// message as in `GriffinAECircuit`. The circuit enforces that the nonces are distinct.
+#[derive(Clone)]
+pub struct GriffinAEBatchCircuit<F: PrimeField> {
+    pub sponge: Sponge<GriffinChip<F>>,
+    pub messages: Vec<Vec<F>>,
+    pub ciphertexts: Vec<Vec<F>>,
+    pub key: F,
+    pub nonces: Vec<F>,
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for GriffinAEBatchCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        if self.ciphertexts.len() != self.messages.len() || self.nonces.len() != self.messages.len()
+        {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let k = FpVar::new_witness(cs.clone(), || Ok(self.key))?;
+        let mut nonces = Vec::with_capacity(self.nonces.len());
+
+        for ((message, ciphertext), nonce) in self
+            .messages
+            .into_iter()
+            .zip(self.ciphertexts)
+            .zip(self.nonces)
+        {
+            let m = Vec::new_witness(cs.clone(), || Ok(message))?;
+            let ct = Vec::new_input(cs.clone(), || Ok(ciphertext))?;
+            let n = FpVar::new_input(cs.clone(), || Ok(nonce))?;
+
+            let result = aead::encrypt(&self.sponge, &k, &n, &[], &m)?;
+
+            result.enforce_equal(&ct)?;
+            nonces.push(n);
+        }
+
+        enforce_distinct(&nonces)
+    }
+}
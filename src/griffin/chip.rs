@@ -19,8 +19,12 @@ use ark_ff::PrimeField;
 use ark_r1cs_std::{fields::fp::FpVar, prelude::FieldVar};
 
 use crate::{
-    api::{ChipAPI, Sponge},
-    common::pattern::IOPattern,
+    api::{permute_native, ChipAPI, Permutation, Sponge},
+    common::{
+        params_file::ParameterFile,
+        rounds::{griffin_rounds, SECURITY_LEVEL},
+        validation::*,
+    },
     griffin::params::*,
 };
 
@@ -30,101 +34,163 @@ pub struct GriffinParameters<F: PrimeField> {
     pub round_constants: Vec<FpVar<F>>,
     pub alpha: FpVar<F>,
     pub beta: FpVar<F>,
-    pub d: [u64; 4],
-    pub d_inv: [u64; 4],
+    pub d: Vec<u64>,
+    pub d_inv: Vec<u64>,
+}
+
+impl<F: PrimeField> GriffinParameters<F> {
+    /// Loads the parameters from a parameter file over F
+    ///
+    /// It expects the `rounds` number of rounds and the `d`, `d_inv`, `alpha`, `beta`
+    /// and `round_constants` constants.
+    pub fn from_file(file: &ParameterFile) -> Result<Self, anyhow::Error> {
+        file.check::<F>("griffin", M)?;
+
+        Ok(Self {
+            nb_rounds: file.rounds("rounds")?,
+            round_constants: file.constants("round_constants")?,
+            alpha: FpVar::Constant(file.element("alpha")?),
+            beta: FpVar::Constant(file.element("beta")?),
+            d: file.exponent("d")?,
+            d_inv: file.exponent("d_inv")?,
+        })
+    }
+
+    /// Checks that the parameters define a valid Griffin permutation
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        if self.nb_rounds == 0 {
+            return Err(anyhow::Error::msg("Griffin needs at least one round"));
+        }
+        check_count(
+            "round constants",
+            self.round_constants.len(),
+            M * (self.nb_rounds - 1),
+        )?;
+        check_exponent::<F>(&self.d)?;
+        check_exponent_inverse::<F>(&self.d, &self.d_inv)?;
+
+        // The non-linear layer of the last branch is only a permutation if this holds
+        let alpha = value(&self.alpha)?;
+        let beta = value(&self.beta)?;
+        if !is_non_residue(alpha.square() - beta.double().double()) {
+            return Err(anyhow::Error::msg(
+                "alpha^2 - 4 * beta must be a quadratic non-residue",
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that the number of rounds reaches the recommended one for a security level (in bits)
+    pub fn check_rounds(&self, security_level: usize) -> Result<(), anyhow::Error> {
+        let recommended = griffin_rounds(M, small_exponent("d", &self.d)?, security_level)?;
+        if self.nb_rounds < recommended {
+            return Err(anyhow::Error::msg(format!(
+                "Griffin uses {} rounds, {} are recommended for {} bits of security",
+                self.nb_rounds, recommended, security_level
+            )));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
 pub struct GriffinChip<F: PrimeField> {
     parameters: GriffinParameters<F>,
-    state: [FpVar<F>; M],
 }
 
 impl<F: PrimeField> GriffinChip<F> {
+    /// Creates a chip, panics if the parameters are invalid
+    ///
+    /// Parameters with fewer rounds than recommended are accepted, with a warning logged through
+    /// the `log` crate
     pub fn new(parameters: GriffinParameters<F>) -> Self {
-        Self {
-            parameters,
-            state: [FpVar::zero(), FpVar::zero(), FpVar::zero()],
+        if let Err(e) = parameters.validate() {
+            panic!("Invalid Griffin parameters: {}", e);
+        }
+        if let Err(e) = parameters.check_rounds(SECURITY_LEVEL) {
+            log::warn!("{}", e);
         }
+
+        Self { parameters }
     }
-}
 
-impl<F: PrimeField> ChipAPI for GriffinChip<F> {
-    type Value = FpVar<F>;
+    /// Creates a chip, rejecting invalid parameters and those below 128 bits of security
+    pub fn try_new(parameters: GriffinParameters<F>) -> Result<Self, anyhow::Error> {
+        parameters.validate()?;
+        parameters.check_rounds(SECURITY_LEVEL)?;
 
-    fn init(&mut self, tag: u128) -> Result<(), anyhow::Error> {
-        // This allows to "reset" when called with 0
-        self.state = [FpVar::zero(), FpVar::zero(), FpVar::zero()];
-        self.state[1] = FpVar::Constant(F::from(tag));
-        Ok(())
+        Ok(Self { parameters })
     }
+}
 
-    fn read(&mut self) -> Self::Value {
-        self.state[0].clone()
+impl<F: PrimeField> Permutation<FpVar<F>> for GriffinChip<F> {
+    fn width(&self) -> usize {
+        M
     }
 
-    fn add(&mut self, val: &Self::Value) -> Result<(), anyhow::Error> {
-        self.state[0] += val;
-        Ok(())
-    }
+    fn permute(&self, state: &mut [FpVar<F>]) -> Result<(), anyhow::Error> {
+        check_count("state elements", state.len(), M)?;
 
-    fn permutation(&mut self) -> Result<(), anyhow::Error> {
         // First we apply the MDS(m) initial operation
-        let sum = self.state[0].clone() + self.state[1].clone() + self.state[2].clone();
-        self.state[0] = self.state[0].clone() + sum.clone();
-        self.state[1] = self.state[1].clone() + sum.clone();
-        self.state[2] = self.state[2].clone() + sum;
+        let sum = state[0].clone() + state[1].clone() + state[2].clone();
+        state[0] = state[0].clone() + sum.clone();
+        state[1] = state[1].clone() + sum.clone();
+        state[2] = state[2].clone() + sum;
 
         for i in 0..self.parameters.nb_rounds - 1 {
             // Apply S
-            self.state[0] = self.state[0]
-                .clone()
-                .pow_by_constant(self.parameters.d_inv)?;
-            self.state[1] = self.state[1].clone().pow_by_constant(self.parameters.d)?;
-            self.state[2] = (self.state[0].clone() + self.state[1].clone()).square()?
-                + self.parameters.alpha.clone() * (self.state[0].clone() + self.state[1].clone())
+            state[0] = state[0].clone().pow_by_constant(&self.parameters.d_inv)?;
+            state[1] = state[1].clone().pow_by_constant(&self.parameters.d)?;
+            state[2] = (state[0].clone() + state[1].clone()).square()?
+                + self.parameters.alpha.clone() * (state[0].clone() + state[1].clone())
                 + self.parameters.beta.clone();
 
             // Apply M and C
-            let sum = self.state[0].clone() + self.state[1].clone() + self.state[2].clone();
-            self.state[0] = self.state[0].clone()
-                + sum.clone()
-                + self.parameters.round_constants[3 * i].clone();
-            self.state[1] = self.state[1].clone()
-                + sum.clone()
-                + self.parameters.round_constants[3 * i + 1].clone();
-            self.state[2] =
-                self.state[2].clone() + sum + self.parameters.round_constants[3 * i + 2].clone();
+            let sum = state[0].clone() + state[1].clone() + state[2].clone();
+            state[0] =
+                state[0].clone() + sum.clone() + self.parameters.round_constants[3 * i].clone();
+            state[1] =
+                state[1].clone() + sum.clone() + self.parameters.round_constants[3 * i + 1].clone();
+            state[2] = state[2].clone() + sum + self.parameters.round_constants[3 * i + 2].clone();
         }
 
         // Apply S
-        self.state[0] = self.state[0]
-            .clone()
-            .pow_by_constant(self.parameters.d_inv)?;
-        self.state[1] = self.state[1].clone().pow_by_constant(self.parameters.d)?;
-        self.state[2] = (self.state[0].clone() + self.state[1].clone()).square()?
-            + self.parameters.alpha.clone() * (self.state[0].clone() + self.state[1].clone())
+        state[0] = state[0].clone().pow_by_constant(&self.parameters.d_inv)?;
+        state[1] = state[1].clone().pow_by_constant(&self.parameters.d)?;
+        state[2] = (state[0].clone() + state[1].clone()).square()?
+            + self.parameters.alpha.clone() * (state[0].clone() + state[1].clone())
             + self.parameters.beta.clone();
 
         // Apply M - without the RC for the final one
-        let sum = self.state[0].clone() + self.state[1].clone() + self.state[2].clone();
-        self.state[0] = self.state[0].clone() + sum.clone();
-        self.state[1] = self.state[1].clone() + sum.clone();
-        self.state[2] = self.state[2].clone() + sum;
+        let sum = state[0].clone() + state[1].clone() + state[2].clone();
+        state[0] = state[0].clone() + sum.clone();
+        state[1] = state[1].clone() + sum.clone();
+        state[2] = state[2].clone() + sum;
 
         Ok(())
     }
 }
 
+impl<F: PrimeField> Permutation<F> for GriffinChip<F> {
+    fn width(&self) -> usize {
+        M
+    }
+
+    fn permute(&self, state: &mut [F]) -> Result<(), anyhow::Error> {
+        permute_native(self, state)
+    }
+}
+
+impl<F: PrimeField> ChipAPI for GriffinChip<F> {
+    type Value = FpVar<F>;
+
+    fn constant(x: u128) -> FpVar<F> {
+        FpVar::Constant(F::from(x))
+    }
+}
+
 impl<F: PrimeField> Sponge<GriffinChip<F>> {
     pub fn new(chip: GriffinChip<F>) -> Self {
-        Self {
-            chip,
-            absord_pos: 0,
-            squeeze_pos: 0,
-            op_count: 0,
-            pattern: IOPattern::new(vec![]),
-            rate: R,
-        }
+        Self::with_rate(chip, R)
     }
 }
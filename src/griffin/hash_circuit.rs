@@ -51,8 +51,7 @@ impl<F: PrimeField> GriffinHashCircuit<F> {
         sponge.start(pattern, None);
         sponge.absorb(message.len() as u32, message);
         let hash = sponge.squeeze(1)[0].clone();
-        let res = sponge.finish();
-        assert!(res.is_ok(), "The sponge didn't finish properly!");
+        sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
 
         Ok(hash)
     }
@@ -60,7 +59,7 @@ impl<F: PrimeField> GriffinHashCircuit<F> {
 
 impl<F: PrimeField> ConstraintSynthesizer<F> for GriffinHashCircuit<F> {
     fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
-        let mut v = Vec::with_capacity(self.message.len() as usize);
+        let mut v = Vec::with_capacity(self.message.len());
 
         for elem in self.message.iter() {
             v.push(FpVar::new_witness(cs.clone(), || Ok(elem))?);
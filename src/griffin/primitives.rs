@@ -17,56 +17,63 @@
 
 /*!
 This module contains primitives used only for testing purpose with Griffin
-They implement both hashing and authenticated encryption.
+They implement both hashing and authenticated encryption, on any field with bundled parameters.
 */
 
 #![allow(unused)] // Only used within tests
 
-pub struct GriffinPrimitivesBlsFr {}
+use std::marker::PhantomData;
 
+use ark_ff::PrimeField;
 use ark_r1cs_std::{fields::fp::FpVar, prelude::FieldVar};
 use ark_relations::r1cs::SynthesisError;
 
 use ark_bls12_381::Fr as BlsFr;
-use ark_ff::BigInteger256 as I256;
 
-use crate::{common::pattern::*, griffin::params::*};
+use crate::{
+    common::pattern::*,
+    griffin::{chip::GriffinParameters, params::*},
+};
 
-impl GriffinPrimitivesBlsFr {
-    fn permutation(mut state: [FpVar<BlsFr>; 3]) -> Result<[FpVar<BlsFr>; 3], anyhow::Error> {
+pub struct GriffinPrimitives<F: PrimeField> {
+    _field: PhantomData<F>,
+}
+
+pub type GriffinPrimitivesBlsFr = GriffinPrimitives<BlsFr>;
+
+impl<F: PrimeField> GriffinPrimitives<F> {
+    fn permutation(
+        parameters: &GriffinParameters<F>,
+        mut state: [FpVar<F>; 3],
+    ) -> Result<[FpVar<F>; 3], anyhow::Error> {
         // First we apply the MDS(m) initial operation
         let sum = state[0].clone() + state[1].clone() + state[2].clone();
         state[0] += sum.clone();
         state[1] = state[1].clone() + sum.clone();
         state[2] = state[2].clone() + sum;
 
-        for i in 0..N - 1 {
+        for i in 0..parameters.nb_rounds - 1 {
             // Apply S
-            state[0] = state[0].clone().pow_by_constant(D_INV_BLS381)?;
-            state[1] = state[1].clone().pow_by_constant(D_BLS381)?;
+            state[0] = state[0].clone().pow_by_constant(&parameters.d_inv)?;
+            state[1] = state[1].clone().pow_by_constant(&parameters.d)?;
             state[2] = (state[0].clone() + state[1].clone()).square()?
-                + FpVar::Constant(BlsFr::from(I256(ALPHA))) * (state[0].clone() + state[1].clone())
-                + FpVar::Constant(BlsFr::from(I256(BETA)));
+                + parameters.alpha.clone() * (state[0].clone() + state[1].clone())
+                + parameters.beta.clone();
 
             // Apply M and C
             let sum = state[0].clone() + state[1].clone() + state[2].clone();
-            state[0] = state[0].clone()
-                + sum.clone()
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[3 * i])));
-            state[1] = state[1].clone()
-                + sum.clone()
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[3 * i + 1])));
-            state[2] = state[2].clone()
-                + sum
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[3 * i + 2])));
+            state[0] = state[0].clone() + sum.clone() + parameters.round_constants[3 * i].clone();
+            state[1] =
+                state[1].clone() + sum.clone() + parameters.round_constants[3 * i + 1].clone();
+            state[2] = state[2].clone() + sum + parameters.round_constants[3 * i + 2].clone();
         }
 
         // Apply S
-        state[0] = state[0].clone().pow_by_constant(D_INV_BLS381)?;
-        state[1] = state[1].clone().pow_by_constant(D_BLS381)?;
+        state[0] = state[0].clone().pow_by_constant(&parameters.d_inv)?;
+        state[1] = state[1].clone().pow_by_constant(&parameters.d)?;
         state[2] = (state[0].clone() + state[1].clone()).square()?
-            + FpVar::Constant(BlsFr::from(I256(ALPHA))) * (state[0].clone() + state[1].clone())
-            + FpVar::Constant(BlsFr::from(I256(BETA)));
+            + parameters.alpha.clone() * (state[0].clone() + state[1].clone())
+            + parameters.beta.clone();
 
         // Apply M - without the RC for the final one
         let sum = state[0].clone() + state[1].clone() + state[2].clone();
@@ -77,33 +84,27 @@ impl GriffinPrimitivesBlsFr {
         Ok(state)
     }
 
-    pub fn hash(message: Vec<BlsFr>) -> Result<FpVar<BlsFr>, anyhow::Error> {
+    pub fn hash(message: Vec<F>) -> Result<FpVar<F>, anyhow::Error> {
+        let parameters = bundled::<F>()?;
         let mut state = [
             FpVar::zero(),
-            FpVar::constant(BlsFr::from(
-                gen_hash_pattern(message.len(), 1).get_tag(None),
-            )),
+            FpVar::constant(F::from(gen_hash_pattern(message.len(), 1).get_tag(None))),
             FpVar::zero(),
         ];
 
         for element in message {
             state[0] = state[0].clone() + element;
-            state = Self::permutation(state)?;
+            state = Self::permutation(&parameters, state)?;
         }
 
         Ok(state[0].clone())
     }
 
-    pub fn ae(
-        message: Vec<BlsFr>,
-        key: BlsFr,
-        nonce: BlsFr,
-    ) -> Result<Vec<FpVar<BlsFr>>, anyhow::Error> {
+    pub fn ae(message: Vec<F>, key: F, nonce: F) -> Result<Vec<FpVar<F>>, anyhow::Error> {
+        let parameters = bundled::<F>()?;
         let mut state = [
             FpVar::zero(),
-            FpVar::constant(BlsFr::from(
-                gen_ae_pattern(message.len(), 1, 1).get_tag(None),
-            )),
+            FpVar::constant(F::from(gen_ae_pattern(message.len(), 1, 1).get_tag(None))),
             FpVar::zero(),
         ];
 
@@ -111,15 +112,15 @@ impl GriffinPrimitivesBlsFr {
 
         // Absorb both the key and the nonce
         state[0] = state[0].clone() + key;
-        state = Self::permutation(state)?;
+        state = Self::permutation(&parameters, state)?;
         state[0] = state[0].clone() + nonce;
-        state = Self::permutation(state)?;
+        state = Self::permutation(&parameters, state)?;
 
         // Get the ciphertext from the sponge and the message
         for block in message {
             ret.push(state[0].clone() + block);
             state[0] = state[0].clone() + block;
-            state = Self::permutation(state)?;
+            state = Self::permutation(&parameters, state)?;
         }
 
         ret.push(state[0].clone());
@@ -17,45 +17,44 @@
 
 #![cfg(test)]
 
+use crate::api::Sponge;
+use crate::common::test_utils::is_satisfied;
 use crate::griffin::params::*;
-use crate::griffin::primitives::GriffinPrimitivesBlsFr;
-use crate::{api::Sponge, griffin::params::ROUND_CONSTANTS};
+use crate::griffin::primitives::{GriffinPrimitives, GriffinPrimitivesBlsFr};
 
-use crate::common::test_utils::to_bls;
+use crate::common::rounds::{griffin_rounds, SECURITY_LEVEL};
 
+use ark_bls12_377::Fr as Bls377Fr;
 use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::{Bn254, Fr as BnFr};
+use ark_pallas::Fr as PallasFr;
+use ark_vesta::Fr as VestaFr;
 
-use ark_ff::{BigInteger256 as I256, UniformRand};
+use ark_ff::{Field, One, PrimeField, UniformRand};
 use ark_groth16::Groth16;
 use ark_marlin::{AHPForR1CS, Marlin};
 use ark_poly::univariate::DensePolynomial;
 use ark_poly_commit::marlin_pc::MarlinKZG10;
 use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
 use ark_snark::SNARK;
 use blake2::Blake2s;
 
-use super::ae_circuit::GriffinAECircuit;
+use super::ae_circuit::{GriffinAEBatchCircuit, GriffinAECircuit};
 use super::chip::GriffinChip;
 use super::chip::GriffinParameters;
 use super::hash_circuit::GriffinHashCircuit;
+use crate::common::params_file::{Constants, ParameterFile};
 
 type GrifChpBls381 = GriffinChip<BlsFr>;
 type GrifHshCrcBls381 = GriffinHashCircuit<BlsFr>;
-type GrifPrmBls381 = GriffinParameters<BlsFr>;
 
 type GrifSpnBls381 = Sponge<GrifChpBls381>;
 type GrifAECrcBls381 = GriffinAECircuit<BlsFr>;
 
 #[test]
 fn marlin_hash_bls() {
-    let parameters = GrifPrmBls381 {
-        nb_rounds: N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(BETA))),
-        d: D_BLS381,
-        d_inv: D_INV_BLS381,
-    };
+    let parameters = bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -100,14 +99,7 @@ fn marlin_hash_bls() {
 
 #[test]
 fn groth16_hash_bls() {
-    let parameters = GrifPrmBls381 {
-        nb_rounds: N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(BETA))),
-        d: D_BLS381,
-        d_inv: D_INV_BLS381,
-    };
+    let parameters = bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -149,14 +141,7 @@ fn groth16_hash_bls() {
 
 #[test]
 fn marlin_ae_bls() {
-    let parameters = GrifPrmBls381 {
-        nb_rounds: N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(BETA))),
-        d: D_BLS381,
-        d_inv: D_INV_BLS381,
-    };
+    let parameters = bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -201,14 +186,7 @@ fn marlin_ae_bls() {
 
 #[test]
 fn groth16_ae_bls() {
-    let parameters = GrifPrmBls381 {
-        nb_rounds: N,
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        alpha: FpVar::Constant(BlsFr::from(I256(ALPHA))),
-        beta: FpVar::Constant(BlsFr::from(I256(BETA))),
-        d: D_BLS381,
-        d_inv: D_INV_BLS381,
-    };
+    let parameters = bls12_381();
     let chip = GrifChpBls381::new(parameters);
 
     let sponge = GrifSpnBls381::new(chip);
@@ -248,3 +226,213 @@ fn groth16_ae_bls() {
     let res = GrothSetup::verify(&vk, &public, &proof).unwrap();
     assert!(res);
 }
+
+#[test]
+fn rounds_bls() {
+    assert_eq!(griffin_rounds(M, 5, SECURITY_LEVEL).unwrap(), N);
+    assert!(griffin_rounds(M, 5, 100_000).is_err());
+
+    let mut parameters = bls12_381();
+    parameters.nb_rounds = N - 1;
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_err());
+
+    // The exponent is checked before it is read
+    let mut parameters = bls12_381();
+    parameters.d.clear();
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_err());
+}
+
+#[test]
+fn validation_bls() {
+    let parameters = bls12_381();
+    assert!(GrifChpBls381::try_new(parameters.clone()).is_ok());
+
+    let mut invalid = parameters.clone();
+    invalid.round_constants.pop();
+    assert!(GrifChpBls381::try_new(invalid).is_err());
+
+    let mut invalid = parameters.clone();
+    invalid.d_inv = invalid.d.clone();
+    assert!(invalid.validate().is_err());
+
+    // alpha^2 - 4 * beta is zero, hence not a non-residue
+    let mut invalid = parameters;
+    let alpha = invalid.alpha.value().unwrap();
+    invalid.beta = FpVar::Constant(alpha.square() / BlsFr::from(4u32));
+    assert!(invalid.validate().is_err());
+}
+
+#[test]
+fn params_file_bls() {
+    let file = ParameterFile::from_json(BLS12_381).unwrap();
+    assert!(GriffinParameters::<BlsFr>::from_file(&file).is_ok());
+
+    // The same file written in TOML
+    let toml = toml::to_string(&file).unwrap();
+    assert_eq!(ParameterFile::from_toml(&toml).unwrap(), file);
+
+    let mut invalid = file.clone();
+    invalid.primitive = "neptune".to_string();
+    assert!(GriffinParameters::<BlsFr>::from_file(&invalid).is_err());
+
+    let mut invalid = file.clone();
+    invalid.field_modulus = "0x1fffffffffffffff".to_string();
+    assert!(GriffinParameters::<BlsFr>::from_file(&invalid).is_err());
+
+    // The constants must be reduced modulo p
+    let mut invalid = file.clone();
+    invalid.constants.insert(
+        "alpha".to_string(),
+        Constants::Single(file.field_modulus.clone()),
+    );
+    assert!(GriffinParameters::<BlsFr>::from_file(&invalid).is_err());
+
+    let mut invalid = file;
+    invalid.constants.remove("beta");
+    assert!(GriffinParameters::<BlsFr>::from_file(&invalid).is_err());
+}
+
+#[test]
+fn bundled_cached_bls() {
+    let file = ParameterFile::from_json(BLS12_381).unwrap();
+    let expected = GriffinParameters::<BlsFr>::from_file(&file)
+        .unwrap()
+        .round_constants
+        .value()
+        .unwrap();
+
+    // The first load parses the file, the next ones (and the ones of other threads) reuse it
+    assert_eq!(bls12_381().round_constants.value().unwrap(), expected);
+    assert_eq!(bls12_381().round_constants.value().unwrap(), expected);
+    let other_thread =
+        std::thread::spawn(|| bundled::<BlsFr>().unwrap().round_constants.value().unwrap());
+    assert_eq!(other_thread.join().unwrap(), expected);
+}
+
+/// Checks the hash and AE circuits against the native primitives on the field F
+fn native_vs_circuit<F: PrimeField>() {
+    let chip = GriffinChip::try_new(bundled::<F>().unwrap()).unwrap();
+    let sponge = Sponge::<GriffinChip<F>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![F::rand(rng), F::rand(rng)];
+    let key = F::rand(rng);
+    let nonce = F::rand(rng);
+
+    let hash = GriffinPrimitives::<F>::hash(message.clone())
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = GriffinHashCircuit {
+        sponge: sponge.clone(),
+        message: message.clone(),
+        hash,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit;
+    invalid.hash += F::one();
+    assert!(!is_satisfied(invalid));
+
+    let ciphertext = GriffinPrimitives::<F>::ae(message.clone(), key, nonce)
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = GriffinAECircuit {
+        sponge,
+        message,
+        ciphertext,
+        key,
+        nonce,
+    };
+    assert!(is_satisfied(circuit));
+}
+
+#[test]
+fn other_fields() {
+    native_vs_circuit::<BnFr>();
+    native_vs_circuit::<Bls377Fr>();
+    native_vs_circuit::<PallasFr>();
+    native_vs_circuit::<VestaFr>();
+}
+
+#[test]
+fn groth16_hash_bn254() {
+    let chip = GriffinChip::try_new(bundled::<BnFr>().unwrap()).unwrap();
+    let sponge = Sponge::<GriffinChip<BnFr>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BnFr::rand(rng)];
+    let hash = GriffinPrimitives::<BnFr>::hash(message.clone())
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = GriffinHashCircuit {
+        message,
+        hash,
+        sponge,
+    };
+
+    type GrothSetup = Groth16<Bn254>;
+
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    let res = GrothSetup::verify(&vk, &[hash], &proof).unwrap();
+    assert!(res);
+}
+
+#[test]
+fn batch_ae_bls() {
+    let chip = GriffinChip::try_new(bundled::<BlsFr>().unwrap()).unwrap();
+    let sponge = Sponge::<GriffinChip<BlsFr>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let key = BlsFr::rand(rng);
+    let messages: Vec<Vec<BlsFr>> = [2, 0, 3]
+        .iter()
+        .map(|len| (0..*len).map(|_| BlsFr::rand(rng)).collect())
+        .collect();
+    let nonces: Vec<BlsFr> = (0..3).map(|_| BlsFr::rand(rng)).collect();
+    let ciphertexts: Vec<Vec<BlsFr>> = messages
+        .iter()
+        .zip(&nonces)
+        .map(|(m, n)| {
+            GriffinPrimitives::<BlsFr>::ae(m.clone(), key, *n)
+                .unwrap()
+                .value()
+                .unwrap()
+        })
+        .collect();
+
+    let circuit = GriffinAEBatchCircuit {
+        sponge,
+        messages: messages.clone(),
+        ciphertexts,
+        key,
+        nonces: nonces.clone(),
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit.clone();
+    invalid.ciphertexts[2][0] += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+
+    // Valid encryptions under a repeated nonce can't be proven, there is no witness for the
+    // inverse of the difference of the nonces
+    let mut repeated = circuit.clone();
+    repeated.nonces[2] = nonces[0];
+    repeated.ciphertexts[2] = GriffinPrimitives::<BlsFr>::ae(messages[2].clone(), key, nonces[0])
+        .unwrap()
+        .value()
+        .unwrap();
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    assert!(repeated.generate_constraints(cs).is_err());
+
+    let mut invalid = circuit;
+    invalid.key += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+}
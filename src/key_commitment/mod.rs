@@ -0,0 +1,110 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Binding the key of an encryption circuit to a public commitment
+//!
+//! The AE circuits witness their key freely, a proof only says that the ciphertext encrypts some
+//! message under some key. Wrapping a circuit with `with_key_commitment` additionally enforces
+//! that the hash of the key and of a secret salt is a public commitment, e.g. of a registered key.
+//! Any of the sponges can be used, the commitment is the last public input.
+
+use std::marker::PhantomData;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, EqGadget},
+    R1CSVar,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::{
+    api::{ChipAPI, Sponge},
+    crh,
+};
+
+pub mod tests;
+
+/// A circuit with a secret key
+pub trait KeyedCircuit<F: PrimeField>: Sized {
+    /// Generates the constraints of the circuit and returns the variables of its key
+    fn generate_keyed_constraints(
+        self,
+        cs: ConstraintSystemRef<F>,
+    ) -> Result<Vec<FpVar<F>>, SynthesisError>;
+
+    /// Binds the key of the circuit to a public commitment, see `commit_key`
+    fn with_key_commitment<C>(
+        self,
+        sponge: Sponge<C>,
+        salt: F,
+        commitment: F,
+    ) -> KeyCommittedCircuit<F, C, Self>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        KeyCommittedCircuit {
+            circuit: self,
+            sponge,
+            salt,
+            commitment,
+            _field: PhantomData,
+        }
+    }
+}
+
+/// A keyed circuit whose key is bound to a public commitment
+#[derive(Clone)]
+pub struct KeyCommittedCircuit<F: PrimeField, C: ChipAPI, K> {
+    pub circuit: K,
+    pub sponge: Sponge<C>,
+    pub salt: F,
+    pub commitment: F,
+    _field: PhantomData<F>,
+}
+
+impl<F, C, K> ConstraintSynthesizer<F> for KeyCommittedCircuit<F, C, K>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+    K: KeyedCircuit<F>,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let mut key = self.circuit.generate_keyed_constraints(cs.clone())?;
+        key.push(FpVar::new_witness(cs.clone(), || Ok(self.salt))?);
+        let commitment = FpVar::new_input(cs, || Ok(self.commitment))?;
+
+        crh::hash(&self.sponge, &key)?.enforce_equal(&commitment)?;
+
+        Ok(())
+    }
+}
+
+/// Commits natively to a key (all its elements, e.g. both Ciminion master keys) with a salt
+pub fn commit_key<F, C>(sponge: &Sponge<C>, key: &[F], salt: F) -> Result<F, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let elements: Vec<_> = key
+        .iter()
+        .chain([salt].iter())
+        .map(|x| FpVar::Constant(*x))
+        .collect();
+
+    Ok(crh::hash(sponge, &elements)?.value()?)
+}
@@ -0,0 +1,157 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_r1cs_std::R1CSVar;
+use ark_snark::SNARK;
+
+use super::{commit_key, KeyedCircuit};
+use crate::{
+    api::Sponge,
+    ciminion::{chip::CiminionChip, circuit::CiminionCircuit, primitives::CiminionPrimitive},
+    common::test_utils::is_satisfied,
+    griffin::{
+        self, ae_circuit::GriffinAECircuit, chip::GriffinChip, primitives::GriffinPrimitives,
+    },
+    neptune::{
+        self, ae_circuit::NeptuneAECircuit, chip::NeptuneChip, primitives::NeptunePrimitives,
+    },
+    rescue_prime::{self, chip::RescuePrimeChip},
+};
+
+fn griffin<F: PrimeField>() -> Sponge<GriffinChip<F>> {
+    Sponge::<GriffinChip<F>>::new(
+        GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn neptune<F: PrimeField>() -> Sponge<NeptuneChip<F>> {
+    Sponge::<NeptuneChip<F>>::new(
+        NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn rescue_prime<F: PrimeField>() -> Sponge<RescuePrimeChip<F>> {
+    Sponge::<RescuePrimeChip<F>>::new(
+        RescuePrimeChip::try_new(rescue_prime::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+#[test]
+fn key_commitment_ae() {
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BnFr::rand(rng), BnFr::rand(rng)];
+    let (key, nonce, salt) = (BnFr::rand(rng), BnFr::rand(rng), BnFr::rand(rng));
+
+    // Griffin AE, committed with Neptune
+    let commitment = commit_key(&neptune(), &[key], salt).unwrap();
+    let circuit = GriffinAECircuit {
+        sponge: griffin(),
+        message: message.clone(),
+        ciphertext: GriffinPrimitives::<BnFr>::ae(message.clone(), key, nonce)
+            .unwrap()
+            .value()
+            .unwrap(),
+        key,
+        nonce,
+    };
+    assert!(is_satisfied(circuit.clone().with_key_commitment(
+        neptune(),
+        salt,
+        commitment
+    )));
+    assert!(!is_satisfied(circuit.clone().with_key_commitment(
+        neptune(),
+        salt + BnFr::from(1u64),
+        commitment
+    )));
+    // The commitment depends on the hash
+    assert!(!is_satisfied(circuit.with_key_commitment(
+        griffin(),
+        salt,
+        commitment
+    )));
+
+    // Neptune AE, committed with Rescue Prime
+    let commitment = commit_key(&rescue_prime(), &[key], salt).unwrap();
+    let circuit = NeptuneAECircuit {
+        sponge: neptune(),
+        message: message.clone(),
+        ciphertext: NeptunePrimitives::<BnFr>::ae(message, key, nonce)
+            .unwrap()
+            .value()
+            .unwrap(),
+        key,
+        nonce,
+    };
+    assert!(is_satisfied(circuit.clone().with_key_commitment(
+        rescue_prime(),
+        salt,
+        commitment
+    )));
+
+    // Another key encrypting the same message doesn't match the commitment
+    let mut other = circuit;
+    other.key += BnFr::from(1u64);
+    other.ciphertext = NeptunePrimitives::<BnFr>::ae(other.message.clone(), other.key, nonce)
+        .unwrap()
+        .value()
+        .unwrap();
+    assert!(is_satisfied(other.clone()));
+    assert!(!is_satisfied(other.with_key_commitment(
+        rescue_prime(),
+        salt,
+        commitment
+    )));
+}
+
+#[test]
+fn groth16_key_commitment_ciminion_bls() {
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BlsFr::rand(rng)];
+    let keys = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let (nonce, salt) = (BlsFr::rand(rng), BlsFr::rand(rng));
+
+    let ciphertext = CiminionPrimitive::<BlsFr>::encrypt(&message, keys, nonce);
+    let commitment = commit_key(&griffin(), &[keys.0, keys.1], salt).unwrap();
+    let chip = CiminionChip::try_new(crate::ciminion::params::bundled::<BlsFr>().unwrap()).unwrap();
+    let circuit = CiminionCircuit {
+        chip,
+        message,
+        ciphertext: ciphertext.clone(),
+        keys,
+        nonce,
+    }
+    .with_key_commitment(griffin(), salt, commitment);
+
+    type GrothSetup = Groth16<Bls381>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    // The commitment follows the public inputs of the circuit
+    let public = [ciphertext, vec![nonce, commitment]].concat();
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+
+    let mut invalid = public;
+    invalid[3] += BlsFr::from(1u64);
+    assert!(!GrothSetup::verify(&vk, &invalid, &proof).unwrap());
+}
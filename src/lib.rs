@@ -15,9 +15,24 @@
 * along with this program.  If not, see <http://www.gnu.org/licenses/>.
 */
 
+pub mod aead;
 pub mod api;
 pub mod ciminion;
+pub mod commitment;
 pub mod common;
+pub mod compression;
+pub mod crh;
+pub mod duplex;
+pub mod fields;
 pub mod griffin;
+pub mod key_commitment;
+pub mod merkle;
 pub mod neptune;
+pub mod note;
+pub mod pke;
+pub mod predicate;
+pub mod prf;
 pub mod rescue_prime;
+pub mod signature;
+pub mod siv;
+pub mod verifiable;
@@ -0,0 +1,122 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, Boolean, CondSelectGadget, EqGadget},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::api::{ChipAPI, Sponge};
+
+use super::{
+    gadget::{MerklePathVar, SparseMerkleProofVar},
+    sparse::{hash_leaf, SparseMerkleProof},
+    tree::MerklePath,
+};
+
+/// Proves that a private leaf is in the tree of a public root
+///
+/// The sponge must be the one used to build the tree, and the path the one of the leaf.
+#[derive(Clone)]
+pub struct MerkleMembershipCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub sponge: Sponge<C>,
+    pub root: F,
+    pub leaf: F,
+    pub path: MerklePath<F>,
+}
+
+impl<F, C> ConstraintSynthesizer<F> for MerkleMembershipCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let root = FpVar::new_input(cs.clone(), || Ok(self.root))?;
+        let leaf = FpVar::new_witness(cs.clone(), || Ok(self.leaf))?;
+        let path = MerklePathVar::new_witness(cs, || Ok(&self.path))?;
+
+        let result = path.root(&self.sponge, &leaf)?;
+
+        result.enforce_equal(&root)?;
+
+        Ok(())
+    }
+}
+
+/// Proves that the value of a private key was updated, from the tree of a public root to
+/// the tree of another public root
+///
+/// `None` stands for an absent key, hence the update may insert or remove the key. The proof
+/// is the one of the key in the old tree, it stays valid in the new tree as the other
+/// leaves are unchanged.
+#[derive(Clone)]
+pub struct SparseMerkleUpdateCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub sponge: Sponge<C>,
+    pub old_root: F,
+    pub new_root: F,
+    pub key: F,
+    pub old_value: Option<F>,
+    pub new_value: Option<F>,
+    pub proof: SparseMerkleProof<F>,
+}
+
+impl<F, C> SparseMerkleUpdateCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    /// Allocates an optional value and returns the leaf of the key
+    fn leaf(
+        &self,
+        cs: ConstraintSystemRef<F>,
+        key: &FpVar<F>,
+        value: Option<F>,
+    ) -> Result<FpVar<F>, SynthesisError> {
+        let is_present = Boolean::new_witness(cs.clone(), || Ok(value.is_some()))?;
+        let value = FpVar::new_witness(cs, || Ok(value.unwrap_or_else(F::zero)))?;
+
+        let leaf = hash_leaf(&self.sponge, key, &value)?;
+        FpVar::conditionally_select(&is_present, &leaf, &FpVar::Constant(F::zero()))
+    }
+}
+
+impl<F, C> ConstraintSynthesizer<F> for SparseMerkleUpdateCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let old_root = FpVar::new_input(cs.clone(), || Ok(self.old_root))?;
+        let new_root = FpVar::new_input(cs.clone(), || Ok(self.new_root))?;
+        let key = FpVar::new_witness(cs.clone(), || Ok(self.key))?;
+        let old_leaf = self.leaf(cs.clone(), &key, self.old_value)?;
+        let new_leaf = self.leaf(cs.clone(), &key, self.new_value)?;
+        let proof = SparseMerkleProofVar::new_witness(cs, || Ok(&self.proof))?;
+
+        let (path, fits) = proof.path(&key)?;
+        fits.enforce_equal(&Boolean::TRUE)?;
+
+        path.root(&self.sponge, &old_leaf)?
+            .enforce_equal(&old_root)?;
+        path.root(&self.sponge, &new_leaf)?
+            .enforce_equal(&new_root)?;
+
+        Ok(())
+    }
+}
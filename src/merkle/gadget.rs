@@ -0,0 +1,181 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::borrow::Borrow;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, AllocationMode, Boolean, CondSelectGadget, EqGadget},
+    ToBitsGadget,
+};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+
+use crate::api::{ChipAPI, Sponge};
+
+use super::{
+    sparse::{hash_leaf, SparseMerkleProof},
+    tree::{hash_pair, MerklePath},
+};
+
+/// The authentication path of a leaf in the circuit
+///
+/// The directions are `Boolean` witnesses, hence the position of the leaf remains private.
+#[derive(Clone)]
+pub struct MerklePathVar<F: PrimeField> {
+    pub siblings: Vec<FpVar<F>>,
+    pub directions: Vec<Boolean<F>>,
+}
+
+impl<F: PrimeField> MerklePathVar<F> {
+    /// Recomputes the root from a leaf
+    pub fn root<C>(&self, sponge: &Sponge<C>, leaf: &FpVar<F>) -> Result<FpVar<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        let mut node = leaf.clone();
+
+        for (sibling, is_right) in self.siblings.iter().zip(self.directions.iter()) {
+            let left = FpVar::conditionally_select(is_right, sibling, &node)?;
+            let right = FpVar::conditionally_select(is_right, &node, sibling)?;
+            node = hash_pair(sponge, &left, &right)?;
+        }
+
+        Ok(node)
+    }
+
+    /// Returns whether the leaf is in the tree of the given root
+    pub fn verify_membership<C>(
+        &self,
+        sponge: &Sponge<C>,
+        leaf: &FpVar<F>,
+        root: &FpVar<F>,
+    ) -> Result<Boolean<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        self.root(sponge, leaf)?.is_eq(root)
+    }
+}
+
+impl<F: PrimeField> AllocVar<MerklePath<F>, F> for MerklePathVar<F> {
+    fn new_variable<T: Borrow<MerklePath<F>>>(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let cs = cs.into().cs();
+        let path = f()?;
+        let path = path.borrow();
+
+        if path.siblings.len() != path.directions.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let siblings = path
+            .siblings
+            .iter()
+            .map(|sibling| FpVar::new_variable(cs.clone(), || Ok(*sibling), mode))
+            .collect::<Result<_, _>>()?;
+        let directions = path
+            .directions
+            .iter()
+            .map(|is_right| Boolean::new_variable(cs.clone(), || Ok(*is_right), mode))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            siblings,
+            directions,
+        })
+    }
+}
+
+/// The proof of the value of a key, or of its absence, in a sparse Merkle tree
+#[derive(Clone)]
+pub struct SparseMerkleProofVar<F: PrimeField> {
+    pub siblings: Vec<FpVar<F>>,
+}
+
+impl<F: PrimeField> SparseMerkleProofVar<F> {
+    /// The authentication path of the leaf of the key, with whether the key fits in the tree
+    pub fn path(&self, key: &FpVar<F>) -> Result<(MerklePathVar<F>, Boolean<F>), SynthesisError> {
+        let depth = self.siblings.len();
+        let mut directions = key.to_bits_le()?;
+        let fits = if directions.len() > depth {
+            Boolean::kary_or(&directions[depth..])?.not()
+        } else {
+            Boolean::TRUE
+        };
+        directions.truncate(depth);
+
+        let path = MerklePathVar {
+            siblings: self.siblings.clone(),
+            directions,
+        };
+        Ok((path, fits))
+    }
+
+    /// Returns whether the key has the given value in the tree of the given root
+    pub fn verify_membership<C>(
+        &self,
+        sponge: &Sponge<C>,
+        key: &FpVar<F>,
+        value: &FpVar<F>,
+        root: &FpVar<F>,
+    ) -> Result<Boolean<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        let (path, fits) = self.path(key)?;
+        let leaf = hash_leaf(sponge, key, value)?;
+        fits.and(&path.verify_membership(sponge, &leaf, root)?)
+    }
+
+    /// Returns whether the key is absent from the tree of the given root
+    pub fn verify_non_membership<C>(
+        &self,
+        sponge: &Sponge<C>,
+        key: &FpVar<F>,
+        root: &FpVar<F>,
+    ) -> Result<Boolean<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        let (path, fits) = self.path(key)?;
+        fits.and(&path.verify_membership(sponge, &FpVar::Constant(F::zero()), root)?)
+    }
+}
+
+impl<F: PrimeField> AllocVar<SparseMerkleProof<F>, F> for SparseMerkleProofVar<F> {
+    fn new_variable<T: Borrow<SparseMerkleProof<F>>>(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let cs = cs.into().cs();
+        let proof = f()?;
+
+        let siblings = proof
+            .borrow()
+            .siblings
+            .iter()
+            .map(|sibling| FpVar::new_variable(cs.clone(), || Ok(*sibling), mode))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self { siblings })
+    }
+}
@@ -0,0 +1,240 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+
+use crate::api::{ChipAPI, Sponge};
+
+use super::tree::{hash_pair, MerklePath};
+
+/// An append-only Merkle tree of fixed depth, storing only its frontier
+///
+/// The empty leaves are zero, as in `MerkleTree`, hence both trees have the same root
+/// for the same leaves and their paths are verified by the same gadget.
+#[derive(Clone)]
+pub struct IncrementalMerkleTree<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    sponge: Sponge<C>,
+    /// The root of an empty subtree at each level, from the leaves (zero) to the root
+    empty: Vec<F>,
+    /// The last left node at each level
+    frontier: Vec<F>,
+    next_index: usize,
+    root: F,
+    /// The last leaf and its path, used to create a witness
+    last: Option<(F, MerklePath<F>)>,
+}
+
+impl<F, C> IncrementalMerkleTree<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    /// Creates an empty tree with 2^depth leaves
+    pub fn new(sponge: Sponge<C>, depth: usize) -> Result<Self, anyhow::Error> {
+        if depth >= usize::BITS as usize {
+            return Err(anyhow::Error::msg(format!(
+                "The depth must be smaller than {}",
+                usize::BITS
+            )));
+        }
+
+        let mut empty = vec![F::zero()];
+        for level in 0..depth {
+            let node = FpVar::Constant(empty[level]);
+            empty.push(hash_pair(&sponge, &node, &node)?.value()?);
+        }
+
+        Ok(Self::with_empty(sponge, empty))
+    }
+
+    /// Creates an empty tree from the roots of the empty subtrees, which define its depth
+    fn with_empty(sponge: Sponge<C>, empty: Vec<F>) -> Self {
+        let depth = empty.len() - 1;
+
+        Self {
+            sponge,
+            frontier: vec![F::zero(); depth],
+            next_index: 0,
+            root: empty[depth],
+            empty,
+            last: None,
+        }
+    }
+
+    pub fn depth(&self) -> usize {
+        self.frontier.len()
+    }
+
+    /// The number of leaves appended so far
+    pub fn len(&self) -> usize {
+        self.next_index
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.next_index == 0
+    }
+
+    pub fn is_full(&self) -> bool {
+        self.next_index == 1 << self.depth()
+    }
+
+    pub fn root(&self) -> F {
+        self.root
+    }
+
+    /// The root of an empty subtree of the given height
+    pub fn empty_root(&self, level: usize) -> F {
+        self.empty[level]
+    }
+
+    /// Appends a leaf and returns its index, the root is updated with depth hashes
+    pub fn append(&mut self, leaf: F) -> Result<usize, anyhow::Error> {
+        if self.is_full() {
+            return Err(anyhow::Error::msg("The tree is full"));
+        }
+
+        let index = self.next_index;
+        let mut node = leaf;
+        let mut siblings = Vec::with_capacity(self.depth());
+        let mut directions = Vec::with_capacity(self.depth());
+
+        for level in 0..self.depth() {
+            let is_right = (index >> level) & 1 == 1;
+            let (left, right) = if is_right {
+                siblings.push(self.frontier[level]);
+                (self.frontier[level], node)
+            } else {
+                // The right subtree is still empty
+                self.frontier[level] = node;
+                siblings.push(self.empty[level]);
+                (node, self.empty[level])
+            };
+            directions.push(is_right);
+
+            node = hash_pair(
+                &self.sponge,
+                &FpVar::Constant(left),
+                &FpVar::Constant(right),
+            )?
+            .value()?;
+        }
+
+        self.root = node;
+        self.next_index += 1;
+        self.last = Some((
+            leaf,
+            MerklePath {
+                siblings,
+                directions,
+            },
+        ));
+
+        Ok(index)
+    }
+
+    /// The authentication path of the last leaf, as of now
+    pub fn last_path(&self) -> Option<&MerklePath<F>> {
+        self.last.as_ref().map(|(_, path)| path)
+    }
+
+    /// Creates a witness for the last leaf, which can then be updated with the next leaves
+    pub fn witness(&self) -> Result<IncrementalWitness<F, C>, anyhow::Error> {
+        let (leaf, path) = self
+            .last
+            .clone()
+            .ok_or_else(|| anyhow::Error::msg("The tree is empty"))?;
+
+        Ok(IncrementalWitness {
+            sponge: self.sponge.clone(),
+            empty: self.empty.clone(),
+            position: self.next_index - 1,
+            leaf,
+            path,
+            next_level: 0,
+            cursor: None,
+        })
+    }
+}
+
+/// The authentication path of a leaf, kept up to date while the tree grows
+///
+/// The left siblings don't change once the leaf is appended, while each right sibling is
+/// the root of a subtree filled by the next leaves. The witness thus builds the subtree of
+/// the lowest right sibling which isn't complete yet (the cursor).
+#[derive(Clone)]
+pub struct IncrementalWitness<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    sponge: Sponge<C>,
+    empty: Vec<F>,
+    position: usize,
+    leaf: F,
+    path: MerklePath<F>,
+    /// The levels below this one have a complete right sibling (or a left one)
+    next_level: usize,
+    /// The level and the subtree of the right sibling being filled
+    cursor: Option<(usize, IncrementalMerkleTree<F, C>)>,
+}
+
+impl<F, C> IncrementalWitness<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    pub fn leaf(&self) -> F {
+        self.leaf
+    }
+
+    /// The current authentication path of the leaf
+    pub fn path(&self) -> &MerklePath<F> {
+        &self.path
+    }
+
+    /// The root of the tree, as of the last leaf given to the witness
+    pub fn root(&self) -> Result<F, anyhow::Error> {
+        self.path.root(&self.sponge, self.leaf)
+    }
+
+    /// Updates the witness with a leaf appended to the tree after this one
+    pub fn append(&mut self, leaf: F) -> Result<(), anyhow::Error> {
+        let (level, mut cursor) = match self.cursor.take() {
+            Some(cursor) => cursor,
+            None => {
+                let level = (self.next_level..self.path.directions.len())
+                    .find(|level| !self.path.directions[*level])
+                    .ok_or_else(|| anyhow::Error::msg("The tree is full"))?;
+                self.next_level = level + 1;
+
+                let empty = self.empty[..=level].to_vec();
+                (
+                    level,
+                    IncrementalMerkleTree::with_empty(self.sponge.clone(), empty),
+                )
+            }
+        };
+
+        cursor.append(leaf)?;
+        self.path.siblings[level] = cursor.root();
+        if !cursor.is_full() {
+            self.cursor = Some((level, cursor));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,28 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Merkle trees over the zekrom hashes, with a membership proof circuit
+//!
+//! The nodes are hashed with any of the SAFE sponges (Griffin, Neptune or Rescue Prime),
+//! both natively and in the circuit.
+
+pub mod circuit;
+pub mod gadget;
+pub mod incremental;
+pub mod sparse;
+pub mod tests;
+pub mod tree;
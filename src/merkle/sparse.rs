@@ -0,0 +1,287 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use ark_ff::{BigInteger, FpParameters, PrimeField};
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::SynthesisError;
+
+use crate::api::{ChipAPI, Sponge};
+
+use super::tree::{hash_pair, MerklePath};
+
+/// Hashes a key and its value into the leaf of a sparse Merkle tree
+///
+/// The leaf of an absent key is zero, the hash binds the value to its key.
+pub fn hash_leaf<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    value: &FpVar<F>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    hash_pair(sponge, key, value)
+}
+
+/// The leaf of a key, zero if the key is absent
+fn leaf<F, C>(sponge: &Sponge<C>, key: F, value: Option<F>) -> Result<F, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    match value {
+        Some(value) => {
+            Ok(hash_leaf(sponge, &FpVar::Constant(key), &FpVar::Constant(value))?.value()?)
+        }
+        None => Ok(F::zero()),
+    }
+}
+
+/// The index of the leaf of a key, the key must fit in `depth` bits
+fn index<F: PrimeField>(key: F, depth: usize) -> Result<F::BigInt, anyhow::Error> {
+    let index = key.into_repr();
+    if index.num_bits() as usize > depth {
+        return Err(anyhow::Error::msg(format!(
+            "The key {} doesn't fit in a tree of depth {}",
+            key, depth
+        )));
+    }
+    Ok(index)
+}
+
+/// The index of the other child of the same parent
+fn sibling<B: BigInteger>(index: &B) -> B {
+    let mut sibling = *index;
+    if index.is_odd() {
+        sibling.sub_noborrow(&B::from(1));
+    } else {
+        sibling.add_nocarry(&B::from(1));
+    }
+    sibling
+}
+
+/// The proof of the value of a key, or of its absence, in a sparse Merkle tree
+///
+/// The directions of the path are the bits of the key, only the siblings are given.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SparseMerkleProof<F: PrimeField> {
+    /// The sibling of the current node at each level, from the leaf up to the root
+    pub siblings: Vec<F>,
+}
+
+impl<F: PrimeField> SparseMerkleProof<F> {
+    /// The authentication path of the leaf of the key
+    pub fn path(&self, key: F) -> Result<MerklePath<F>, anyhow::Error> {
+        let index = index(key, self.siblings.len())?;
+
+        Ok(MerklePath {
+            siblings: self.siblings.clone(),
+            directions: (0..self.siblings.len()).map(|i| index.get_bit(i)).collect(),
+        })
+    }
+
+    /// Recomputes the root from a key and its value, `None` for an absent key
+    pub fn root<C>(&self, sponge: &Sponge<C>, key: F, value: Option<F>) -> Result<F, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        self.path(key)?.root(sponge, leaf(sponge, key, value)?)
+    }
+
+    /// Checks that the key has the given value (or is absent) in the tree of the given root
+    pub fn verify<C>(
+        &self,
+        sponge: &Sponge<C>,
+        key: F,
+        value: Option<F>,
+        root: F,
+    ) -> Result<bool, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        Ok(self.root(sponge, key, value)? == root)
+    }
+}
+
+/// A key-value map committed by a sparse Merkle tree
+///
+/// The key selects a leaf by its bits (the least significant bit at the bottom), hence
+/// a tree of depth d holds the keys smaller than 2^d. Only the non-empty nodes are stored.
+#[derive(Clone)]
+pub struct SparseMerkleTree<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    sponge: Sponge<C>,
+    /// The root of an empty subtree at each level, from the leaves (zero) to the root
+    empty: Vec<F>,
+    /// The non-empty nodes, indexed by their level and their position in the level
+    nodes: BTreeMap<(usize, F::BigInt), F>,
+    values: BTreeMap<F::BigInt, F>,
+}
+
+impl<F, C> SparseMerkleTree<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    /// Creates an empty tree, the depth is at most the size of the field elements
+    pub fn new(sponge: Sponge<C>, depth: usize) -> Result<Self, anyhow::Error> {
+        let max_depth = F::Params::MODULUS_BITS as usize;
+        if depth == 0 || depth > max_depth {
+            return Err(anyhow::Error::msg(format!(
+                "The depth must be between 1 and {}",
+                max_depth
+            )));
+        }
+
+        let mut empty = vec![F::zero()];
+        for level in 0..depth {
+            let node = FpVar::Constant(empty[level]);
+            empty.push(hash_pair(&sponge, &node, &node)?.value()?);
+        }
+
+        Ok(Self {
+            sponge,
+            empty,
+            nodes: BTreeMap::new(),
+            values: BTreeMap::new(),
+        })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.empty.len() - 1
+    }
+
+    pub fn root(&self) -> F {
+        self.node(self.depth(), &F::BigInt::default())
+    }
+
+    pub fn sponge(&self) -> &Sponge<C> {
+        &self.sponge
+    }
+
+    /// The number of keys in the tree
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value of a key, `None` if the key is absent
+    pub fn get(&self, key: F) -> Option<F> {
+        self.values.get(&key.into_repr()).copied()
+    }
+
+    /// Sets the value of a key, returning its previous value
+    pub fn insert(&mut self, key: F, value: F) -> Result<Option<F>, anyhow::Error> {
+        let previous = self.get(key);
+        self.update_batch(&[(key, Some(value))])?;
+        Ok(previous)
+    }
+
+    /// Removes a key, returning its value
+    pub fn remove(&mut self, key: F) -> Result<Option<F>, anyhow::Error> {
+        let previous = self.get(key);
+        self.update_batch(&[(key, None)])?;
+        Ok(previous)
+    }
+
+    /// Sets (or removes, with `None`) the values of several keys
+    ///
+    /// The ancestors shared by the keys are hashed once. If a key is repeated, its last
+    /// value is kept. Nothing is updated if one of the keys doesn't fit in the tree.
+    pub fn update_batch(&mut self, updates: &[(F, Option<F>)]) -> Result<(), anyhow::Error> {
+        let depth = self.depth();
+        let mut leaves = BTreeMap::new();
+        for (key, value) in updates {
+            leaves.insert(index(*key, depth)?, (*key, *value));
+        }
+
+        let mut positions = BTreeSet::new();
+        for (index, (key, value)) in leaves {
+            let node = leaf(&self.sponge, key, value)?;
+            self.set_node(0, index, node);
+            match value {
+                Some(value) => self.values.insert(index, value),
+                None => self.values.remove(&index),
+            };
+            positions.insert(index);
+        }
+
+        for level in 0..depth {
+            let parents = positions
+                .iter()
+                .map(|position| {
+                    let mut parent = *position;
+                    parent.div2();
+                    parent
+                })
+                .collect::<BTreeSet<_>>();
+
+            for parent in parents.iter() {
+                let mut left = *parent;
+                left.mul2();
+                let right = sibling(&left);
+
+                let node = hash_pair(
+                    &self.sponge,
+                    &FpVar::Constant(self.node(level, &left)),
+                    &FpVar::Constant(self.node(level, &right)),
+                )?
+                .value()?;
+                self.set_node(level + 1, *parent, node);
+            }
+
+            positions = parents;
+        }
+
+        Ok(())
+    }
+
+    /// The proof of the value of a key, or of its absence
+    pub fn proof(&self, key: F) -> Result<SparseMerkleProof<F>, anyhow::Error> {
+        let mut position = index(key, self.depth())?;
+
+        let siblings = (0..self.depth())
+            .map(|level| {
+                let node = self.node(level, &sibling(&position));
+                position.div2();
+                node
+            })
+            .collect();
+
+        Ok(SparseMerkleProof { siblings })
+    }
+
+    fn node(&self, level: usize, position: &F::BigInt) -> F {
+        self.nodes
+            .get(&(level, *position))
+            .copied()
+            .unwrap_or(self.empty[level])
+    }
+
+    fn set_node(&mut self, level: usize, position: F::BigInt, node: F) {
+        if node == self.empty[level] {
+            self.nodes.remove(&(level, position));
+        } else {
+            self.nodes.insert((level, position), node);
+        }
+    }
+}
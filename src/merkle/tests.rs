@@ -0,0 +1,560 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_groth16::Groth16;
+use ark_marlin::Marlin;
+use ark_poly::univariate::DensePolynomial;
+use ark_poly_commit::marlin_pc::MarlinKZG10;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_r1cs_std::{prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystem};
+use ark_snark::SNARK;
+use blake2::Blake2s;
+
+use super::{
+    circuit::{MerkleMembershipCircuit, SparseMerkleUpdateCircuit},
+    gadget::SparseMerkleProofVar,
+    incremental::{IncrementalMerkleTree, IncrementalWitness},
+    sparse::{SparseMerkleProof, SparseMerkleTree},
+    tree::{hash_pair, MerklePath, MerkleTree},
+};
+use crate::{
+    api::{ChipAPI, Sponge},
+    common::test_utils::is_satisfied,
+    griffin::{self, chip::GriffinChip},
+    neptune::{self, chip::NeptuneChip},
+    rescue_prime::{self, chip::RescuePrimeChip},
+};
+
+fn griffin_sponge<F: PrimeField>() -> Sponge<GriffinChip<F>> {
+    let chip = GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap();
+    Sponge::<GriffinChip<F>>::new(chip)
+}
+
+fn neptune_sponge<F: PrimeField>() -> Sponge<NeptuneChip<F>> {
+    let chip = NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap();
+    Sponge::<NeptuneChip<F>>::new(chip)
+}
+
+fn rescue_prime_sponge<F: PrimeField>() -> Sponge<RescuePrimeChip<F>> {
+    let chip = RescuePrimeChip::try_new(rescue_prime::params::bundled::<F>().unwrap()).unwrap();
+    Sponge::<RescuePrimeChip<F>>::new(chip)
+}
+
+/// Builds a tree with random leaves and the membership circuit of one of them
+fn membership<F, C>(sponge: Sponge<C>, depth: usize) -> MerkleMembershipCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let rng = &mut ark_std::test_rng();
+    let leaves = (0..(1 << depth) - 1)
+        .map(|_| F::rand(rng))
+        .collect::<Vec<_>>();
+    let tree = MerkleTree::new(sponge.clone(), depth, &leaves).unwrap();
+
+    let index = leaves.len() / 3;
+    MerkleMembershipCircuit {
+        sponge,
+        root: tree.root(),
+        leaf: leaves[index],
+        path: tree.path(index).unwrap(),
+    }
+}
+
+#[test]
+fn native_tree_bls() {
+    let sponge = griffin_sponge::<BlsFr>();
+    let rng = &mut ark_std::test_rng();
+    let leaves = (0..5).map(|_| BlsFr::rand(rng)).collect::<Vec<_>>();
+    let tree = MerkleTree::new(sponge.clone(), 3, &leaves).unwrap();
+
+    assert_eq!(tree.depth(), 3);
+    assert_eq!(tree.capacity(), 8);
+    assert_eq!(tree.leaves(), &leaves[..]);
+
+    // The root of the tree is the hash of its two subtrees
+    let hash = |l: BlsFr, r: BlsFr| {
+        hash_pair(&sponge, &FpVar::Constant(l), &FpVar::Constant(r))
+            .unwrap()
+            .value()
+            .unwrap()
+    };
+    let zero = BlsFr::zero();
+    let left = hash(hash(leaves[0], leaves[1]), hash(leaves[2], leaves[3]));
+    let right = hash(hash(leaves[4], zero), hash(zero, zero));
+    assert_eq!(tree.root(), hash(left, right));
+
+    for index in 0..tree.capacity() {
+        let leaf = &leaves.get(index).copied().unwrap_or(zero);
+        let path = tree.path(index).unwrap();
+        assert_eq!(path.index(), index);
+        assert!(path.verify(&sponge, *leaf, tree.root()).unwrap());
+        assert!(!path
+            .verify(&sponge, *leaf + BlsFr::from(1u64), tree.root())
+            .unwrap());
+    }
+
+    assert!(tree.path(8).is_err());
+    assert!(MerkleTree::new(sponge.clone(), 2, &leaves).is_err());
+
+    let invalid = MerklePath {
+        siblings: vec![zero],
+        directions: vec![],
+    };
+    assert!(invalid.verify(&sponge, zero, tree.root()).is_err());
+}
+
+#[test]
+fn deep_tree_bls() {
+    let sponge = neptune_sponge::<BlsFr>();
+    let rng = &mut ark_std::test_rng();
+    let depth = 40;
+
+    // Only the nodes above the given leaves are stored
+    let leaves = (0..3).map(|_| BlsFr::rand(rng)).collect::<Vec<_>>();
+    let tree = MerkleTree::new(sponge.clone(), depth, &leaves).unwrap();
+    assert_eq!(tree.capacity(), 1 << depth);
+
+    let mut incremental = IncrementalMerkleTree::new(sponge.clone(), depth).unwrap();
+    for leaf in leaves.iter() {
+        incremental.append(*leaf).unwrap();
+    }
+    assert_eq!(tree.root(), incremental.root());
+
+    let path = tree.path(1).unwrap();
+    assert!(path.verify(&sponge, leaves[1], tree.root()).unwrap());
+    let path = tree.path((1 << depth) - 1).unwrap();
+    assert!(path.verify(&sponge, BlsFr::zero(), tree.root()).unwrap());
+    assert!(tree.path(1 << depth).is_err());
+}
+
+#[test]
+fn invalid_membership() {
+    let circuit = membership(neptune_sponge::<BlsFr>(), 4);
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit.clone();
+    invalid.leaf += BlsFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+
+    // Another position in the tree
+    let mut invalid = circuit.clone();
+    invalid.path.directions[0] = !invalid.path.directions[0];
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.path.siblings[3] += BlsFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn other_hashes() {
+    assert!(is_satisfied(membership(griffin_sponge::<BnFr>(), 2)));
+    assert!(is_satisfied(membership(neptune_sponge::<BnFr>(), 2)));
+    assert!(is_satisfied(membership(rescue_prime_sponge::<BnFr>(), 2)));
+}
+
+#[test]
+fn groth16_griffin_bls381() {
+    let circuit = membership(griffin_sponge::<BlsFr>(), 4);
+    let rng = &mut ark_std::test_rng();
+    let root = circuit.root;
+
+    type GrothSetup = Groth16<Bls381>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &[root], &proof).unwrap());
+    assert!(!GrothSetup::verify(&vk, &[root + BlsFr::from(1u64)], &proof).unwrap());
+}
+
+#[test]
+fn marlin_neptune_bls381() {
+    let circuit = membership(neptune_sponge::<BlsFr>(), 4);
+    let rng = &mut ark_std::test_rng();
+    let root = circuit.root;
+
+    type KZG10 = MarlinKZG10<Bls381, DensePolynomial<BlsFr>>;
+    type MarlinSetup = Marlin<BlsFr, KZG10, Blake2s>;
+    let nc = 20000;
+    let nv = 1;
+    let srs = MarlinSetup::universal_setup(nc, nv, nv, rng).unwrap();
+    let (pk, vk) = MarlinSetup::index(&srs, circuit.clone()).unwrap();
+    let proof = MarlinSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(MarlinSetup::verify(&vk, &[root], &proof, rng).unwrap());
+}
+
+#[test]
+fn incremental_tree_bls() {
+    let sponge = neptune_sponge::<BlsFr>();
+    let rng = &mut ark_std::test_rng();
+    let depth = 4;
+
+    let mut tree = IncrementalMerkleTree::new(sponge.clone(), depth).unwrap();
+    let empty = MerkleTree::new(sponge.clone(), depth, &[]).unwrap();
+    assert!(tree.is_empty());
+    assert_eq!(tree.root(), empty.root());
+    assert!(tree.witness().is_err());
+
+    let mut leaves = vec![];
+    let mut witnesses: Vec<IncrementalWitness<_, _>> = vec![];
+    for index in 0..1 << depth {
+        let leaf = BlsFr::rand(rng);
+        assert_eq!(tree.append(leaf).unwrap(), index);
+        for witness in witnesses.iter_mut() {
+            witness.append(leaf).unwrap();
+        }
+        leaves.push(leaf);
+
+        // The frontier gives the same root and path as the whole tree
+        let full = MerkleTree::new(sponge.clone(), depth, &leaves).unwrap();
+        assert_eq!(tree.root(), full.root());
+        assert_eq!(tree.last_path().unwrap(), &full.path(index).unwrap());
+
+        if index % 5 == 0 {
+            witnesses.push(tree.witness().unwrap());
+        }
+        for witness in witnesses.iter() {
+            assert_eq!(witness.path(), &full.path(witness.position()).unwrap());
+            assert_eq!(witness.root().unwrap(), tree.root());
+        }
+    }
+
+    assert!(tree.is_full());
+    assert!(tree.append(BlsFr::zero()).is_err());
+    assert!(witnesses[0].clone().append(BlsFr::zero()).is_err());
+}
+
+#[test]
+fn incremental_membership() {
+    let sponge = griffin_sponge::<BnFr>();
+    let rng = &mut ark_std::test_rng();
+
+    let mut tree = IncrementalMerkleTree::new(sponge.clone(), 3).unwrap();
+    let leaf = BnFr::rand(rng);
+    tree.append(BnFr::rand(rng)).unwrap();
+    tree.append(leaf).unwrap();
+
+    let mut witness = tree.witness().unwrap();
+    // The witness is the one of the last leaf appended
+    assert_eq!(witness.leaf(), leaf);
+    assert_eq!(witness.position(), 1);
+    for _ in 0..3 {
+        let next = BnFr::rand(rng);
+        tree.append(next).unwrap();
+        witness.append(next).unwrap();
+    }
+
+    // The path of the witness is verified by the gadget used for the static trees
+    let circuit = MerkleMembershipCircuit {
+        sponge,
+        root: tree.root(),
+        leaf,
+        path: witness.path().clone(),
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit;
+    invalid.root = tree.empty_root(3);
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn sparse_tree_bn() {
+    let sponge = neptune_sponge::<BnFr>();
+    let rng = &mut ark_std::test_rng();
+
+    // Every key fits in a tree of depth 254 on BN254
+    let mut tree = SparseMerkleTree::new(sponge.clone(), 254).unwrap();
+    let empty = tree.root();
+    assert!(SparseMerkleTree::new(sponge.clone(), 0).is_err());
+    assert!(SparseMerkleTree::new(sponge.clone(), 255).is_err());
+
+    let entries = (0..4)
+        .map(|_| (BnFr::rand(rng), BnFr::rand(rng)))
+        .collect::<Vec<_>>();
+    for (key, value) in entries.iter() {
+        assert_eq!(tree.insert(*key, *value).unwrap(), None);
+    }
+    assert_eq!(tree.len(), 4);
+
+    let absent = BnFr::rand(rng);
+    for (key, value) in entries.iter() {
+        let proof = tree.proof(*key).unwrap();
+        assert_eq!(tree.get(*key), Some(*value));
+        assert!(proof
+            .verify(&sponge, *key, Some(*value), tree.root())
+            .unwrap());
+        assert!(!proof.verify(&sponge, *key, None, tree.root()).unwrap());
+        assert!(!proof
+            .verify(&sponge, *key, Some(*value + BnFr::from(1u64)), tree.root())
+            .unwrap());
+        assert!(!proof.verify(&sponge, absent, None, tree.root()).unwrap());
+    }
+
+    let proof = tree.proof(absent).unwrap();
+    assert_eq!(tree.get(absent), None);
+    assert!(proof.verify(&sponge, absent, None, tree.root()).unwrap());
+    assert!(!proof
+        .verify(&sponge, absent, Some(BnFr::zero()), tree.root())
+        .unwrap());
+
+    // A batch gives the same tree as the updates one by one
+    let updates = vec![
+        (entries[0].0, Some(BnFr::from(7u64))),
+        (entries[1].0, None),
+        (absent, Some(BnFr::from(1u64))),
+        (absent, Some(BnFr::from(2u64))),
+    ];
+    let mut batch = tree.clone();
+    batch.update_batch(&updates).unwrap();
+    assert_eq!(
+        tree.insert(entries[0].0, BnFr::from(7u64)).unwrap(),
+        Some(entries[0].1)
+    );
+    assert_eq!(tree.remove(entries[1].0).unwrap(), Some(entries[1].1));
+    tree.insert(absent, BnFr::from(2u64)).unwrap();
+    assert_eq!(batch.root(), tree.root());
+    assert_eq!(batch.len(), 4);
+    assert_eq!(batch.get(absent), Some(BnFr::from(2u64)));
+    assert_eq!(
+        batch.proof(entries[2].0).unwrap(),
+        tree.proof(entries[2].0).unwrap()
+    );
+
+    // Removing all the keys gives back the empty tree
+    let keys = entries.iter().map(|(key, _)| *key).chain([absent]);
+    batch
+        .update_batch(&keys.map(|key| (key, None)).collect::<Vec<_>>())
+        .unwrap();
+    assert!(batch.is_empty());
+    assert_eq!(batch.root(), empty);
+}
+
+#[test]
+fn sparse_key_range() {
+    let sponge = griffin_sponge::<BlsFr>();
+    let mut tree = SparseMerkleTree::new(sponge.clone(), 8).unwrap();
+
+    tree.insert(BlsFr::from(255u64), BlsFr::from(1u64)).unwrap();
+    assert!(tree.insert(BlsFr::from(256u64), BlsFr::from(1u64)).is_err());
+    assert!(tree.proof(-BlsFr::from(1u64)).is_err());
+
+    let proof = tree.proof(BlsFr::from(3u64)).unwrap();
+    assert_eq!(proof.path(BlsFr::from(3u64)).unwrap().index(), 3);
+    assert!(proof
+        .verify(&sponge, BlsFr::from(256u64), None, tree.root())
+        .is_err());
+}
+
+#[test]
+fn sparse_gadgets() {
+    let sponge = neptune_sponge::<BlsFr>();
+    let mut tree = SparseMerkleTree::new(sponge.clone(), 8).unwrap();
+    let (key, value) = (BlsFr::from(42u64), BlsFr::from(1000u64));
+    let absent = BlsFr::from(43u64);
+    tree.insert(key, value).unwrap();
+    tree.insert(BlsFr::from(200u64), value).unwrap();
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let witness = |x: BlsFr| FpVar::new_witness(cs.clone(), || Ok(x)).unwrap();
+    let proof = |key: BlsFr| {
+        SparseMerkleProofVar::new_witness(cs.clone(), || {
+            tree.proof(key)
+                .map_err(|_| ark_relations::r1cs::SynthesisError::AssignmentMissing)
+        })
+        .unwrap()
+    };
+    let root = FpVar::new_input(cs.clone(), || Ok(tree.root())).unwrap();
+
+    let member = proof(key);
+    let is_member = |key, value| {
+        member
+            .verify_membership(&sponge, &witness(key), &witness(value), &root)
+            .unwrap()
+            .value()
+            .unwrap()
+    };
+    assert!(is_member(key, value));
+    assert!(!is_member(key, value + BlsFr::from(1u64)));
+    assert!(!is_member(absent, value));
+
+    let non_member = proof(absent);
+    let is_absent = |proof: &SparseMerkleProofVar<BlsFr>, key| {
+        proof
+            .verify_non_membership(&sponge, &witness(key), &root)
+            .unwrap()
+            .value()
+            .unwrap()
+    };
+    assert!(is_absent(&non_member, absent));
+    assert!(!is_absent(&member, key));
+
+    // The key 43 + 2^8 has the same path but doesn't fit in the tree
+    assert!(!is_absent(&non_member, absent + BlsFr::from(256u64)));
+
+    let (path, fits) = non_member.path(&witness(absent)).unwrap();
+    assert!(fits.value().unwrap());
+    assert_eq!(
+        path.directions
+            .iter()
+            .map(|bit| bit.value().unwrap())
+            .collect::<Vec<_>>(),
+        tree.proof(absent).unwrap().path(absent).unwrap().directions
+    );
+    assert!(cs.is_satisfied().unwrap());
+}
+
+/// Updates a key in a tree with a few keys and builds the circuit of the transition
+fn sparse_update<F, C>(
+    sponge: Sponge<C>,
+    depth: usize,
+    key: F,
+    new_value: Option<F>,
+) -> SparseMerkleUpdateCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let mut tree = SparseMerkleTree::new(sponge.clone(), depth).unwrap();
+    tree.update_batch(&[
+        (F::from(1u64), Some(F::from(10u64))),
+        (F::from(6u64), Some(F::from(60u64))),
+        (F::from(9u64), Some(F::from(90u64))),
+    ])
+    .unwrap();
+
+    let old_root = tree.root();
+    let old_value = tree.get(key);
+    let proof = tree.proof(key).unwrap();
+    tree.update_batch(&[(key, new_value)]).unwrap();
+
+    SparseMerkleUpdateCircuit {
+        sponge,
+        old_root,
+        new_root: tree.root(),
+        key,
+        old_value,
+        new_value,
+        proof,
+    }
+}
+
+#[test]
+fn sparse_update_circuit() {
+    let sponge = neptune_sponge::<BlsFr>();
+    let (one, six) = (BlsFr::from(1u64), BlsFr::from(6u64));
+
+    // Update, insertion and removal
+    let update = sparse_update(sponge.clone(), 16, six, Some(BlsFr::from(61u64)));
+    assert!(is_satisfied(update.clone()));
+    assert!(is_satisfied(sparse_update(
+        sponge.clone(),
+        16,
+        BlsFr::from(5u64),
+        Some(one)
+    )));
+    assert!(is_satisfied(sparse_update(sponge.clone(), 16, one, None)));
+
+    let mut invalid = update.clone();
+    invalid.old_value = Some(BlsFr::from(61u64));
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = update.clone();
+    invalid.old_value = None;
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = update.clone();
+    invalid.new_value = Some(BlsFr::from(62u64));
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = update.clone();
+    invalid.key = BlsFr::from(7u64);
+    assert!(!is_satisfied(invalid));
+
+    // Same leaf, but outside of the tree
+    let mut invalid = update.clone();
+    invalid.key += BlsFr::from(1u64 << 16);
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = update;
+    invalid.proof = SparseMerkleProof {
+        siblings: vec![BlsFr::zero(); 16],
+    };
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_sparse_update_bn254() {
+    use ark_bn254::Bn254;
+
+    let circuit = sparse_update(griffin_sponge::<BnFr>(), 4, BnFr::from(9u64), None);
+    let rng = &mut ark_std::test_rng();
+    let inputs = [circuit.old_root, circuit.new_root];
+
+    type GrothSetup = Groth16<Bn254>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &inputs, &proof).unwrap());
+    assert!(!GrothSetup::verify(&vk, &[inputs[1], inputs[0]], &proof).unwrap());
+}
+
+/// Counts the constraints of a membership circuit of the given depth
+fn count_constraints<C>(sponge: Sponge<C>, depth: usize) -> usize
+where
+    C: ChipAPI<Value = FpVar<BlsFr>> + Clone,
+{
+    let circuit = MerkleMembershipCircuit {
+        sponge,
+        root: BlsFr::zero(),
+        leaf: BlsFr::zero(),
+        path: MerklePath {
+            siblings: vec![BlsFr::zero(); depth],
+            directions: vec![false; depth],
+        },
+    };
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    circuit.generate_constraints(cs.clone()).unwrap();
+    cs.num_constraints()
+}
+
+/// Prints the number of R1CS constraints of a membership proof on BLS12-381, per depth
+#[test]
+#[ignore = "Benchmark test"]
+fn cost_per_depth() {
+    println!("| Depth | Griffin | Neptune | Rescue Prime |");
+    println!("| ----- | ------- | ------- | ------------ |");
+
+    for depth in [1, 4, 8, 16, 20, 32] {
+        println!(
+            "| {} | {} | {} | {} |",
+            depth,
+            count_constraints(griffin_sponge(), depth),
+            count_constraints(neptune_sponge(), depth),
+            count_constraints(rescue_prime_sponge(), depth)
+        );
+    }
+}
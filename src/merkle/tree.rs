@@ -0,0 +1,204 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{
+    api::{ChipAPI, Sponge, SpongeAPI},
+    common::pattern::gen_hash_pattern,
+};
+
+/// Hashes two nodes of the tree, it works both natively (on constants) and in a circuit
+///
+/// The sponge is cloned for each hash, it should be freshly created (i.e. never started),
+/// otherwise it doesn't finish properly and the hash fails.
+pub fn hash_pair<F, C>(
+    sponge: &Sponge<C>,
+    left: &FpVar<F>,
+    right: &FpVar<F>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let mut sponge = sponge.clone();
+
+    sponge.start(gen_hash_pattern(2, 1), None);
+    sponge.absorb(2, &[left.clone(), right.clone()]);
+    let hash = sponge.squeeze(1)[0].clone();
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(hash)
+}
+
+/// The authentication path of a leaf, from the leaf up to the root
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MerklePath<F: PrimeField> {
+    /// The sibling of the current node at each level
+    pub siblings: Vec<F>,
+    /// Whether the current node is the right child at each level
+    pub directions: Vec<bool>,
+}
+
+impl<F: PrimeField> MerklePath<F> {
+    /// Recomputes the root from a leaf
+    pub fn root<C>(&self, sponge: &Sponge<C>, leaf: F) -> Result<F, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        let mut node = leaf;
+
+        for (sibling, is_right) in self.siblings.iter().zip(self.directions.iter()) {
+            let (left, right) = if *is_right {
+                (*sibling, node)
+            } else {
+                (node, *sibling)
+            };
+            node = hash_pair(sponge, &FpVar::Constant(left), &FpVar::Constant(right))?.value()?;
+        }
+
+        Ok(node)
+    }
+
+    /// Checks that the leaf is in the tree of the given root
+    pub fn verify<C>(&self, sponge: &Sponge<C>, leaf: F, root: F) -> Result<bool, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        if self.siblings.len() != self.directions.len() {
+            return Err(anyhow::Error::msg(
+                "The path must have as many siblings as directions",
+            ));
+        }
+        Ok(self.root(sponge, leaf)? == root)
+    }
+
+    /// The index of the leaf, given by the directions
+    pub fn index(&self) -> usize {
+        self.directions
+            .iter()
+            .rev()
+            .fold(0, |index, is_right| 2 * index + *is_right as usize)
+    }
+}
+
+/// A Merkle tree of fixed depth
+///
+/// The leaves are field elements (e.g. commitments), they aren't hashed before being
+/// inserted. As the depth is fixed, an inner node can't be presented as a leaf.
+/// Only the nodes above the given leaves are stored, the other ones are the roots of
+/// empty subtrees (as in `IncrementalMerkleTree`), so a deep tree with few leaves is cheap.
+#[derive(Clone)]
+pub struct MerkleTree<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    sponge: Sponge<C>,
+    /// The non-empty nodes of each level, from the leaves to the root
+    layers: Vec<Vec<F>>,
+    /// The root of an empty subtree at each level, from the leaves (zero) to the root
+    empty: Vec<F>,
+}
+
+impl<F, C> MerkleTree<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    /// Builds a tree with 2^depth leaves, the missing leaves are set to zero
+    pub fn new(sponge: Sponge<C>, depth: usize, leaves: &[F]) -> Result<Self, anyhow::Error> {
+        if depth >= usize::BITS as usize || leaves.len() > 1 << depth {
+            return Err(anyhow::Error::msg(format!(
+                "A tree of depth {} can't hold {} leaves",
+                depth,
+                leaves.len()
+            )));
+        }
+
+        let hash = |left: F, right: F| -> Result<F, anyhow::Error> {
+            Ok(hash_pair(&sponge, &FpVar::Constant(left), &FpVar::Constant(right))?.value()?)
+        };
+
+        let mut empty = vec![F::zero()];
+        let mut layers = vec![leaves.to_vec()];
+        for level in 0..depth {
+            let layer = layers[level]
+                .chunks(2)
+                .map(|pair| hash(pair[0], pair.get(1).copied().unwrap_or(empty[level])))
+                .collect::<Result<Vec<_>, _>>()?;
+            layers.push(layer);
+            empty.push(hash(empty[level], empty[level])?);
+        }
+
+        Ok(Self {
+            sponge,
+            layers,
+            empty,
+        })
+    }
+
+    pub fn depth(&self) -> usize {
+        self.layers.len() - 1
+    }
+
+    /// The number of leaves of the tree, 2^depth
+    pub fn capacity(&self) -> usize {
+        1 << self.depth()
+    }
+
+    pub fn root(&self) -> F {
+        self.node(self.depth(), 0)
+    }
+
+    /// The leaves given when building the tree, the next ones are zero
+    pub fn leaves(&self) -> &[F] {
+        &self.layers[0]
+    }
+
+    pub fn sponge(&self) -> &Sponge<C> {
+        &self.sponge
+    }
+
+    /// The authentication path of the leaf at the given index
+    pub fn path(&self, index: usize) -> Result<MerklePath<F>, anyhow::Error> {
+        if index >= self.capacity() {
+            return Err(anyhow::Error::msg(format!(
+                "The tree has no leaf at index {}",
+                index
+            )));
+        }
+
+        let (siblings, directions) = (0..self.depth())
+            .map(|level| {
+                let position = index >> level;
+                (self.node(level, position ^ 1), position & 1 == 1)
+            })
+            .unzip();
+
+        Ok(MerklePath {
+            siblings,
+            directions,
+        })
+    }
+
+    /// The node at the given position of a level, which is empty if it isn't stored
+    fn node(&self, level: usize, position: usize) -> F {
+        self.layers[level]
+            .get(position)
+            .copied()
+            .unwrap_or(self.empty[level])
+    }
+}
@@ -20,8 +20,12 @@ use ark_r1cs_std::{fields::fp::FpVar, prelude::FieldVar};
 use ark_relations::r1cs::SynthesisError;
 
 use crate::{
-    api::{ChipAPI, Sponge},
-    common::pattern::IOPattern,
+    api::{permute_native, ChipAPI, Permutation, Sponge},
+    common::{
+        params_file::ParameterFile,
+        rounds::{neptune_rounds, SECURITY_LEVEL},
+        validation::*,
+    },
 };
 
 use super::params::*;
@@ -32,10 +36,61 @@ pub struct NeptuneParameters<F: PrimeField> {
     pub nb_rounds_int: usize,
     pub round_constants: Vec<FpVar<F>>,
     pub gamma: FpVar<F>,
-    pub d: [u64; 4],
+    pub d: Vec<u64>,
     pub matrix_int: Vec<FpVar<F>>,
 }
 
+impl<F: PrimeField> NeptuneParameters<F> {
+    /// Loads the parameters from a parameter file over F
+    ///
+    /// It expects the `external_begin`, `internal` and `external_end` numbers of rounds
+    /// and the `d`, `gamma`, `matrix_int` and `round_constants` constants.
+    pub fn from_file(file: &ParameterFile) -> Result<Self, anyhow::Error> {
+        file.check::<F>("neptune", M)?;
+
+        Ok(Self {
+            nb_rounds_ext: [file.rounds("external_begin")?, file.rounds("external_end")?],
+            nb_rounds_int: file.rounds("internal")?,
+            round_constants: file.constants("round_constants")?,
+            gamma: FpVar::Constant(file.element("gamma")?),
+            d: file.exponent("d")?,
+            matrix_int: file.constants("matrix_int")?,
+        })
+    }
+
+    /// Checks that the parameters define a valid Neptune permutation
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        let nb_rounds = self.nb_rounds_ext[0] + self.nb_rounds_int + self.nb_rounds_ext[1];
+        check_count("round constants", self.round_constants.len(), M * nb_rounds)?;
+        check_count("internal matrix elements", self.matrix_int.len(), M)?;
+        check_exponent::<F>(&self.d)?;
+
+        if value(&self.gamma)?.is_zero() {
+            return Err(anyhow::Error::msg("gamma can't be zero"));
+        }
+
+        // The internal matrix is full of 1s, except for the diagonal
+        let mut matrix = vec![vec![F::one(); M]; M];
+        for (i, row) in matrix.iter_mut().enumerate() {
+            row[i] = value(&self.matrix_int[i])?;
+        }
+        check_invertible(matrix)
+    }
+
+    /// Checks that the number of rounds reaches the recommended one for a security level (in bits)
+    pub fn check_rounds(&self, security_level: usize) -> Result<(), anyhow::Error> {
+        let (ext, int) = neptune_rounds::<F>(M, small_exponent("d", &self.d)?, security_level);
+        let nb_rounds_ext = self.nb_rounds_ext[0] + self.nb_rounds_ext[1];
+        if nb_rounds_ext < ext || self.nb_rounds_int < int {
+            return Err(anyhow::Error::msg(format!(
+                "Neptune uses {} external and {} internal rounds, {} and {} are recommended for {} bits of security",
+                nb_rounds_ext, self.nb_rounds_int, ext, int, security_level
+            )));
+        }
+        Ok(())
+    }
+}
+
 /// This chip implements the [Neptune permutation](https://eprint.iacr.org/2021/1695.pdf)
 ///
 /// It manages the state internally in order to only expose helper functions
@@ -43,15 +98,30 @@ pub struct NeptuneParameters<F: PrimeField> {
 #[derive(Clone)]
 pub struct NeptuneChip<F: PrimeField> {
     parameters: NeptuneParameters<F>,
-    state: [FpVar<F>; M],
 }
 
 impl<F: PrimeField> NeptuneChip<F> {
+    /// Creates a chip, panics if the parameters are invalid
+    ///
+    /// Parameters with fewer rounds than recommended are accepted, with a warning logged through
+    /// the `log` crate
     pub fn new(parameters: NeptuneParameters<F>) -> Self {
-        Self {
-            parameters,
-            state: [FpVar::zero(), FpVar::zero(), FpVar::zero(), FpVar::zero()],
+        if let Err(e) = parameters.validate() {
+            panic!("Invalid Neptune parameters: {}", e);
+        }
+        if let Err(e) = parameters.check_rounds(SECURITY_LEVEL) {
+            log::warn!("{}", e);
         }
+
+        Self { parameters }
+    }
+
+    /// Creates a chip, rejecting invalid parameters and those below 128 bits of security
+    pub fn try_new(parameters: NeptuneParameters<F>) -> Result<Self, anyhow::Error> {
+        parameters.validate()?;
+        parameters.check_rounds(SECURITY_LEVEL)?;
+
+        Ok(Self { parameters })
     }
 
     fn internal_round(
@@ -60,7 +130,7 @@ impl<F: PrimeField> NeptuneChip<F> {
         mut state: [FpVar<F>; 4],
     ) -> Result<[FpVar<F>; 4], SynthesisError> {
         // do the simple operation ^d on the first element of the state
-        state[0] = state[0].pow_by_constant(self.parameters.d)?;
+        state[0] = state[0].pow_by_constant(&self.parameters.d)?;
 
         let sum: FpVar<F> =
             state[0].clone() + state[1].clone() + state[2].clone() + state[3].clone();
@@ -146,51 +216,61 @@ impl<F: PrimeField> NeptuneChip<F> {
     }
 }
 
-impl<F: PrimeField> ChipAPI for NeptuneChip<F> {
-    type Value = FpVar<F>;
-
-    fn init(&mut self, tag: u128) -> Result<(), anyhow::Error> {
-        self.state[1] = FpVar::Constant(F::from(tag));
-        Ok(())
-    }
-
-    fn read(&mut self) -> FpVar<F> {
-        self.state[0].clone()
-    }
-
-    fn add(&mut self, val: &FpVar<F>) -> Result<(), anyhow::Error> {
-        self.state[0] += val;
-        Ok(())
+impl<F: PrimeField> Permutation<FpVar<F>> for NeptuneChip<F> {
+    fn width(&self) -> usize {
+        M
     }
 
-    /// This function will do a permutation on the state, without exposing it
     /// It returns a result in order to propagate the potential error of internal rounds
-    fn permutation(&mut self) -> Result<(), anyhow::Error> {
+    fn permute(&self, state: &mut [FpVar<F>]) -> Result<(), anyhow::Error> {
+        check_count("state elements", state.len(), M)?;
+        let mut rounds_state = [
+            state[0].clone(),
+            state[1].clone(),
+            state[2].clone(),
+            state[3].clone(),
+        ];
+
         for i in 0..self.parameters.nb_rounds_ext[0] {
-            self.state = self.external_round(i, self.state.clone())?;
+            rounds_state = self.external_round(i, rounds_state)?;
         }
 
+        // The constants are indexed by round, across the three phases
+        let offset = self.parameters.nb_rounds_ext[0];
         for i in 0..self.parameters.nb_rounds_int {
-            self.state = self.internal_round(i + 4, self.state.clone())?;
+            rounds_state = self.internal_round(i + offset, rounds_state)?;
         }
 
+        let offset = offset + self.parameters.nb_rounds_int;
         for i in 0..self.parameters.nb_rounds_ext[1] {
-            self.state = self.external_round(i + 68, self.state.clone())?;
+            rounds_state = self.external_round(i + offset, rounds_state)?;
         }
 
+        state.clone_from_slice(&rounds_state);
         Ok(())
     }
 }
 
+impl<F: PrimeField> Permutation<F> for NeptuneChip<F> {
+    fn width(&self) -> usize {
+        M
+    }
+
+    fn permute(&self, state: &mut [F]) -> Result<(), anyhow::Error> {
+        permute_native(self, state)
+    }
+}
+
+impl<F: PrimeField> ChipAPI for NeptuneChip<F> {
+    type Value = FpVar<F>;
+
+    fn constant(x: u128) -> FpVar<F> {
+        FpVar::Constant(F::from(x))
+    }
+}
+
 impl<F: PrimeField> Sponge<NeptuneChip<F>> {
     pub fn new(chip: NeptuneChip<F>) -> Self {
-        Self {
-            chip,
-            absord_pos: 0,
-            squeeze_pos: 0,
-            op_count: 0,
-            pattern: IOPattern::new(vec![]),
-            rate: R,
-        }
+        Self::with_rate(chip, R)
     }
 }
@@ -17,6 +17,11 @@
 
 #![allow(dead_code)]
 
+use ark_bls12_381::Fr as BlsFr;
+use ark_ff::PrimeField;
+
+use crate::{common::params_file::Bundled, neptune::chip::NeptuneParameters};
+
 // These parameters were issued by the gen_params.sage script
 
 /// The rate of the sponge
@@ -25,2948 +30,40 @@ pub const R: usize = 1;
 // The size of the state
 pub const M: usize = 4;
 
-/// The number of rounds
-pub const N: usize = 10;
-
 pub const NE: usize = 6; // The number of external rounds
 pub const NEB: usize = 4; // The number of external rounds (at the beginning)
 pub const NEE: usize = 2; // The number of external rounds (at the end)
 pub const NI: usize = 68; // The number of internal rounds
 
-/// The number of constants, depends on M (state size) and N (number of rounds)
-pub const NB_CONSTS: usize = M * (N);
-
-/// The value of the exponent D on both curves
-pub const D: [u64; 4] = [
-    0x0000_0000_0000_0005,
-    0x0000_0000_0000_0000,
-    0x0000_0000_0000_0000,
-    0x0000_0000_0000_0000,
-];
+/// The number of rounds
+pub const N: usize = NE + NI;
 
-/// The value of Gamma on the BLS12_381 curve
-pub const GAMMA_BLS: [u64; 4] = [
-    0x0edc_baeb_ccb4_e261,
-    0x5b5a_fb4a_3c87_1e2a,
-    0x5adf_bf00_e7d1_1b0c,
-    0x2533_5ea3_0222_ac2c,
-];
+/// The number of constants, depends on M (state size) and N (number of rounds)
+pub const NB_CONSTS: usize = M * N;
 
+/// The parameters on the scalar field of the BLS12_381 curve (d = 5), see `common::params_file` for the format
+pub const BLS12_381: &str = include_str!("../../params/bls12_381/neptune.json");
+/// The parameters on the scalar field of the BN254 curve (d = 5)
+pub const BN254: &str = include_str!("../../params/bn254/neptune.json");
+/// The parameters on the scalar field of the BLS12_377 curve (d = 11)
+pub const BLS12_377: &str = include_str!("../../params/bls12_377/neptune.json");
+/// The parameters on the scalar field of the Pallas curve (d = 5)
+pub const PALLAS: &str = include_str!("../../params/pallas/neptune.json");
+/// The parameters on the scalar field of the Vesta curve (d = 5)
+pub const VESTA: &str = include_str!("../../params/vesta/neptune.json");
 
+/// All the bundled parameter sets
+pub const BUNDLED: [&str; 5] = [BLS12_381, BN254, BLS12_377, PALLAS, VESTA];
 
-/// Internal matrix values for the BLS curve
-///
-/// The internal matrix is actually a (MxM) matrix full of 1s, except the diagonal.
-/// Therefore, I chose to only store the diagonal in the constants
-pub const INTERNAL_MATRIX_BLS: [[u64; 4]; M] = [
-    [
-        0xc0b8_e5a9_14d3_902c,
-        0x0b3b_375a_1d9f_7356,
-        0x8c7c_0b9c_97c1_6ece,
-        0x4fd0_f672_8488_b91d,
-    ],
-    [
-        0x2ede_9397_1279_4a79,
-        0xfa2d_7825_5884_dbf7,
-        0x9ab9_49f3_73aa_6c21,
-        0x309f_aee9_2dd0_8b9d,
-    ],
-    [
-        0x954e_f24d_a485_4cb2,
-        0x485c_e5bd_eeb5_f11f,
-        0xa15e_8b72_72b3_f099,
-        0x0d18_470c_ed47_5f3a,
-    ],
-    [
-        0x48e5_745c_1414_1c0d,
-        0x9e1e_b3b4_781d_3607,
-        0x647d_9f71_e6dc_0352,
-        0x72a2_7a19_3781_6fed,
-    ],
-];
+/// The bundled parameter sets, parsed on first use
+static PARSED: Bundled = Bundled::new(&BUNDLED);
 
+/// Loads the bundled parameters for the field F, if any (the files are only parsed once)
+pub fn bundled<F: PrimeField>() -> Result<NeptuneParameters<F>, anyhow::Error> {
+    PARSED.load::<F, _>(NeptuneParameters::from_file)
+}
 
-/// The round constants on the BLS curve
-pub const ROUND_CONSTANTS_BLS: [[u64; 4]; 2*2*40*3] = [ // XXX: we need 296
-    [
-        0xaa63_7490_ed43_e1f6,
-        0xc55e_d2f6_3bbf_5429,
-        0x056e_6bd9_33a0_f49d,
-        0x16d3_b30e_0177_e9ce,
-    ],
-    [
-        0x9349_ed02_eea2_ac7f,
-        0xbd22_f9b9_6b64_0cb6,
-        0x3cd6_1f46_c176_aa5a,
-        0x1cb9_480a_77f1_5e7e,
-    ],
-    [
-        0x5f0f_fd88_7c1a_e204,
-        0xe245_1cd7_7ce5_3e14,
-        0x0419_39a7_447d_bdbd,
-        0x4e55_34fc_537c_9a46,
-    ],
-    [
-        0x42a7_4c9f_a758_4c32,
-        0x7f06_75cb_367e_5938,
-        0x58b8_ab69_36d6_568b,
-        0x4208_e86b_5fe2_bcda,
-    ],
-    [
-        0xa75b_afeb_87fd_0b18,
-        0x5f43_4981_6548_a4de,
-        0xc844_85b3_2691_e9bb,
-        0x3c8b_f2a5_b6d4_0124,
-    ],
-    [
-        0xd422_87b7_6039_04f0,
-        0x75ed_da6e_2c68_e006,
-        0x008a_eb8d_d79d_7882,
-        0x38e0_ff60_ac96_d679,
-    ],
-    [
-        0xe10c_175c_6241_dea4,
-        0xaebc_7140_7185_e78f,
-        0x9874_3b12_8873_2f87,
-        0x3955_0e91_bfce_de7a,
-    ],
-    [
-        0xafd9_c041_7315_e8f9,
-        0x8cd5_7cdd_a500_9caa,
-        0x89f8_982d_5151_f987,
-        0x72d9_a0df_efb7_0bc3,
-    ],
-    [
-        0x4c8c_6f07_ae31_6ebe,
-        0xe544_8346_84f3_f8b1,
-        0xea9c_1709_e373_c650,
-        0x6f3e_e455_99b5_b0ea,
-    ],
-    [
-        0x275a_7e57_4afd_a697,
-        0x68dd_4f16_aa19_10f0,
-        0x937d_e5e0_0b41_40c9,
-        0x4c97_9d34_de86_4fba,
-    ],
-    [
-        0x8802_9fb5_41aa_609d,
-        0xe670_cb60_1e04_e16d,
-        0x81ed_76e2_5c23_ced5,
-        0x32ff_be19_4c22_9349,
-    ],
-    [
-        0x6f6c_e888_74fe_a683,
-        0x615b_f4b1_978e_a33d,
-        0x7e46_4d5e_4ef1_ba97,
-        0x08e5_753a_2486_99ea,
-    ],
-    [
-        0x815e_b0d7_c4df_500a,
-        0x79d7_dab9_6c7a_8d2d,
-        0x3780_d3bf_41dd_e7c3,
-        0x0bd2_e6c1_1ff0_bf6f,
-    ],
-    [
-        0x73ce_b95d_6fe8_ebf5,
-        0x96de_9765_dbb7_3cac,
-        0x2075_651f_136f_8c0d,
-        0x419e_891b_b381_9400,
-    ],
-    [
-        0x970e_ece6_0c55_c9d3,
-        0xba64_d0ce_1863_4217,
-        0x9d74_fbbe_b275_e852,
-        0x0c2a_fe8b_b29f_754b,
-    ],
-    [
-        0xfa36_4917_8f46_712a,
-        0xbb4d_6cb4_3f28_119a,
-        0x7777_a8d9_1b60_3ff5,
-        0x1369_5a42_55c9_c094,
-    ],
-    [
-        0x7ba4_66cf_44ef_c158,
-        0x053a_5719_c7a8_fbd2,
-        0x7fcb_83fa_a7ce_99ee,
-        0x5ee3_9eb3_33d9_6d11,
-    ],
-    [
-        0xe9fe_9695_df0e_1472,
-        0xeca0_0792_5e00_af13,
-        0xe70e_c64e_20ac_5463,
-        0x17d8_0160_54b0_1824,
-    ],
-    [
-        0x65fa_8d2d_a727_ab9a,
-        0x121a_54f7_efe2_c9f6,
-        0xa497_25b6_b896_0788,
-        0x34b0_4b80_a246_a481,
-    ],
-    [
-        0xb223_fdc6_8388_4b7d,
-        0x1153_0b28_de87_7b25,
-        0xe444_c055_3017_ad93,
-        0x5fe1_2458_de6e_1c32,
-    ],
-    [
-        0x0722_3198_92f8_1b9a,
-        0xbfb8_1208_a385_9940,
-        0xed29_b7f1_39b2_e1cb,
-        0x424e_f1a3_9148_c0a2,
-    ],
-    [
-        0x7ec6_8481_ef59_cc98,
-        0x3be0_d217_6220_d8b7,
-        0x88c6_2e77_c2ba_bdb6,
-        0x5c5e_df31_0466_9d98,
-    ],
-    [
-        0x39b1_f28d_bafa_c781,
-        0xb94b_1fbc_7207_14f6,
-        0x5f21_9d0f_36db_751d,
-        0x2e8b_4d13_e02e_2043,
-    ],
-    [
-        0x2403_17cb_a31b_75c2,
-        0xf9ff_46cc_01bc_85d0,
-        0x177e_5064_583a_9701,
-        0x515e_fb6a_efc8_8b12,
-    ],
-    [
-        0xddd4_1247_966a_7567,
-        0x1c75_1cfc_9aea_9c46,
-        0x5236_f041_06d3_887a,
-        0x1904_5e97_6ab8_6720,
-    ],
-    [
-        0xb0c9_dc98_e269_506f,
-        0x1151_1c9e_3a83_a9db,
-        0x0ced_5d80_20db_2eb5,
-        0x6b14_1f46_a59b_61c9,
-    ],
-    [
-        0x20ad_dfa8_ae27_8a6f,
-        0x1bad_1744_6f4d_c486,
-        0xdda5_0a48_dce1_58c3,
-        0x34e2_ed76_9633_541b,
-    ],
-    [
-        0x0f7c_5fa7_0d44_9dc9,
-        0x2244_1343_85c3_f33e,
-        0x5b19_62df_09de_5db0,
-        0x4756_db2e_29bf_1dc1,
-    ],
-    [
-        0x7b1d_bd9b_5ba1_09c5,
-        0xf95b_922c_80fb_b10b,
-        0xa9b1_94f1_69e5_78cb,
-        0x3770_7d43_6a4f_c45d,
-    ],
-    [
-        0x620b_8525_6642_4836,
-        0xc136_e913_3a3f_4131,
-        0xd98a_5b75_5dfc_3b7d,
-        0x4cd7_d96e_7840_a98b,
-    ],
-    [
-        0xf56e_39ae_c55b_258a,
-        0xb217_5802_ceec_f4a5,
-        0x986a_283d_ef2d_f0ee,
-        0x66a3_5d12_83e9_683f,
-    ],
-    [
-        0x0033_7d4a_d4b9_78dd,
-        0x20f9_b46f_5e5c_1283,
-        0x991c_ed02_54c2_1ee8,
-        0x0719_8cd4_55f7_83f6,
-    ],
-    [
-        0x6465_432b_e921_1c87,
-        0x36f1_20fc_806b_e592,
-        0xe0b0_3263_e5b4_77d5,
-        0x6953_05aa_52a4_9eda,
-    ],
-    [
-        0xca6c_da9f_8dc5_932a,
-        0xa5dc_5547_bbea_533c,
-        0xd625_2d77_d11b_250f,
-        0x58ff_2d99_4956_991a,
-    ],
-    [
-        0x7c98_943c_894d_8024,
-        0x4ee4_e543_bd8f_1639,
-        0xd262_f8d6_c4d3_760a,
-        0x6ef2_150b_16f9_46dc,
-    ],
-    [
-        0xc842_a3bb_0111_f667,
-        0x70cf_cc1e_7648_bacf,
-        0x4cb1_85c0_7196_923b,
-        0x4201_3d5d_949d_7c0a,
-    ],
-    [
-        0xbf63_4130_aa29_f5aa,
-        0x13d1_f9ba_b11e_69c4,
-        0xdeda_fa8e_ba4f_5821,
-        0x3b0a_3a44_45e4_0b89,
-    ],
-    [
-        0x6d1a_654f_b010_31b8,
-        0xdd8e_3a04_2eaa_e371,
-        0xb043_6907_44e2_8741,
-        0x5f72_cca8_6046_9acd,
-    ],
-    [
-        0x46a3_6b9c_168b_0f11,
-        0x61d7_a828_4a40_de63,
-        0x68b6_d1ae_6605_494e,
-        0x18bb_cdb0_a8b3_6060,
-    ],
-    [
-        0xa6a9_b0fd_8cb7_2205,
-        0x7371_29a6_d0d4_ff7d,
-        0x9a88_fd04_3c34_ed86,
-        0x3368_e77b_6ce1_9370,
-    ],
-    [
-        0x9e58_d7fb_c85a_eac6,
-        0xa231_bc23_b065_e662,
-        0x6567_1cba_ab91_f41b,
-        0x6ecf_699d_81f2_a7b4,
-    ],
-    [
-        0xa2a6_5c4f_998b_d901,
-        0x5954_5b2e_24ba_fb6d,
-        0xcf61_549d_1f78_a836,
-        0x3090_6ff2_0fe4_2e6a,
-    ],
-    [
-        0x2bfd_1ac6_50ad_2505,
-        0x05f1_6b62_1d92_3a45,
-        0x6220_6f3f_02b6_c231,
-        0x42f0_405a_86e2_7fb7,
-    ],
-    [
-        0xd4fe_cfab_710d_8e16,
-        0xf233_501e_3f8b_1583,
-        0x2169_00a2_f25b_1cb8,
-        0x0db2_7ade_0820_bbb1,
-    ],
-    [
-        0x78ae_d759_a478_8d34,
-        0xb687_31aa_c485_a0b3,
-        0xa49d_716a_80b1_3518,
-        0x35f9_9d30_c7a3_40e6,
-    ],
-    [
-        0x8a75_217e_6e6b_ad92,
-        0xab7e_21d8_d02a_0ce8,
-        0xf44f_7c5f_9af0_ed62,
-        0x157c_3a9d_25a3_2a67,
-    ],
-    [
-        0x9f61_8af8_a302_cc59,
-        0x7934_5215_0080_ea30,
-        0x2c4d_a81f_42d3_486f,
-        0x47bd_4c16_d18e_e060,
-    ],
-    [
-        0xff76_e677_a251_7501,
-        0x574d_db92_a942_aedf,
-        0xa4ed_7feb_5d40_c6ed,
-        0x3de3_0f54_2fff_35f2,
-    ],
-    [
-        0x108c_69b4_3e2a_6466,
-        0x64d0_cf93_214e_d668,
-        0x0bd3_4598_09b0_0f79,
-        0x12d2_f87e_b1a5_5a44,
-    ],
-    [
-        0x5af9_4bb9_be86_ad5b,
-        0x647e_2bf5_ba8b_aa8b,
-        0x7e9b_2006_0fb0_8820,
-        0x6251_fae6_d732_4a62,
-    ],
-    [
-        0x8eb8_ef0a_6f3a_e605,
-        0xcda7_8470_9805_8da3,
-        0x933d_75dc_d114_7970,
-        0x4f66_eff0_0b40_d430,
-    ],
-    [
-        0x7c6c_0a01_9bee_76ad,
-        0xe253_8a8f_a5f4_8069,
-        0x70ff_4e93_594a_cac7,
-        0x70d7_ce1b_f0d6_ee43,
-    ],
-    [
-        0x4e5b_f492_1a30_21f9,
-        0x01e8_c787_1b54_6120,
-        0xa941_2032_9733_30c9,
-        0x088c_5677_02c3_d2de,
-    ],
-    [
-        0x639b_16dc_c00c_52b8,
-        0x4c68_3fb0_8615_13d6,
-        0x86ba_a818_b3dd_2d48,
-        0x5e3e_5175_a451_85e1,
-    ],
-    [
-        0x122b_11f1_eaca_62a3,
-        0xaf20_ac46_0508_dd52,
-        0x303e_cb57_ba84_9c76,
-        0x7278_ed45_86f6_7a8d,
-    ],
-    [
-        0xc36c_978e_31ba_f0c6,
-        0xae36_49f8_7545_2c0e,
-        0xa907_87cb_c9ea_29d5,
-        0x445b_a670_feee_a212,
-    ],
-    [
-        0xf183_8ce2_d5ae_da8d,
-        0x656d_c659_0654_a2e8,
-        0x9225_b96e_c24a_f0b9,
-        0x38c2_11a6_a854_f1dc,
-    ],
-    [
-        0x54aa_4588_fff4_be94,
-        0x3e84_5bef_36d4_1bfe,
-        0x63d8_31d5_ef1b_0d8b,
-        0x4e32_76c2_adac_beff,
-    ],
-    [
-        0x7220_303a_9898_4c76,
-        0x5e9c_de89_89ff_ba79,
-        0xae8d_1eaa_7e0f_dca2,
-        0x1e16_15b4_ab05_9a95,
-    ],
-    [
-        0xf782_eddd_306d_4978,
-        0x6aa5_aa96_32d7_f94f,
-        0x4c61_92f7_f1ee_95ad,
-        0x6d88_9646_0e66_d1d6,
-    ],
-    [
-        0xe2f7_0776_1f8b_6596,
-        0xa6f5_44c7_ff04_bd44,
-        0x9e2a_88c7_255d_ed87,
-        0x6e12_c261_926d_2769,
-    ],
-    [
-        0xacde_51b7_cdf6_699d,
-        0x526e_2285_5624_9873,
-        0x3090_9dab_d395_81e2,
-        0x6314_1b78_6c3b_5cb6,
-    ],
-    [
-        0x860b_f748_e319_8a63,
-        0x3ddf_01f0_91ff_6541,
-        0x9b05_0eae_8ed8_8655,
-        0x1ced_4684_5f0e_8f16,
-    ],
-    [
-        0xfc57_bd66_d43d_f4ff,
-        0xecb3_f958_d490_83c2,
-        0x5641_6577_6333_82d9,
-        0x24ed_ff38_3a67_a147,
-    ],
-    [
-        0xd1a7_ddde_16e5_074e,
-        0xbe48_afbb_3c7d_30c7,
-        0xa2d1_0d24_d0d5_94ab,
-        0x6e69_c74a_33bc_3fd7,
-    ],
-    [
-        0x566d_f90d_5f54_b362,
-        0x0c60_fda8_28a5_7b97,
-        0x4a0d_0973_4b4a_b6cc,
-        0x341a_4fc0_729b_7391,
-    ],
-    [
-        0xe334_ae48_8ea1_221b,
-        0xf22e_7a0f_e61a_b244,
-        0x663d_5cc5_69a5_a7da,
-        0x2466_0fc9_9985_c64c,
-    ],
-    [
-        0x7cce_e0d4_f9aa_3564,
-        0xd788_f711_b223_4320,
-        0xf7fa_3dd1_f4d9_1de1,
-        0x6614_c9ee_1adc_8a83,
-    ],
-    [
-        0x7361_37a2_90a3_609b,
-        0xd6e2_e163_f451_38c9,
-        0x64ab_1799_1c01_b73f,
-        0x5915_0bb5_0d76_7d4f,
-    ],
-    [
-        0x9d66_0f85_2c86_58ee,
-        0xd57c_6e86_2661_1512,
-        0x593c_393e_dc01_a865,
-        0x3e76_5d0f_53c0_d4aa,
-    ],
-    [
-        0x6bda_80e0_3c79_8ecc,
-        0x9c89_a11b_9a5d_fa8b,
-        0x1a2d_34d7_06f2_8e97,
-        0x3d42_31d2_14fb_9bc0,
-    ],
-    [
-        0x175b_34e3_0e55_14ed,
-        0xdb70_826c_0698_baa4,
-        0x31b4_f03b_4537_2b71,
-        0x0bcd_4c63_6180_c409,
-    ],
-    [
-        0xe393_d99c_ea17_5be5,
-        0x1230_6811_d596_82c5,
-        0x739c_5071_3b23_6951,
-        0x329d_8697_a038_90a6,
-    ],
-    [
-        0xf45c_f6e8_9df8_6fdc,
-        0xf356_e31a_6251_0512,
-        0x17b0_2710_0ba9_d703,
-        0x1d5c_30f5_bc8b_fffd,
-    ],
-    [
-        0x480d_cd52_e95f_d24a,
-        0xbe49_564a_9be5_8d29,
-        0x94ad_93cd_4bc9_a694,
-        0x1299_bd1c_91b7_3de5,
-    ],
-    [
-        0x87c3_f235_8cbb_da4f,
-        0xdaa9_4258_464a_5a98,
-        0x2ad9_3c6d_1423_d6bf,
-        0x45c5_ead6_45e3_23bc,
-    ],
-    [
-        0xbe0b_f34b_7df3_7da3,
-        0x7a5f_94ea_e852_21cc,
-        0x55b2_564c_e353_5f08,
-        0x1566_a1d4_1919_6048,
-    ],
-    [
-        0x87da_78fb_2705_6e55,
-        0x53f3_edd7_7f26_cea2,
-        0x9c20_c6d8_bad3_791b,
-        0x442e_fb3a_f6bc_8415,
-    ],
-    [
-        0xb62e_4932_83e7_95e1,
-        0xa3a5_2f49_aada_3a00,
-        0x76e6_5ba9_133a_7980,
-        0x5e9f_8997_2430_df14,
-    ],
-    [
-        0x9372_0ecf_fa55_3bf5,
-        0xaafa_67f0_c1c3_9e09,
-        0xca5b_ce4a_b02c_1c26,
-        0x5933_58e5_295a_f48b,
-    ],
-    [
-        0x8be0_80d4_30cf_f20e,
-        0xf99c_b497_84f1_27aa,
-        0x26af_9444_307c_8247,
-        0x5619_ee67_9968_5cd5,
-    ],
-    [
-        0x8de2_f2ee_14aa_5c0a,
-        0xb5fc_1fc6_63f3_5288,
-        0x5be4_bc5c_db16_7130,
-        0x2a39_293e_569d_5745,
-    ],
-    [
-        0x1edc_383c_d57b_95aa,
-        0x13aa_3f73_82cc_7e26,
-        0xfbc2_6a69_e65b_7445,
-        0x6243_ea81_b0b8_e256,
-    ],
-    [
-        0xcc6c_e889_ac02_1327,
-        0x186f_c403_3976_e203,
-        0xaf56_3289_c40a_d2fb,
-        0x3dfb_5afc_3c9a_5f4d,
-    ],
-    [
-        0x7335_d285_b3c6_5738,
-        0xd0ac_fc7d_cdd9_6125,
-        0x7550_4214_f581_dfa7,
-        0x22d4_d794_e2af_fae8,
-    ],
-    [
-        0xae09_cdfe_dae2_9e9a,
-        0xf68d_f9a8_3933_d7ee,
-        0x948f_e3cd_6388_1aa1,
-        0x62f2_2af1_97e4_dc42,
-    ],
-    [
-        0x9acc_8911_c057_0084,
-        0x167f_eb16_a07f_ce21,
-        0x2ff7_7145_305b_a7cb,
-        0x3f26_a460_6cd9_4c3c,
-    ],
-    [
-        0xcbfc_726a_a2cc_3deb,
-        0x7c9a_0fec_a374_1373,
-        0x2f41_1806_aa9f_85c2,
-        0x1c0f_0d48_4a36_da82,
-    ],
-    [
-        0x522a_1e95_9f9d_5a58,
-        0x85c0_ebe4_8bbe_94b0,
-        0x5484_d0a6_bd1e_b691,
-        0x6e65_a4c2_0509_4ccf,
-    ],
-    [
-        0x78da_a94c_7ae8_88ef,
-        0x1127_a43e_8a08_e523,
-        0x3983_0de1_de21_81f7,
-        0x3911_bddc_358f_8210,
-    ],
-    [
-        0xd6bb_d319_0704_319e,
-        0x25f8_9627_64c5_52cc,
-        0x3a06_f873_57c4_5c2c,
-        0x4161_493a_79b2_97ea,
-    ],
-    [
-        0x8d8c_cb22_54c6_2130,
-        0xae10_b215_4eda_0f8f,
-        0x0f42_2b3a_3780_80db,
-        0x516e_3bc3_dd29_171a,
-    ],
-    [
-        0xbaf7_8f41_cc59_e654,
-        0xe2c4_d799_f31c_5eba,
-        0x3344_2090_c666_e857,
-        0x0d38_f54c_3146_791f,
-    ],
-    [
-        0x447f_a5b3_abe4_5712,
-        0x0b1d_f594_0bce_4b54,
-        0xfec5_2b56_c9fe_560a,
-        0x633e_f2ff_821d_2a60,
-    ],
-    [
-        0x7a8e_b1fe_3a0d_9d90,
-        0x7ec0_62f7_478c_4d64,
-        0x2361_6be4_bc9d_808c,
-        0x173e_09f4_9161_c183,
-    ],
-    [
-        0x49c0_ffa6_7e30_71c6,
-        0x13d8_8d34_1b81_9e1a,
-        0x19f8_8c71_6a4d_091d,
-        0x33c8_be3e_6f18_e183,
-    ],
-    [
-        0x84a8_d1c7_4a55_4052,
-        0x5a78_2917_b859_fdd7,
-        0x8706_bac3_d84a_7c64,
-        0x5cfe_c1cf_8da9_e0b5,
-    ],
-    [
-        0x2d74_6efd_22af_a179,
-        0x283c_00da_b79b_7fb5,
-        0x86eb_8a3b_ce76_abfc,
-        0x04fd_5597_e42f_d9e0,
-    ],
-    [
-        0x1be1_6c26_e0e7_c974,
-        0xc2ab_7a08_42f8_7d7c,
-        0x394f_4019_b076_f81f,
-        0x6745_88c8_6c4b_fad7,
-    ],
-    [
-        0xa8de_3303_cca3_19b2,
-        0x4ebe_dd5c_fe78_1420,
-        0x4b1b_92ef_0050_9724,
-        0x328c_46e1_1d9a_9f0b,
-    ],
-    [
-        0x8791_88cf_2fe4_0da3,
-        0x7a8c_6b5b_ba6c_a7a3,
-        0xf237_a48f_6af4_9133,
-        0x0efe_4b73_1723_efbb,
-    ],
-    [
-        0x985f_752b_5aef_16fd,
-        0xf975_1743_58ad_5807,
-        0x0a76_d72a_cca8_5b50,
-        0x2280_2c88_709a_2b63,
-    ],
-    [
-        0x87d0_b05a_b3da_a598,
-        0x15e3_1442_327f_db4c,
-        0x1f8a_2adc_c49a_53b9,
-        0x63a6_5c10_dea6_814e,
-    ],
-    [
-        0xbf37_5f02_ec74_b3c5,
-        0x5780_aee2_27c1_2ccc,
-        0xc16c_1033_c996_74b9,
-        0x24a9_93c7_df34_027e,
-    ],
-    [
-        0xfb43_0853_2a3b_a3a1,
-        0xbd88_3722_2fe4_6497,
-        0xe8d2_ab2f_7e3f_9403,
-        0x2029_f0c3_d561_c6f4,
-    ],
-    [
-        0x4b26_cd87_bd65_45cc,
-        0xf20d_1dae_053f_7fa3,
-        0xa13e_85cf_45e9_12d6,
-        0x3e2c_a93f_1d76_a1ed,
-    ],
-    [
-        0x9c88_4105_8b48_11cf,
-        0x1990_a9ad_ca88_96f7,
-        0xc174_a68f_b43a_9a93,
-        0x4c84_6170_57f4_fdd7,
-    ],
-    [
-        0xe25a_136d_ef76_9f6f,
-        0xe7a4_45df_2ab0_1a1b,
-        0x021e_2cf0_9282_4797,
-        0x1969_e6eb_7345_07e2,
-    ],
-    [
-        0xe679_adf6_9ca5_9347,
-        0x3a8b_fe3f_8a72_3738,
-        0xa4cd_59e7_8d11_44a1,
-        0x6b24_6c30_f0c8_7a02,
-    ],
-    [
-        0x9daf_197b_5ac1_0b58,
-        0x7d22_7bac_1d2c_04ec,
-        0x33d1_346a_8e3c_0c46,
-        0x44ab_9322_c7c3_33ec,
-    ],
-    [
-        0xfe48_3e53_8b4f_5565,
-        0x7255_8c65_6c9a_b7df,
-        0x9710_407d_1c94_881d,
-        0x3ff5_9f47_7195_f602,
-    ],
-    [
-        0x538f_e40e_e956_7141,
-        0xe1ff_2df1_ff54_fa33,
-        0x91ec_1d96_5254_0ed8,
-        0x161b_6f11_622f_4d6d,
-    ],
-    [
-        0x8741_c35e_cd76_9332,
-        0x82ba_510d_0e2c_128d,
-        0x7710_7857_f882_b7a5,
-        0x5c63_7ee9_30b4_dee8,
-    ],
-    [
-        0x63e4_151d_b9fe_2c40,
-        0x3877_bb8f_1f55_5d6b,
-        0x74d0_ec0b_8200_da66,
-        0x45cf_6d23_3970_b8d5,
-    ],
-    [
-        0xdabe_f366_10b6_bb5f,
-        0xd259_a7dc_624e_5777,
-        0xbc4f_4497_6c82_0b61,
-        0x083c_b7e9_83c8_46a2,
-    ],
-    [
-        0x5063_67a9_f6a4_2902,
-        0xe806_1ded_a844_0c5d,
-        0x6677_f838_bbf8_0b6c,
-        0x070f_9044_5e9e_1121,
-    ],
-    [
-        0x82d1_174b_a697_60d9,
-        0xf2d2_705f_30c3_4937,
-        0x34c4_d041_95c3_a372,
-        0x0f26_d2de_779a_7aa1,
-    ],
-    [
-        0x84b2_48ca_86a8_53a9,
-        0x9a57_84a0_d876_af93,
-        0x3038_2865_340f_d61a,
-        0x2481_2f9e_e95c_f417,
-    ],
-    [
-        0x57c1_2839_d45e_a42d,
-        0xe883_c116_7883_6a9f,
-        0x6f02_7793_b620_3703,
-        0x3058_0dd1_4b26_7fc3,
-    ],
-    [
-        0x28d6_2647_33b4_24e0,
-        0x50d8_baba_9517_f878,
-        0x506a_209f_59ce_0136,
-        0x5fcb_c0ad_f873_f8aa,
-    ],
-    [
-        0xa5ce_fa5c_c8c9_6f46,
-        0xe495_edad_b8ab_070d,
-        0x04f3_8693_d6d5_56d6,
-        0x3fda_62da_2576_ccef,
-    ],
-    [
-        0x94c4_9f71_c371_4e63,
-        0xb9c4_9d6c_bb86_85b0,
-        0xb6da_f697_fb2a_dae4,
-        0x1964_0f11_78e3_3110,
-    ],
-    [
-        0x54ec_ef9c_4296_1caf,
-        0xe2e9_8ddc_19aa_d4b1,
-        0xf18f_b486_0215_1967,
-        0x5ba3_8228_feea_b683,
-    ],
-    [
-        0x5335_a89d_394d_3962,
-        0x3d3d_9df5_7494_faf6,
-        0x6030_8eff_e62f_4f26,
-        0x2cdc_f6fd_b660_7e1c,
-    ],
-    [
-        0x5169_9bfc_da3c_8119,
-        0x0c03_a856_ed4e_b23e,
-        0xe5ff_a608_b82a_bb76,
-        0x3cba_7586_85f2_58d3,
-    ],
-    [
-        0x2cd6_b3ee_9cd6_f95e,
-        0x18a9_6d9f_edd0_3e34,
-        0x74de_c289_8494_7fb7,
-        0x0d30_7de3_443a_b89f,
-    ],
-    [
-        0x56f1_32ed_b666_6dc0,
-        0x0dae_45c6_8c4c_6365,
-        0x9a32_07d5_6d6c_f080,
-        0x451d_bbd4_09fb_787f,
-    ],
-    [
-        0x622e_2f73_cc04_12b7,
-        0x201e_2a38_6780_24c3,
-        0x44b3_43af_abd5_d707,
-        0x04b9_f978_ac00_0166,
-    ],
-    [
-        0x9197_e0c2_2149_59d8,
-        0xb478_f99d_7244_5a50,
-        0x4561_b7b0_2fef_dc81,
-        0x4a0a_68df_14a7_b419,
-    ],
-    [
-        0x2f47_0be6_0229_52f0,
-        0xa17c_b203_b115_3a8c,
-        0x648d_f145_de32_26cf,
-        0x28d4_6da2_358c_1e42,
-    ],
-    [
-        0xc6aa_6dd2_b26f_8070,
-        0x4302_418b_f925_ac1d,
-        0xc7c2_b7d5_1c2d_6bc2,
-        0x657e_ae4b_5c5d_2ff2,
-    ],
-    [
-        0xa8eb_01de_2b06_7c45,
-        0x44fb_8248_4fcc_27f3,
-        0xa035_5e1c_125b_c305,
-        0x713b_5a64_0693_354c,
-    ],
-    [
-        0x720c_7294_9146_d356,
-        0x3064_f289_f9e5_8c72,
-        0xbfef_b6a1_2bd8_f908,
-        0x1b04_1f2c_9572_fcd4,
-    ],
-    [
-        0xbfa8_c2fd_7325_291c,
-        0x655b_4081_802c_18e8,
-        0x13b1_f309_623d_5212,
-        0x6558_4eaf_af50_b1aa,
-    ],
-    [
-        0x46a2_e302_1c93_1976,
-        0x8af0_62d0_d553_6fd9,
-        0x08fc_9b8a_fbaa_2f8b,
-        0x17ca_8811_a379_0460,
-    ],
-    [
-        0xaac6_9904_2913_185b,
-        0xcef4_69ec_4c1a_9b3f,
-        0x3fa3_7a5d_4196_a2de,
-        0x4f69_5fdf_ee04_bd65,
-    ],
-    [
-        0xdaf0_3f88_c8c2_0f5c,
-        0xeadc_2d2c_9821_44ee,
-        0x64cf_48f0_32b4_e207,
-        0x0712_2768_e5b3_d31b,
-    ],
-    [
-        0x8091_5ad3_d995_211d,
-        0x72ab_d7e8_6e6e_ff58,
-        0xd25f_1920_a7c2_ae7d,
-        0x69e8_11f8_d5b4_cd98,
-    ],
-    [
-        0x0057_3496_90b9_f302,
-        0x0dea_7b9b_5357_20f9,
-        0xc871_4a87_9064_e34e,
-        0x497a_5b7a_b9af_c84c,
-    ],
-    [
-        0x4f04_eae4_8572_d723,
-        0xbd8d_f4c3_2ffa_af83,
-        0xad53_60e8_621d_724a,
-        0x0ac3_1a72_2ba9_6e49,
-    ],
-    [
-        0x61e1_f8a4_de45_a4ce,
-        0x91bf_565d_bc09_65d6,
-        0x0082_5bf7_d307_4c6f,
-        0x29fc_8cb3_e94b_ba09,
-    ],
-    [
-        0x8a34_5788_76fb_98e9,
-        0xa9d6_f0be_5bb4_65f0,
-        0xc97a_e1c8_021e_a4c6,
-        0x67f8_22e7_7b7a_1835,
-    ],
-    [
-        0xa6c8_cdb3_83f8_eefa,
-        0x7231_58e3_d157_fa60,
-        0xf535_6d02_f308_618e,
-        0x14d6_bd9d_7641_4a57,
-    ],
-    [
-        0x9ad7_a4dd_c788_4c75,
-        0x9489_7e92_bb3f_264b,
-        0xac7f_ade8_028c_6378,
-        0x2fa4_b2c6_7640_247d,
-    ],
-    [
-        0xbcd2_1e6c_086b_a996,
-        0x4dd5_474a_62d4_f64e,
-        0x86a0_aca7_af93_dd79,
-        0x4343_5ed0_9b23_6c4f,
-    ],
-    [
-        0x2622_440c_22d1_1edb,
-        0xea87_1a62_131c_88ac,
-        0x6bed_14d3_ca93_1ee3,
-        0x509b_2d9e_07b3_32ad,
-    ],
-    [
-        0x7ec6_e376_2c72_3699,
-        0x565d_31f5_e022_5c3a,
-        0xc2e1_acd7_f531_893d,
-        0x274d_3002_b1be_3162,
-    ],
-    [
-        0xdfdd_04a3_eda2_ee22,
-        0x6e34_cfa7_6b23_be06,
-        0x6c89_5b28_1c70_3908,
-        0x4108_b106_2e36_a698,
-    ],
-    [
-        0x98ed_654d_e958_c60e,
-        0xf2da_ff00_2dfa_ce2a,
-        0xa08c_cc36_a0fd_2613,
-        0x54a1_40c8_6296_78cc,
-    ],
-    [
-        0x1064_0979_b0f3_8646,
-        0xf6d9_b48f_20c1_b0b0,
-        0xbbfd_cf10_55ca_4506,
-        0x6f51_6a0a_151f_6280,
-    ],
-    [
-        0x2af1_e5c8_f40d_c52b,
-        0x3bf9_d6d5_c992_3a76,
-        0x5896_731b_c446_bdaa,
-        0x2886_fcaf_abbe_1963,
-    ],
-    [
-        0x5d2f_d364_fb3a_5beb,
-        0x76f0_3382_6e25_2c4b,
-        0xbd31_4f67_e3ad_0d3e,
-        0x2ea9_b360_abcf_6e63,
-    ],
-    [
-        0x40e7_3402_f5e7_c512,
-        0x162c_7064_582a_e38f,
-        0x0ad8_b225_bb4d_a118,
-        0x6793_8f58_e366_f3d5,
-    ],
-    [
-        0x2557_68fa_02b4_803c,
-        0xc985_8d15_883a_3d02,
-        0x29f4_a0d0_62b2_ec73,
-        0x4ac3_5df8_6060_6fb9,
-    ],
-    [
-        0x5182_29ee_99bb_b37f,
-        0xcede_e92c_fb92_cb34,
-        0xd7bb_9581_c4ba_7364,
-        0x155a_2e5b_17e9_8db7,
-    ],
-    [
-        0xf3e9_383b_2b4d_30c7,
-        0x9b05_13fa_7efb_eb70,
-        0x89d2_07ef_f9f6_6bd7,
-        0x0a8c_a7c1_5623_9dd9,
-    ],
-    [
-        0x1e0b_6ba1_112e_63b5,
-        0xefb6_0632_bb48_5cd6,
-        0x3a35_0b4f_bb9d_6aa6,
-        0x2004_330d_39b3_befa,
-    ],
-    [
-        0x65d0_9d3f_0f67_bf69,
-        0x8fa2_2e7a_8dc8_cd50,
-        0x9175_0fe3_a69d_daaa,
-        0x4993_e774_5761_6f25,
-    ],
-    [
-        0x10ae_735f_4949_d937,
-        0xdca2_8e9f_5ade_65c6,
-        0x6002_eae8_b5b2_6bb0,
-        0x2dc8_50e6_2458_5106,
-    ],
-    [
-        0x9780_f93c_df7b_c663,
-        0x9580_c109_d1b4_bb74,
-        0xb175_22f5_bf08_f017,
-        0x558c_6c01_c34c_75a4,
-    ],
-    [
-        0x922f_5a41_c200_b195,
-        0x6373_b1a3_bb8f_dbda,
-        0x3ae4_1581_d9fd_b281,
-        0x2849_c193_480d_e39a,
-    ],
-    [
-        0x017b_1705_df34_a035,
-        0x95c4_fefa_29a7_c58d,
-        0xff4e_96e9_c97a_fe73,
-        0x2d2c_5491_b649_ef79,
-    ],
-    [
-        0x4441_bd19_44f2_c7b9,
-        0xa3f8_bb34_a5eb_7b0a,
-        0x54a8_5083_eb9f_53b9,
-        0x4fc1_aeca_5a65_f04e,
-    ],
-    [
-        0x868d_8b4f_59c3_08ab,
-        0x7300_e231_769a_920c,
-        0xfa15_9c2c_a89b_60e6,
-        0x507c_23f6_37b0_aec3,
-    ],
-    [
-        0x8d30_9521_6b6c_b060,
-        0x1aee_7480_2e69_3d98,
-        0xe954_3761_6cbb_6e0d,
-        0x3d77_3a7a_9a89_498f,
-    ],
-    [
-        0x8aa9_ff0e_857f_32f0,
-        0x0706_ba70_addc_013e,
-        0x0e90_165b_5273_98df,
-        0x1d0d_d5c0_cda8_66f3,
-    ],
-    [
-        0xe945_b9bc_dbee_dfc6,
-        0xf35b_8680_5cfa_07c6,
-        0xa610_2562_6733_eaf3,
-        0x6b0a_cd11_1f35_4f83,
-    ],
-    [
-        0xcfba_d7f5_94ed_b3c2,
-        0x58f6_3f7d_6a95_adc1,
-        0x3bef_1872_829f_c96f,
-        0x05d4_b03a_0834_99ae,
-    ],
-    [
-        0xac45_b6c2_1944_ffcc,
-        0xf399_c1bb_9284_fa70,
-        0xd75b_f42f_8043_bff8,
-        0x21d8_2bb1_bd1d_588c,
-    ],
-    [
-        0x4c5e_b551_d920_dd7d,
-        0xe6b4_1b4c_e948_c405,
-        0xfacb_5144_d409_7a54,
-        0x2e26_e988_63b5_f13c,
-    ],
-    [
-        0xe03e_9224_38cd_12f2,
-        0xb5c2_17b7_9fc6_cafe,
-        0xc863_0065_be79_dc1f,
-        0x6057_32c0_2308_67c6,
-    ],
-    [
-        0x068c_f4a0_71af_b9e9,
-        0x5a80_1efa_accd_e0aa,
-        0x6d15_018c_63b0_0601,
-        0x444e_99af_c27b_fb47,
-    ],
-    [
-        0x73fc_be7a_d879_5577,
-        0xd644_55c3_ab73_8f73,
-        0x5377_2d7e_e104_f009,
-        0x02b9_2f09_4209_2ba4,
-    ],
-    [
-        0x30af_d277_52e6_b765,
-        0x8f05_d8cf_88c0_00f7,
-        0xaae0_d4b0_f374_7370,
-        0x444e_04fe_a114_672a,
-    ],
-    [
-        0xc129_1026_d52c_a8db,
-        0x1f5c_187d_f7be_b0c5,
-        0x6d48_d2c1_4339_35c7,
-        0x73a6_9cb4_acb8_06f2,
-    ],
-    [
-        0xd1d3_4778_2b1d_a881,
-        0x2fe0_e88c_6c79_335c,
-        0x7026_3134_2880_cead,
-        0x0d6d_948d_b741_b733,
-    ],
-    [
-        0x6699_c64c_29ff_66f8,
-        0xf4f6_165a_c3de_24bd,
-        0xe42e_751c_6fa3_92da,
-        0x6540_fbbe_5a0c_03fe,
-    ],
-    [
-        0xa876_41e4_c31e_fa26,
-        0xb834_7311_fbef_73db,
-        0x2282_eca6_f586_8196,
-        0x30b4_97cd_9158_35b5,
-    ],
-    [
-        0x83e7_dd58_f3c2_eeba,
-        0xcba2_2021_920e_9559,
-        0x8c28_7190_44f1_0fce,
-        0x1265_fb11_2218_77eb,
-    ],
-    [
-        0xf4bd_4f33_0b11_3e1b,
-        0xf94d_066d_d041_0186,
-        0x16cb_e07d_a27c_2e83,
-        0x732c_dcb7_135a_20c1,
-    ],
-    [
-        0x3728_2bb0_73e8_e6fa,
-        0x74e0_c7f4_3fc6_0c2d,
-        0x8426_3db8_40d1_585f,
-        0x5b0e_0007_42d7_ba21,
-    ],
-    [
-        0x512f_66e0_9cba_6886,
-        0xb809_27e7_17f9_524c,
-        0xe604_50ad_54b9_40a0,
-        0x0134_bc7f_d90d_fb36,
-    ],
-    [
-        0xcf77_ad03_47e4_86fc,
-        0x2c52_df28_1782_bd0c,
-        0x3681_e58a_dd9e_2b56,
-        0x265d_43d2_599d_2c7c,
-    ],
-    [
-        0xf9fb_2720_a91b_4d79,
-        0xcb20_a2da_17e1_c32f,
-        0xe0f6_0999_4830_c9cf,
-        0x240f_d69b_a5e6_3be0,
-    ],
-    [
-        0x1381_1940_2b30_5339,
-        0x38b9_a4cb_cb32_9984,
-        0x22de_0be7_645e_42b6,
-        0x4349_ffb3_af0f_4115,
-    ],
-    [
-        0x88e6_d9fe_650b_c821,
-        0x6250_5c54_8349_280e,
-        0xedf8_09ff_f381_45bd,
-        0x1948_a73b_f9e8_6b5c,
-    ],
-    [
-        0x7882_ee4b_0d45_002e,
-        0x6e03_21fe_e073_b257,
-        0x43ee_de85_95f1_aac8,
-        0x551b_df59_76e1_5c9a,
-    ],
-    [
-        0x67db_295d_dad8_5a1b,
-        0xb875_0fa6_0ce8_778e,
-        0x2b47_442e_34fa_e197,
-        0x333b_1de2_8e82_4498,
-    ],
-    [
-        0xdf58_7ff0_0548_1fbc,
-        0x17aa_787f_ebdc_df58,
-        0x33a6_a5a6_fc48_3b87,
-        0x2307_6de2_dc09_355a,
-    ],
-    [
-        0xae3f_f55b_9296_46bc,
-        0x8a4a_2c23_47a9_043c,
-        0x007f_cc3c_d287_adce,
-        0x2a42_2a92_5928_0baf,
-    ],
-    [
-        0xa957_9a79_08f9_605d,
-        0x17a3_e427_d5dc_5cd5,
-        0xccfe_b186_dd66_473e,
-        0x1d14_dfff_7d54_d024,
-    ],
-    [
-        0xe47a_ac84_84b9_398a,
-        0x8591_6bfb_be81_8542,
-        0xb667_73af_e302_e5f9,
-        0x1cea_6c78_70a9_73d6,
-    ],
-    [
-        0x2322_9108_1daa_d683,
-        0x1e37_b81d_f433_82d9,
-        0xf462_441d_a547_f7c8,
-        0x09cb_d7d3_a50a_405f,
-    ],
-    [
-        0x3479_2b39_f121_8b04,
-        0x9cf9_cf7f_b5ae_d3a5,
-        0xaf36_cbdf_c1d4_0caa,
-        0x204a_1217_0deb_6d20,
-    ],
-    [
-        0xc0ba_7e6a_8f0b_175e,
-        0xcd3f_0bc9_8b5b_13ce,
-        0x1fca_7cfe_a6c3_8f84,
-        0x476f_c1e0_a800_edb0,
-    ],
-    [
-        0x96a7_ea58_788c_f8ea,
-        0xfc2d_fc4b_0153_9884,
-        0xa8e1_737a_97b5_5581,
-        0x02e5_d8ad_ace2_a56f,
-    ],
-    [
-        0xde33_72c1_d491_6694,
-        0xbefd_fa8f_dfbf_2845,
-        0x0d23_a341_78e5_dc1a,
-        0x01cb_e4e8_3109_4f8f,
-    ],
-    [
-        0xe285_10a0_fa14_86fa,
-        0x4ae0_162b_f7d4_d3ce,
-        0x628a_090c_1daf_37bf,
-        0x2086_2abf_191f_f15b,
-    ],
-    [
-        0x559d_b1bc_78fe_7245,
-        0xacc8_9afc_7723_f025,
-        0x8b8c_1c66_9409_6e9a,
-        0x4a88_7f2a_a7aa_ef5c,
-    ],
-    [
-        0x16bb_33fd_5fa6_2de1,
-        0xe526_c193_7960_b5a9,
-        0x1b85_389e_6779_1b55,
-        0x23a1_1343_2deb_6987,
-    ],
-    [
-        0xcb81_03b1_2b44_a4cd,
-        0xfd78_a3e0_b1de_a5ac,
-        0xd890_3058_add3_59d3,
-        0x0292_f424_0ef7_ecbf,
-    ],
-    [
-        0x1186_52a3_89ff_9c45,
-        0x7c7b_8a87_6a51_821a,
-        0x031d_1c09_7490_5b83,
-        0x4ddc_97f4_6b9e_723e,
-    ],
-    [
-        0x8fff_0edf_b0ab_029a,
-        0xf299_51a1_688c_9d50,
-        0x0168_519c_3376_66ef,
-        0x444b_71c2_8bed_2acf,
-    ],
-    [
-        0xf827_3873_272c_fc51,
-        0xdac6_2c32_deee_6d84,
-        0x147f_137a_bc50_c2f0,
-        0x1083_b3a7_de0d_ca6b,
-    ],
-    [
-        0x81e2_6a25_c363_cf4a,
-        0x4fe7_2b28_bc69_9779,
-        0x95df_048c_a4e4_c1c4,
-        0x1a3c_30dd_747b_4949,
-    ],
-    [
-        0x384f_80b3_0fe2_ab64,
-        0x7acc_6f26_27f2_85f2,
-        0x7c41_c7e8_378b_b4b0,
-        0x3085_6c7c_51b6_1eb9,
-    ],
-    [
-        0x06ad_3331_f5fd_feb2,
-        0xf4f8_c089_638f_0e39,
-        0x896f_2649_d63a_dd3a,
-        0x13e8_56ab_df4f_293d,
-    ],
-    [
-        0x1645_597f_1f9c_9c00,
-        0x1ed8_55fa_cf10_5d6d,
-        0xc7cc_312d_a188_8374,
-        0x0f39_8c87_1ec2_a022,
-    ],
-    [
-        0x5380_c911_230b_0c19,
-        0x45a2_387f_6630_dac5,
-        0x640f_b113_d7b6_0af8,
-        0x5f45_8b98_31bc_a014,
-    ],
-    [
-        0x407c_a868_e0cf_5a0e,
-        0xd366_eb92_e8d5_c77a,
-        0x0272_9f84_ec9a_4b5e,
-        0x4d5f_8287_a1d5_37a8,
-    ],
-    [
-        0x2c34_281f_d456_9d5c,
-        0xc1d7_d328_c69e_ed56,
-        0x4925_cf83_7d8c_3b27,
-        0x3665_bcc4_b46f_6fb8,
-    ],
-    [
-        0xae7d_62c4_4307_db55,
-        0x827c_2bb9_0268_3141,
-        0xc50b_6517_2b66_5ab8,
-        0x14b1_481c_eba9_844d,
-    ],
-    [
-        0x5251_1c82_a0c8_e481,
-        0x15af_ba1f_1b2f_b276,
-        0xcd57_e480_4573_810c,
-        0x3a86_6be2_4b63_39ef,
-    ],
-    [
-        0xc49c_7ca5_2c10_e4b5,
-        0x7be1_a8c9_25a7_113e,
-        0xedf1_876f_6527_4242,
-        0x628b_9a2a_a809_0413,
-    ],
-    [
-        0xd8f3_c0eb_62a0_f88c,
-        0x25e7_5bd3_eec2_0515,
-        0x1041_81f3_aecf_6c25,
-        0x4c4f_5161_ae23_e3bf,
-    ],
-    [
-        0x63e9_57de_e5b9_603f,
-        0x6ad4_aa44_dded_5b3b,
-        0x4324_202d_9d46_b634,
-        0x01f2_5572_9fdb_9ec5,
-    ],
-    [
-        0x8278_79bc_d529_0490,
-        0x5f52_fb95_02ed_7796,
-        0x65bb_1df1_91c3_a97f,
-        0x665c_58cf_d882_3ec1,
-    ],
-    [
-        0xba7e_a99d_b69d_5a3c,
-        0x5a4d_84c7_9259_c126,
-        0xf4a9_34ad_c312_d5e9,
-        0x599c_0654_a440_0707,
-    ],
-    [
-        0x793e_853a_0a77_edea,
-        0x0f67_d4e6_5424_a3ad,
-        0xb203_e81b_ca84_e248,
-        0x12f3_2fc8_4b5e_b492,
-    ],
-    [
-        0xec0d_b6f5_e01e_2e13,
-        0xdfe4_1b92_59f6_ae60,
-        0xca42_3b8b_7fe0_076d,
-        0x57bf_a5a5_4a3d_cf6f,
-    ],
-    [
-        0x8fd5_516f_806a_0db5,
-        0x178a_7462_6652_5a7d,
-        0x4536_f42a_e08e_3f98,
-        0x0043_0cc4_3832_1397,
-    ],
-    [
-        0x983e_40a0_00a9_a8cb,
-        0x8dbb_2a49_6d60_8115,
-        0x0db3_afce_fd3d_c21c,
-        0x6b8b_86e6_14ce_acdc,
-    ],
-    [
-        0x7234_02ac_9363_d802,
-        0xf673_aa00_1511_91a5,
-        0xceb9_ffc5_ad7d_5a28,
-        0x3e83_64d9_975b_6f84,
-    ],
-    [
-        0x3795_8ee4_c0ca_cea4,
-        0x8070_41ac_b01f_d18a,
-        0x3c16_9cd4_bdbd_7b2e,
-        0x0b45_43cb_1ff2_14e3,
-    ],
-    [
-        0x07c0_26a9_59e7_5859,
-        0x07e9_8044_6ed0_e536,
-        0xe9d9_4a73_a714_1084,
-        0x60f5_3b47_4e0b_cc1f,
-    ],
-    [
-        0x2c18_d513_6bdd_15a8,
-        0x79cb_e493_6855_3d17,
-        0x750a_a155_36d4_8ddd,
-        0x6186_a90d_b94e_c74f,
-    ],
-    [
-        0x452a_20e7_9ffe_3988,
-        0xddf8_985c_3ff4_cbc0,
-        0x0a01_82c4_d54e_ce16,
-        0x21c0_d670_df22_90be,
-    ],
-    [
-        0x3b1b_aa5a_2532_1622,
-        0xc9b4_f15f_c875_6685,
-        0xbd46_9dcd_0065_e681,
-        0x673f_6a9c_7379_4c48,
-    ],
-    [
-        0x9e4c_49be_3ee1_ccf6,
-        0x94ad_98e5_10bc_5f92,
-        0x09f2_79f4_5468_2f42,
-        0x3970_2dcd_e1c7_740c,
-    ],
-    [
-        0x76e9_1a25_3865_b5cd,
-        0x88b3_6b9d_933a_f89f,
-        0x0e54_555b_a521_5905,
-        0x57fd_0818_3d8c_176c,
-    ],
-    [
-        0xa054_95bd_b2b1_52e1,
-        0x496d_a7f8_62c1_676a,
-        0xe319_e657_f33a_0223,
-        0x374d_3977_fb6b_bf47,
-    ],
-    [
-        0x2421_08d6_8d6d_433c,
-        0xb607_4868_7aa9_7028,
-        0x5360_8356_7ecd_5211,
-        0x04dd_2472_fa05_9ae7,
-    ],
-    [
-        0xd178_b36a_1376_9ae1,
-        0x25b7_db15_f252_bd55,
-        0xaeb6_9cc7_8e9e_463b,
-        0x5cd1_5fa6_9282_335b,
-    ],
-    [
-        0x99df_f5a1_bd4f_3185,
-        0x8159_e131_77fe_104a,
-        0x2b68_0042_385b_d8d4,
-        0x5634_e024_c779_80fa,
-    ],
-    [
-        0xca6e_b6a5_ec07_2c21,
-        0xecf4_c886_fa78_f612,
-        0x366a_1caa_78bc_6833,
-        0x4c42_46c3_a66e_e228,
-    ],
-    [
-        0x62b5_9dea_0eb0_ce0e,
-        0xa9eb_69c2_28b8_8ab8,
-        0x1485_24a1_0fb7_c455,
-        0x6ecd_c206_c59d_99f4,
-    ],
-    [
-        0x73d1_0a54_d434_20ff,
-        0xc3e3_ef08_4342_e8fa,
-        0x132e_c3ba_e66e_15e1,
-        0x6a08_e7f3_581d_e25b,
-    ],
-    [
-        0xa5df_d88e_2e79_b94a,
-        0x6540_6bee_454f_14f3,
-        0x616b_7986_4cd7_2dcc,
-        0x6600_0aeb_80ef_bb02,
-    ],
-    [
-        0x9283_0095_5be9_a05f,
-        0x03ed_b422_c705_a465,
-        0x7981_e3f9_4e63_128f,
-        0x0d07_a518_9da0_80ea,
-    ],
-    [
-        0x287b_872b_77a2_f509,
-        0x3d82_501b_348f_a4ad,
-        0xa06f_1f27_32ad_e2b3,
-        0x2757_28cd_e985_5b78,
-    ],
-    [
-        0x76f9_82f0_9645_4786,
-        0xf926_969b_4527_10a8,
-        0x8b4f_4982_38e6_f3a4,
-        0x72da_1aa2_e661_a36e,
-    ],
-    [
-        0x0422_e277_4345_860c,
-        0x44c6_c9b4_8bc3_7624,
-        0x5e95_ec53_f9bb_27df,
-        0x56ec_a23e_59b7_f3f6,
-    ],
-    [
-        0x1dca_27d7_4a5f_76f4,
-        0xc1ce_99b6_8619_4a9f,
-        0x34cf_1764_88af_ce84,
-        0x3d2e_fb20_4b71_2292,
-    ],
-    [
-        0x829f_a5ce_fe5d_7a54,
-        0x9a5d_4c3c_8cdf_d4b8,
-        0xeea7_d580_c98e_8472,
-        0x7353_307e_d9b6_238c,
-    ],
-    [
-        0x60c7_501e_fcbe_cfb3,
-        0x72f1_8fcf_c509_bac3,
-        0x38a5_8654_8e58_ee90,
-        0x22bc_2eca_746c_c968,
-    ],
-    [
-        0xc2cf_b26d_d3f8_18c0,
-        0x0a67_98f7_7fee_9ca6,
-        0xa1b5_2c68_473b_584c,
-        0x1840_3eb3_add4_4cce,
-    ],
-    [
-        0x90b0_1b2a_1b0c_d945,
-        0x6932_96e6_aebf_425a,
-        0xd81f_831a_a450_e574,
-        0x680d_f99c_9cab_c12e,
-    ],
-    [
-        0xb364_e520_a6e6_9d44,
-        0xc3ed_dc5d_5f7e_c42f,
-        0x7a7e_4aa2_5bd7_6c52,
-        0x21b7_91ea_4d22_2bf9,
-    ],
-    [
-        0x2523_3b13_dd83_93d3,
-        0xfe69_52dd_8ecf_530a,
-        0x9bd2_807e_0e41_b358,
-        0x6543_0ef0_32b7_db41,
-    ],
-    [
-        0x455f_e1f0_2652_0957,
-        0xa1da_0a67_eb49_592e,
-        0xc390_e0d3_2f8e_b62e,
-        0x3667_9649_ed0f_830f,
-    ],
-    [
-        0xc691_8833_49b1_d629,
-        0xdf21_9062_69e2_5a29,
-        0xe05f_c075_ad05_6a09,
-        0x3811_013e_8c39_25b8,
-    ],
-    [
-        0xfd7d_c912_689e_1e64,
-        0xc27f_a609_4809_6421,
-        0x0ede_b48f_30cb_78c8,
-        0x2360_1e9f_727e_c6c1,
-    ],
-    [
-        0x76f2_76c8_8652_10fa,
-        0x0c78_ede1_2783_40f1,
-        0xa294_92ab_5e30_fbe1,
-        0x2ba2_0f8e_1fde_aca3,
-    ],
-    [
-        0x567a_1b88_3e64_010f,
-        0x4813_9333_7c1a_cc11,
-        0x1584_38f4_def3_fa01,
-        0x33cc_b5d5_dc84_0965,
-    ],
-    [
-        0x0d2d_221f_1214_8072,
-        0x211d_498d_7ee8_65ab,
-        0x9020_5b57_12f2_a8b0,
-        0x0e6e_f162_a964_47f3,
-    ],
-    [
-        0xa43b_c58f_9bb6_cce1,
-        0xb162_5494_4b2c_9455,
-        0x0954_363c_d959_ae54,
-        0x5f09_2df6_80c2_31a0,
-    ],
-    [
-        0x5e19_63a5_d3fe_b320,
-        0x122a_d4c4_4dec_15b6,
-        0xabae_71cc_243f_0cdf,
-        0x3ad5_ba86_2be8_fd0b,
-    ],
-    [
-        0x9673_44be_bcae_2b9d,
-        0xb21e_f104_a2df_497d,
-        0x752e_ed94_d4bd_3f6b,
-        0x2f9d_aea0_fa40_901f,
-    ],
-    [
-        0x4f12_9398_5e56_edea,
-        0x1c3f_0553_e1cf_070e,
-        0x7255_7f49_f412_32b1,
-        0x0ca5_e43f_7231_8445,
-    ],
-    [
-        0xefdb_9d35_1af0_6023,
-        0x958b_81dc_d5f3_12c6,
-        0xc41a_b06c_21ad_5b1c,
-        0x29d6_8667_2b6a_6b78,
-    ],
-    [
-        0xfd7e_9704_3fac_878f,
-        0xdc69_0cfd_e183_0c26,
-        0xfb39_11d8_5700_790d,
-        0x5501_563a_c9ae_b924,
-    ],
-    [
-        0xb5d9_7114_8513_5785,
-        0xc299_bb72_d004_699e,
-        0x0a66_ab29_5083_b380,
-        0x5e50_38d3_5723_1034,
-    ],
-    [
-        0x1cf1_8684_f022_1009,
-        0xb9aa_d49d_dea1_7745,
-        0x238b_b026_682f_3f62,
-        0x3159_cb9c_d636_0b9d,
-    ],
-    [
-        0x42e2_ab2a_972a_ed4a,
-        0xbfc8_20e2_ec6e_7fea,
-        0xb0ad_20f3_df3c_f4af,
-        0x3967_b2c0_733a_3b17,
-    ],
-    [
-        0x2e0e_e52f_1ff3_2456,
-        0x7ea8_80f5_6170_0aca,
-        0xca4c_01d2_c158_7e2a,
-        0x6696_ae35_b49a_2300,
-    ],
-    [
-        0xc6a8_24dc_a89b_47d6,
-        0x47c7_2684_ad0b_5f1c,
-        0x7010_e487_b0dc_3779,
-        0x1fd5_def8_4625_93e5,
-    ],
-    [
-        0xbff3_8bb8_cbbe_e780,
-        0x2010_3e5f_32e5_d00e,
-        0x5e8d_e725_5baf_b625,
-        0x29b7_1a8f_7c5b_6b1a,
-    ],
-    [
-        0x5b4a_e7af_12b8_8a97,
-        0xe9a3_d1ba_d06d_4acb,
-        0x427d_56f0_7d51_482f,
-        0x160e_f846_1720_048b,
-    ],
-    [
-        0xe8ab_55ea_8351_3c31,
-        0xe13a_e5ca_d31c_f772,
-        0xdb8b_618a_55de_9270,
-        0x6044_72b9_7984_80b6,
-    ],
-    [
-        0x621b_4e32_6984_8ac2,
-        0x4229_534a_d3dc_a19e,
-        0x6d58_6470_394b_7498,
-        0x6b4c_cd9b_629a_a5f8,
-    ],
-    [
-        0x1689_4b62_c8e5_e615,
-        0x1628_a5cf_22ab_25eb,
-        0x29fe_e17b_d96d_3456,
-        0x5796_e911_0d7e_5284,
-    ],
-    [
-        0x073e_14f6_b11c_66d0,
-        0xb985_7f6e_51e2_d969,
-        0x05ae_bb69_feb3_8d3e,
-        0x3009_d391_0443_81d0,
-    ],
-    [
-        0x4043_f411_17f6_4f24,
-        0xa042_777f_b6d5_f52f,
-        0xbe7f_9509_dfc4_1805,
-        0x2a01_7b37_1320_b2e1,
-    ],
-    [
-        0x82d5_3806_9097_4343,
-        0x8a8a_cc48_59b0_4028,
-        0x4820_cd88_ad7f_404e,
-        0x71a4_9e81_9417_fc7d,
-    ],
-    [
-        0x26f5_709e_179c_8b44,
-        0x154a_e90d_63ec_4901,
-        0xb78d_9fc2_adb9_02c2,
-        0x1224_615d_13e4_dddc,
-    ],
-    [
-        0x14f1_9f8b_e3c2_ef3f,
-        0xa570_11c6_35be_5d12,
-        0x2e97_a5db_3c0c_37f7,
-        0x7049_2d4b_677f_ba76,
-    ],
-    [
-        0x5c72_481b_66ff_df16,
-        0xa0c5_634c_d28d_4a9c,
-        0xc6aa_ecee_df4a_be0f,
-        0x598e_f558_0375_b4d1,
-    ],
-    [
-        0xffb9_559f_a30d_6191,
-        0xa53e_23cb_6e71_88a2,
-        0x5b16_2c02_89b8_dc94,
-        0x2e2f_a2ad_bcf0_5062,
-    ],
-    [
-        0x0678_ea18_6aeb_2a5c,
-        0x3bd4_8bda_0fc6_5666,
-        0xc963_f3cb_19f2_ce92,
-        0x405e_d65f_7798_0f4c,
-    ],
-    [
-        0x1dc9_9a4b_6f22_1a5e,
-        0xa9ab_7a3b_763e_1561,
-        0x3b24_4362_9ae6_f719,
-        0x4ff6_dbdb_a9f0_a536,
-    ],
-    [
-        0xa1a7_7440_dc18_fbe9,
-        0xdbad_409d_3b83_6c7d,
-        0x5bc9_f908_fa67_a928,
-        0x491f_cd2b_d279_11c5,
-    ],
-    [
-        0x3df9_16dc_c1da_41c7,
-        0x61b6_2545_0dc9_8894,
-        0xc250_c3ca_9db3_2900,
-        0x6208_1951_6d09_afb0,
-    ],
-    [
-        0xddfd_a880_e373_910c,
-        0x81e7_4b9a_4e44_e633,
-        0xb271_a47c_318a_5fef,
-        0x2966_b950_e79c_9f72,
-    ],
-    [
-        0xc998_d17f_998c_acb6,
-        0xdb6a_418a_b1a7_5023,
-        0xfb1d_fbba_1736_0e90,
-        0x2682_ca87_d692_ddaa,
-    ],
-    [
-        0xf298_e99b_fb3a_03e9,
-        0x650c_9b06_9cd8_b5ea,
-        0xbf93_c5ac_8fc4_0038,
-        0x51a2_bcbd_fd17_a652,
-    ],
-    [
-        0x6fae_e3a3_4230_2387,
-        0x2c7d_7532_a6f9_2156,
-        0x5fe6_e305_7add_9fcf,
-        0x0221_75c6_c38a_6cc4,
-    ],
-    [
-        0x2e0b_2b1b_fc26_e6ca,
-        0xf832_e83c_3c08_ef11,
-        0xe7bc_427a_9089_c539,
-        0x32ef_2d90_91f7_0a41,
-    ],
-    [
-        0x4864_e642_7743_0a53,
-        0x4353_7098_eb9b_be14,
-        0xe00a_614b_6807_118b,
-        0x0431_18b2_adcd_bed7,
-    ],
-    [
-        0xa3dd_a255_579d_6c1a,
-        0xc7e1_a654_93d6_25db,
-        0x99c3_0b05_dc01_7f42,
-        0x1a35_e4f1_7a0f_9142,
-    ],
-    [
-        0x2949_7dcc_d80e_70da,
-        0xf43d_b75b_285d_6c57,
-        0xbcee_0837_1a72_1ca0,
-        0x3416_5b25_28ae_a753,
-    ],
-    [
-        0x2364_1725_bbb1_d556,
-        0xd08b_cd5e_a42a_ca7d,
-        0xe934_a054_92bc_eb76,
-        0x212b_aba0_12c3_514f,
-    ],
-    [
-        0xa740_bd26_4590_f7d4,
-        0x03f2_006b_15e4_d029,
-        0x5b5b_2bbf_43b6_66db,
-        0x6d79_3151_1480_775f,
-    ],
-    [
-        0xef83_db01_1646_5559,
-        0xd8c3_755a_ccfb_3612,
-        0xe08e_dfdb_0c92_37c7,
-        0x28a2_9567_2820_05cf,
-    ],
-    [
-        0x6cd0_e1fb_0a73_29db,
-        0x5c7a_81f0_8a83_a021,
-        0x4b44_d114_bd35_1f0e,
-        0x527d_1145_c51d_6809,
-    ],
-    [
-        0x8023_2d34_7110_0e3d,
-        0xbe70_adf2_7a47_7184,
-        0x2fc2_d8e0_87fa_9e60,
-        0x560a_de31_58cb_8605,
-    ],
-    [
-        0xc26e_89e2_2ff0_0122,
-        0xa29e_7174_828b_d606,
-        0x1ffa_00c5_fbb0_d469,
-        0x21a8_95a4_a4d8_0e98,
-    ],
-    [
-        0x6d66_28a5_3b50_d75c,
-        0xa144_63df_d391_6b1c,
-        0x7ef8_8fb6_efc5_4d20,
-        0x5d4b_ef16_a897_1b0d,
-    ],
-    [
-        0xc496_49ff_ab08_1db8,
-        0x4279_3c12_a283_8239,
-        0xa832_6e71_9912_0521,
-        0x15e5_baa1_55ca_dd1e,
-    ],
-    [
-        0x1eb9_6a86_5a89_3a50,
-        0xe157_3f34_55cf_cad4,
-        0xda91_b7fe_720a_f561,
-        0x1f3e_858e_8025_dc3b,
-    ],
-    [
-        0x0a50_f4d2_34c5_5e9a,
-        0x8ebc_79b1_4207_ed9b,
-        0x2d9f_6aab_d709_550a,
-        0x1f9c_a259_3308_2f9f,
-    ],
-    [
-        0x4ea9_c8d8_4170_882b,
-        0xe9ff_635b_f521_1d3d,
-        0x8aef_f69e_70ab_507e,
-        0x62a5_b6f7_6af6_d92c,
-    ],
-    [
-        0x0da7_6d9e_5cc5_a8f6,
-        0x6934_70af_f8c0_e7c2,
-        0x55c1_66ba_dc5b_e7b1,
-        0x34f2_235c_5946_e988,
-    ],
-    [
-        0xc3be_6ae0_6c43_2fb9,
-        0x8a56_9ebe_a76d_3782,
-        0x41b7_3c21_3a32_15bf,
-        0x1b0c_84e6_8f19_8ce2,
-    ],
-    [
-        0x1c92_4ea1_b3c8_a646,
-        0x55ba_6ef2_53c1_0d44,
-        0x7be8_31cd_93c0_9ccd,
-        0x2ae7_0d76_18ad_1d00,
-    ],
-    [
-        0xf8d9_d552_03c8_29ae,
-        0xd650_8290_ada5_2ee6,
-        0x8fe4_48f3_f277_cb8d,
-        0x267d_dd7c_bf72_78ad,
-    ],
-    [
-        0x6a99_e841_1832_eb8c,
-        0xf7d3_7b52_436c_c0f5,
-        0x89aa_9e36_0fcf_3e30,
-        0x70cc_19af_2ca3_9be9,
-    ],
-    [
-        0xf2cb_c089_d292_cc96,
-        0xec69_c08b_ee1b_5d86,
-        0x87ba_5bbc_e366_693c,
-        0x086b_7add_eca3_5574,
-    ],
-    [
-        0xce40_965c_9295_f01b,
-        0x4742_e656_4707_3333,
-        0x29e6_3fef_b0b3_ec2e,
-        0x72c3_8d3e_b50e_65a9,
-    ],
-    [
-        0x72fb_f0f7_56b4_3720,
-        0x6695_c733_0ed2_30a4,
-        0xf5f0_ea66_66d0_e241,
-        0x3579_8605_fe0c_b8e4,
-    ],
-    [
-        0xc0da_34a4_0008_e8ca,
-        0x1f14_b622_367d_1d23,
-        0xbaab_8e52_dc80_6eb0,
-        0x6161_8623_96ec_eb03,
-    ],
-    [
-        0x6997_8a72_dd52_f4a5,
-        0x4dc6_e082_517a_460f,
-        0xb32d_d526_47cd_8760,
-        0x5bb1_81fc_7088_8bbf,
-    ],
-    [
-        0x5a8d_cfd0_4965_941c,
-        0x2268_7af2_7048_f0b7,
-        0xb5b2_0af8_880f_c6da,
-        0x2e1c_775d_7615_704c,
-    ],
-    [
-        0xebb7_7a04_7e7e_9521,
-        0x59b6_4673_4b33_dbf7,
-        0x1b15_69ba_7939_46f8,
-        0x657a_c688_a529_1271,
-    ],
-    [
-        0x8c7a_c263_4b55_c23b,
-        0xc318_3ef5_a842_55a8,
-        0xa318_11fc_9038_4aec,
-        0x26a5_751b_7453_b6f3,
-    ],
-    [
-        0xcb05_edee_e647_f86e,
-        0xbe62_2e75_48cd_59d2,
-        0x35cd_59e3_d689_725a,
-        0x19eb_29aa_3869_5f8f,
-    ],
-    [
-        0x292d_5272_e98a_6159,
-        0x8536_b2e8_b50f_fe61,
-        0xa98c_aaa4_ce5f_c404,
-        0x697b_eb29_e700_0780,
-    ],
-    [
-        0x8448_04c1_22a2_2b71,
-        0x4af0_8fbe_e4f9_c6c1,
-        0x3034_1ab8_11dd_6ea0,
-        0x339d_9137_71fa_ccaf,
-    ],
-    [
-        0x5ce0_adca_6f52_24b6,
-        0x78a1_f072_0576_6ffd,
-        0x6c2e_d1ac_83c6_7f8c,
-        0x19fc_b5c4_9d94_dfe6,
-    ],
-    [
-        0x5c75_f96e_983a_cbc6,
-        0x5d27_95fe_a746_999a,
-        0x2c5a_3a3b_c8e9_5138,
-        0x71da_6d7d_2331_b121,
-    ],
-    [
-        0xfb35_2bc8_5ac9_e206,
-        0x4ec8_c55d_ba11_817b,
-        0xecef_8a23_520c_eabd,
-        0x1355_ad49_2461_4063,
-    ],
-    [
-        0xeaba_4d95_b511_c63f,
-        0x58de_e6e2_b009_208d,
-        0xe7ab_f6a8_46cd_351b,
-        0x03c1_da43_d30b_611f,
-    ],
-    [
-        0x11b6_9268_bb54_b8db,
-        0x2b84_65a3_11e1_4de5,
-        0x046a_af8b_aadd_7372,
-        0x0c92_d6cd_c277_651a,
-    ],
-    [
-        0xa0f9_2492_fde1_7ee4,
-        0x2908_8596_8cb5_4dd1,
-        0x0938_3561_b2e0_8880,
-        0x323d_1ad1_09ca_7e7f,
-    ],
-    [
-        0xda0f_72c8_a753_523b,
-        0x268d_2340_49ee_f55d,
-        0xd6a2_6b34_1d0c_9d37,
-        0x57bf_4ff8_1e13_eea6,
-    ],
-    [
-        0x3d35_11fb_18c0_865b,
-        0xaf5e_6a2d_4d69_9af0,
-        0x2669_12c3_d449_36bc,
-        0x306f_99b3_d069_014d,
-    ],
-    [
-        0xdca4_260e_2964_f458,
-        0xd163_282b_74e1_aceb,
-        0x199b_4315_e2c7_51e0,
-        0x02e1_8043_2955_bee1,
-    ],
-    [
-        0x1fe7_53b3_475d_099d,
-        0xa1ea_b1b8_1855_5c88,
-        0xe08a_0a05_3be8_18f9,
-        0x3ddd_4110_3671_f288,
-    ],
-    [
-        0xc079_b1cc_8507_4c87,
-        0x904b_738e_e427_2d45,
-        0x9f87_30dd_b457_f092,
-        0x438c_2a4f_cdcb_9ed5,
-    ],
-    [
-        0x1bb2_1d32_25c6_d22a,
-        0xd481_ad60_b330_ba19,
-        0xc0d6_4cdc_bd53_8461,
-        0x0724_98e8_9df5_f1ef,
-    ],
-    [
-        0xab3d_eae0_46db_ebb4,
-        0xd80b_b76a_ffe6_4a9d,
-        0x7ef4_bd7d_ff7f_7fba,
-        0x6b2f_73ce_6221_859f,
-    ],
-    [
-        0xf5c8_80f9_a126_6432,
-        0x3420_3aa9_ca85_e0d9,
-        0xba1c_a77d_96af_391d,
-        0x3db3_9045_2f60_9c7a,
-    ],
-    [
-        0x3eb6_abab_c70f_f25b,
-        0xa174_09c4_7f37_95cd,
-        0x552e_e5bb_781e_6372,
-        0x038b_5887_bb21_8f7e,
-    ],
-    [
-        0x8eb3_bb81_0e28_a442,
-        0x481b_469e_3386_fe1d,
-        0x6012_2c25_76c6_da4e,
-        0x2e9e_5f34_b158_bbe7,
-    ],
-    [
-        0xef54_d2d3_41e1_50e4,
-        0xa2da_28ec_d744_26b9,
-        0x6e8a_b208_0880_3bfa,
-        0x34b2_0111_606d_861c,
-    ],
-    [
-        0x0938_b058_9934_a657,
-        0x44b1_af0e_ca23_474f,
-        0xbd89_3c44_2a61_76fc,
-        0x4a68_5143_43ea_effd,
-    ],
-    [
-        0x5121_9720_5de3_7542,
-        0x741c_e31a_513f_a48e,
-        0x05a5_e812_ced5_4f63,
-        0x6e72_c9d6_61f2_19be,
-    ],
-    [
-        0x065b_7205_42d9_39e3,
-        0x0b14_65d8_100a_09d0,
-        0xde0c_fac9_79c4_7131,
-        0x1903_98bc_0867_d0ff,
-    ],
-    [
-        0xb3f1_410f_ae09_f096,
-        0x0a09_d599_fc66_6f9c,
-        0x1f9c_faa9_0398_675f,
-        0x0922_f720_340c_8c96,
-    ],
-    [
-        0xdf9c_05dc_6411_6c6b,
-        0xcb6f_e8b5_9637_0349,
-        0x6220_b81c_fc36_725a,
-        0x4897_db14_b5de_04a5,
-    ],
-    [
-        0x3fa9_8f9f_e478_2eab,
-        0xd93b_164a_eca3_4c2b,
-        0x670b_59b5_e5b3_849d,
-        0x4e89_5e70_1771_cfa5,
-    ],
-    [
-        0x7b32_61b1_6a12_b1b8,
-        0xcf74_1f2f_105a_7c9f,
-        0x0e03_f00e_7f1e_2db6,
-        0x0036_c21e_9b86_1813,
-    ],
-    [
-        0xa1bf_2b75_a8de_6781,
-        0x6601_b10e_7476_1d61,
-        0x4cb2_a6da_b120_6176,
-        0x51a9_34a9_20b6_e343,
-    ],
-    [
-        0x4e26_e8bc_a414_c8d3,
-        0x60ae_13c1_da64_f1c7,
-        0xf4ac_e154_9164_57a8,
-        0x1eb8_2e13_4ef0_5416,
-    ],
-    [
-        0x4e0f_9156_c643_a05e,
-        0x66fc_4d2d_2b7c_b41f,
-        0x06f8_3625_e0a5_dbbd,
-        0x0b0a_a2a5_957b_76c1,
-    ],
-    [
-        0x5ead_76d3_edac_08aa,
-        0x4ed1_7f39_f582_d736,
-        0xe13c_a310_28ec_a336,
-        0x3a68_d887_dd21_7537,
-    ],
-    [
-        0x0035_54ac_1d6b_54dc,
-        0xc043_5f01_ec81_d91a,
-        0x2661_431b_3931_a175,
-        0x6337_8f8f_86e7_8ffa,
-    ],
-    [
-        0xf19a_8104_e701_db34,
-        0x3d72_0bb6_5dda_8724,
-        0xedd6_4f06_60b1_bc91,
-        0x62d7_ab48_dbd2_3db4,
-    ],
-    [
-        0xcd04_1541_ca62_81a3,
-        0x50ba_32d3_2069_d456,
-        0xcd75_397b_c1c2_8b20,
-        0x3805_c914_2824_0aad,
-    ],
-    [
-        0x403b_23b0_0933_a7ad,
-        0x4703_d4a7_9d2f_d41d,
-        0x4e1e_45fa_67fc_d24e,
-        0x5dc6_e516_357a_ec1a,
-    ],
-    [
-        0x7257_b0ea_789d_989f,
-        0xbbbb_0ced_9b87_c74a,
-        0xabcc_29d0_913f_42eb,
-        0x2e89_f3f3_2d1b_254e,
-    ],
-    [
-        0x32d8_15d5_9cf6_ed38,
-        0x4f74_5a1f_6756_ca79,
-        0x2ff9_1e75_7603_ee91,
-        0x2f2e_c400_dd28_02d1,
-    ],
-    [
-        0x05ab_c214_74ee_34d9,
-        0x77fb_20d2_2ab8_aa55,
-        0xa7e1_e97b_d917_d363,
-        0x15d2_4034_7b6a_1c7d,
-    ],
-    [
-        0xb40f_80b0_1581_f9ef,
-        0x8776_7d3c_fb99_4d41,
-        0x2b62_2a92_12f4_9d94,
-        0x5844_f855_52be_66ec,
-    ],
-    [
-        0x9e9a_5e79_d75c_5e62,
-        0xc766_c2f3_62a6_d997,
-        0x9d95_082d_493f_09b9,
-        0x7199_83d0_0b3e_df7b,
-    ],
-    [
-        0xc6da_461f_39b7_9f5a,
-        0x1a5b_2190_025b_706b,
-        0x04c7_7f7d_be8f_e7f8,
-        0x41e1_cc8d_96eb_99ce,
-    ],
-    [
-        0x9f43_fc00_53d5_4d39,
-        0xfb3a_adb9_f1e0_75e5,
-        0x1f0b_d14a_a9ca_0a3d,
-        0x4978_c07f_1b36_e9c8,
-    ],
-    [
-        0x9f83_2f4b_c8d4_b1cc,
-        0x4ede_0d90_8529_590e,
-        0x2bf6_11b5_c457_3862,
-        0x5b45_daaf_e94c_85ca,
-    ],
-    [
-        0xf5e4_0e31_eb1d_769d,
-        0xb368_7311_957d_d7e7,
-        0xa469_bd76_0e33_5a06,
-        0x1b52_01f5_70b6_84f8,
-    ],
-    [
-        0x2a4c_9057_ae33_7a82,
-        0xf9f6_251d_2d0c_f259,
-        0xb260_1b4c_be7b_ff60,
-        0x684d_a389_85ad_6c1a,
-    ],
-    [
-        0x4de6_bea2_d29c_e5f7,
-        0x2150_5301_7f97_9ea5,
-        0x3f22_819b_8090_ba12,
-        0x207b_b368_25ca_28c1,
-    ],
-    [
-        0x0a0b_a4f5_7b2a_f4c6,
-        0xb7b9_1a17_def1_a401,
-        0xd625_3fcf_7b23_563c,
-        0x3e7c_dd2e_a444_59ac,
-    ],
-    [
-        0xc0a6_fadd_2005_15dd,
-        0xd6b5_66e2_5962_21a1,
-        0x0236_450c_929f_7cce,
-        0x00e0_473e_eeef_cb5e,
-    ],
-    [
-        0x1932_f5b8_f86f_765c,
-        0x6762_e717_64e9_4283,
-        0x5d18_8ab7_c812_fd2e,
-        0x3f95_1123_1edc_3b71,
-    ],
-    [
-        0x997d_8005_c75a_304d,
-        0x1496_58e8_8de0_ff1d,
-        0x6979_e667_f145_78be,
-        0x691c_1420_be76_b4de,
-    ],
-    [
-        0xf4c1_ec69_3c0a_c440,
-        0xd702_4595_1b45_e7f6,
-        0xf27c_01aa_ebb4_c46b,
-        0x4386_8233_e291_714a,
-    ],
-    [
-        0xddc0_f0e2_4eb5_5ead,
-        0x7d15_937d_ea0b_cd0b,
-        0x2690_9bd3_4b8b_fd31,
-        0x0bdd_9eef_ce7d_73a3,
-    ],
-    [
-        0x4ec4_c4f2_6b21_b59a,
-        0xcb16_887e_c4fd_6b30,
-        0x837c_3491_e5b0_09cd,
-        0x6bad_873e_0f89_99cf,
-    ],
-    [
-        0x14ac_94f2_08e7_5472,
-        0x9136_5d73_3d64_0fe7,
-        0xaac3_c6f7_9b03_e26a,
-        0x4bb0_7d1c_b89f_06d1,
-    ],
-    [
-        0xbea2_629c_49c6_66ed,
-        0xb320_83d4_fd4c_fb3c,
-        0x8877_6c31_f4d0_d755,
-        0x469f_a79c_b85a_3cc6,
-    ],
-    [
-        0x25ee_a22a_bc06_8a39,
-        0x5f53_e3ae_4a8e_b6bb,
-        0xe2d0_d5ee_8c85_492d,
-        0x46e0_0c34_f95b_0d49,
-    ],
-    [
-        0x07b8_2128_5563_727b,
-        0x1455_9633_2aed_8856,
-        0xeac4_2470_95c3_4809,
-        0x4288_9316_83cd_c6bf,
-    ],
-    [
-        0x02fa_5a0d_972b_db2f,
-        0x776e_f5ed_115c_5f2e,
-        0x0725_645c_c908_759c,
-        0x0155_16e5_300f_ba17,
-    ],
-    [
-        0xe40a_2ab4_f5ee_ebd0,
-        0x8e8a_39bf_23fc_49e6,
-        0xec78_d72b_ba03_3d63,
-        0x3988_0196_d2e5_a51f,
-    ],
-    [
-        0xe5de_2d2a_4e41_73af,
-        0xaf31_54c3_65ce_cf8e,
-        0xb49b_c85c_2502_4359,
-        0x3901_688e_6cc9_e6d6,
-    ],
-    [
-        0x653d_5fba_eea7_aaf6,
-        0xd248_9a36_a2f2_9f80,
-        0x159f_ed80_9ae9_fd17,
-        0x30d0_5878_11c8_b3d6,
-    ],
-    [
-        0x234f_cf0e_9b2e_e619,
-        0x8d34_0763_a20d_0dcf,
-        0x046d_41c3_ca2e_4c57,
-        0x0f34_074d_beee_c9ca,
-    ],
-    [
-        0x2288_fb61_8571_15de,
-        0x361b_8afa_e0dc_6450,
-        0x1d70_5908_640d_5938,
-        0x2fad_3cb0_f5fe_ec28,
-    ],
-    [
-        0x0d10_5971_8fcd_b9ba,
-        0x1581_3001_edf7_5abd,
-        0x2321_4939_c6f3_730c,
-        0x32f4_d967_fa02_33d8,
-    ],
-    [
-        0x016e_221b_4226_9d74,
-        0x1404_d137_f201_91f6,
-        0x5c34_ba97_b10a_652d,
-        0x1333_fa39_d8a9_6a7f,
-    ],
-    [
-        0x1c09_e5e2_d62b_0454,
-        0x31f1_2e09_a74d_035e,
-        0x9892_98db_ec2c_d4c1,
-        0x66a2_dbf6_b48a_4bca,
-    ],
-    [
-        0x3e02_bc72_b9e5_16a9,
-        0x69e7_ff5f_148a_1139,
-        0xf48b_d773_665b_84c4,
-        0x0a46_19ff_a51b_29fe,
-    ],
-    [
-        0x9fd9_563c_4f5e_07d1,
-        0xe314_5b72_544e_4176,
-        0x3029_5133_93bc_82d7,
-        0x351d_4c1f_ab8e_5a1f,
-    ],
-    [
-        0xb3e8_bd7c_e046_0805,
-        0xa70a_d73d_6c05_c863,
-        0xcdb7_bb40_023e_694a,
-        0x23be_fe43_374c_4ebb,
-    ],
-    [
-        0x5fde_1d1c_9773_21db,
-        0x9d1c_03a1_dccf_9bdf,
-        0x444f_2b84_ac1a_e2b9,
-        0x1f96_2145_4fb8_e906,
-    ],
-    [
-        0x2631_6b66_c92d_b44c,
-        0xcf2b_60e6_3b79_ca51,
-        0x2961_73d7_e4cb_e3af,
-        0x62b6_5e97_0cba_c278,
-    ],
-    [
-        0x8bc1_52d7_0804_9355,
-        0xe7c3_6770_01ea_fe0a,
-        0x7659_18fb_1eb7_1f59,
-        0x52a9_9d78_6b68_3928,
-    ],
-    [
-        0x5ae2_82ea_1d87_031f,
-        0x2414_85cc_d5e8_a505,
-        0xd293_b740_a834_7291,
-        0x68bf_40f1_84f0_95a8,
-    ],
-    [
-        0x9ba1_44b0_3bba_ad7f,
-        0xd9c2_671d_b7aa_a021,
-        0x6d46_8fc5_38bb_6ba1,
-        0x526c_7bbe_9591_41d8,
-    ],
-    [
-        0xab8b_aae3_8da2_2d83,
-        0x2aa7_cb4d_6c13_5aca,
-        0x6888_b5d7_f1f1_4673,
-        0x08db_7195_ed3b_c104,
-    ],
-    [
-        0x7dd3_a352_1e8b_0d43,
-        0xc730_09c4_04bf_52a6,
-        0x4e88_de5e_62bd_d641,
-        0x3ba3_5415_1b90_0c22,
-    ],
-    [
-        0x3f44_ef0d_4b2e_9951,
-        0xd858_05f1_945c_89bd,
-        0x60fb_081f_7c7d_19d9,
-        0x0ac7_300f_041f_e32e,
-    ],
-    [
-        0xc7c0_e4db_f2f9_1c15,
-        0x838d_62d3_49f2_2d19,
-        0xa9aa_0747_6682_c678,
-        0x262d_abed_198d_e065,
-    ],
-    [
-        0x982f_f87e_a1b8_980e,
-        0x70a9_2f93_662f_c3e3,
-        0x6eaf_9095_a723_a82e,
-        0x62e5_1a8b_9baf_1d3f,
-    ],
-    [
-        0x2e26_9ef3_ef22_b23b,
-        0x4f6d_48dc_8963_d904,
-        0xd324_b733_6e41_3edd,
-        0x1170_8bb5_71d5_09ac,
-    ],
-    [
-        0x8657_2956_090d_e1ea,
-        0x91b8_bbd2_f78d_4e9e,
-        0x8256_126c_13b9_5e33,
-        0x53f9_880d_0648_3cd9,
-    ],
-    [
-        0x737b_6b0c_e10f_104f,
-        0xb160_490e_8373_e706,
-        0x3f80_0265_ec7d_d99c,
-        0x31ca_34ae_ceb8_a74e,
-    ],
-    [
-        0x37e6_5304_5d97_ee4f,
-        0x3e73_ca62_9561_cd3c,
-        0x8bab_1282_303e_d410,
-        0x4653_3573_a00c_cc0a,
-    ],
-    [
-        0x7b7a_b157_508c_02be,
-        0xaaa6_985f_cc2e_eb34,
-        0xcef3_4b1f_a59b_03b8,
-        0x68a4_e145_74b3_df12,
-    ],
-    [
-        0x535b_fd42_f038_87b5,
-        0x3859_d91e_df27_ba82,
-        0x2a5d_068c_af0a_7474,
-        0x5116_5c5b_1788_501e,
-    ],
-    [
-        0x222c_7392_6ddc_38b7,
-        0xd080_04ba_4743_5abc,
-        0x9df1_9457_8887_0d20,
-        0x2bc7_33f0_dd7a_6fc2,
-    ],
-    [
-        0x4e55_263c_6721_8e85,
-        0x3a22_7032_9ed4_671a,
-        0x769c_f000_f446_51d8,
-        0x4bb8_4c91_563d_713c,
-    ],
-    [
-        0xf169_cdcb_00e3_1cf9,
-        0xc4cd_0e48_393d_e8dd,
-        0x232d_bd6a_ea67_f6d4,
-        0x3af3_f3a2_591a_2a4a,
-    ],
-    [
-        0x70fb_df20_b266_ed5a,
-        0xabdb_a4ae_b8c2_e4fe,
-        0xe463_3e12_7b63_5bf0,
-        0x0b42_0753_c9ef_f136,
-    ],
-    [
-        0x22ab_09f2_b63b_6f02,
-        0x7a0f_b230_5f4b_1a55,
-        0x0200_5743_404d_0941,
-        0x416d_1e78_e815_5208,
-    ],
-    [
-        0xbf7f_25e8_4b4d_3601,
-        0x5cba_6fdb_c8ce_aa8e,
-        0xb6e5_07a3_dfc9_1e95,
-        0x5770_ddee_37fc_62cf,
-    ],
-    [
-        0xd920_6e6a_ba1c_c02b,
-        0x16f8_163a_4b1b_fa44,
-        0xe73d_3ab2_ebcb_918b,
-        0x432c_a53a_bcd0_632a,
-    ],
-    [
-        0x935b_2125_3a38_15bc,
-        0x6180_f038_472d_5522,
-        0xc0db_c9ae_7929_8321,
-        0x077f_2922_97e3_63a7,
-    ],
-    [
-        0xc27e_2b57_b889_2c2d,
-        0xa173_f43a_d2d4_b30a,
-        0x89b2_ebb5_91f1_50e0,
-        0x3fba_68e3_c14f_4a47,
-    ],
-    [
-        0x60ad_7e14_496a_7dee,
-        0xfc85_ac6b_cb52_be10,
-        0x4037_d9ce_54b8_028b,
-        0x2566_c711_286e_349a,
-    ],
-    [
-        0xafb2_e43c_1d04_eebf,
-        0x757e_9fe1_39ee_f9c1,
-        0x2e02_a197_2e2a_bb3d,
-        0x72fa_5bfa_d147_e396,
-    ],
-    [
-        0xf52f_6ac4_f8d1_afe5,
-        0x661d_23f2_3822_697b,
-        0x5a0f_c344_46fa_8de5,
-        0x41fb_b952_8a83_3371,
-    ],
-    [
-        0x4774_89e4_cc65_e432,
-        0xbaaa_b4ed_e2b7_0316,
-        0x7fd2_d4c9_7626_9cb2,
-        0x2437_10a9_6dc7_1010,
-    ],
-    [
-        0x1a6f_e63d_b581_6285,
-        0x97da_2257_d095_c44b,
-        0x1f78_e319_3c1b_a04f,
-        0x4460_74c4_0d31_79c4,
-    ],
-    [
-        0x64f1_2e2c_cd22_bfc3,
-        0x01f2_b712_8056_c911,
-        0x171e_acd4_39f3_090f,
-        0x0f66_da24_0205_0648,
-    ],
-    [
-        0x369c_0b78_d598_74e5,
-        0xeb8b_8076_dc07_d910,
-        0x1833_131b_6340_5e14,
-        0x321d_25b8_85f7_de08,
-    ],
-    [
-        0xbb27_b548_6e38_0753,
-        0x30e5_7a13_ec2c_378d,
-        0x826c_c690_7602_ed79,
-        0x086b_dc33_bd63_df00,
-    ],
-    [
-        0x820d_1e25_1d5c_7c8c,
-        0x85b9_043b_b7c8_53fa,
-        0x73d1_c82a_38ee_144f,
-        0x101d_4fb0_e050_9d2b,
-    ],
-    [
-        0x11c8_d50c_a711_0e34,
-        0xdf1b_cddd_8310_7451,
-        0x5296_0967_48b5_77c4,
-        0x3dc8_bf43_f405_2396,
-    ],
-    [
-        0x5936_7835_0ab2_53aa,
-        0x157c_342e_ddf7_2d8f,
-        0xf1a3_c471_d142_9ca1,
-        0x3c47_f971_4413_452f,
-    ],
-    [
-        0x7ee7_2e5d_523e_d9c5,
-        0xcd58_8880_bafb_a7e1,
-        0x3235_343c_7a6e_3b30,
-        0x3282_c5f9_7c99_8b23,
-    ],
-    [
-        0x15ea_f537_4e19_431d,
-        0x80f8_3656_4c25_ca6d,
-        0xcb91_5037_63a5_1d9a,
-        0x4e5b_da9c_66f3_e319,
-    ],
-    [
-        0x6e86_79d6_94ce_4b41,
-        0x84da_c14e_9889_772b,
-        0xb02a_72bf_3daa_75d5,
-        0x13a2_3a02_479f_a9ff,
-    ],
-    [
-        0x43a5_2ae5_28e6_c9f7,
-        0xc487_914f_2e9f_d592,
-        0xa1fb_31a1_993e_d31e,
-        0x1163_38dd_400b_3aa1,
-    ],
-    [
-        0x5f9c_b68d_8843_47bc,
-        0x6aca_1655_e126_4baf,
-        0xc75f_7849_5e85_8b27,
-        0x46eb_53f8_5a22_770f,
-    ],
-    [
-        0xd5b9_8bf4_d61d_6855,
-        0xf4a7_5cc5_9014_2a10,
-        0x6e6d_3072_4160_4a8d,
-        0x3ff3_4626_24dc_f3a3,
-    ],
-    [
-        0x9d3d_2148_23c0_6557,
-        0x32e4_7c7e_9183_48d8,
-        0xf2ab_a185_6f0a_dc68,
-        0x61ef_4b8e_52cc_55e6,
-    ],
-    [
-        0x9cc1_1e5c_932f_842d,
-        0x76b4_1837_6f21_eee7,
-        0x6185_1c35_6c75_1c39,
-        0x583c_d61d_c23e_cdfa,
-    ],
-    [
-        0x1593_f6a8_5de1_7c78,
-        0x6e87_178c_b7a0_f76f,
-        0x59e1_5407_e6dd_5cee,
-        0x1cea_64fc_5bba_dbe1,
-    ],
-    [
-        0x52b0_7d9e_dd41_ca26,
-        0x5d18_35b6_84a5_5f80,
-        0xb52b_e5fe_5a07_c433,
-        0x579a_77ed_42ea_304e,
-    ],
-    [
-        0x0a11_3ab8_3a10_ac83,
-        0x416f_e8f0_ad6c_d469,
-        0xb1e7_87bd_e79c_3de2,
-        0x4732_4713_fbe9_2731,
-    ],
-    [
-        0x1125_a2d6_0c49_0757,
-        0x4e2d_7ba1_0dbb_6c85,
-        0xe832_880b_5c0d_b37d,
-        0x52b5_46e4_bccc_5884,
-    ],
-    [
-        0x8e22_3ad8_0e8b_18da,
-        0x0516_d250_f2fb_0e60,
-        0x13f0_801a_0cee_24a0,
-        0x4772_8bea_efa1_438b,
-    ],
-    [
-        0x0940_4c76_e9b6_519a,
-        0x3ff5_6b19_2723_3be5,
-        0x3ba5_0f17_aab7_cf06,
-        0x3855_4cf9_6809_b066,
-    ],
-    [
-        0x9d7e_738e_0621_438c,
-        0xe64b_1351_bca6_c0f2,
-        0xdec2_bd55_48c3_4075,
-        0x5e5d_8f51_8f19_e728,
-    ],
-    [
-        0x3807_bce3_845a_fd0e,
-        0x8d12_faca_e702_1fbc,
-        0x4ac2_f47e_b4b7_1d44,
-        0x26a6_5d66_770a_e156,
-    ],
-    [
-        0xd9ca_b58c_054a_0994,
-        0xdb5e_be4e_b71c_a3d0,
-        0x60ab_2ee7_0322_81bc,
-        0x6b75_62ec_5f96_b4dd,
-    ],
-    [
-        0x4e95_1028_8053_f143,
-        0xea90_5f1a_8437_a72e,
-        0x2219_f422_7aab_00d9,
-        0x4e1c_a820_39f4_43b2,
-    ],
-    [
-        0x3585_fd44_535d_9259,
-        0x7186_d4f0_6833_0271,
-        0x771a_14bd_da33_13a4,
-        0x0acc_84f2_616c_1323,
-    ],
-    [
-        0x6da2_d40f_62d0_b5e1,
-        0x1e50_6cd4_6f96_7066,
-        0x6d8c_9fba_6cf0_66fa,
-        0x655e_f961_23ce_f052,
-    ],
-    [
-        0x2088_fe6a_954e_c8b3,
-        0x77b9_82f8_afc2_5cd6,
-        0x7cfa_ea92_59d9_48c4,
-        0x6e0c_b7e6_5858_147f,
-    ],
-    [
-        0x6a03_3cda_421e_8cdf,
-        0xa431_7fa8_8a99_1ca9,
-        0x4048_d787_b9bb_27ef,
-        0x0ec4_703c_0dce_912f,
-    ],
-    [
-        0x0e6b_bbf6_7fcb_fe60,
-        0xa10a_938b_6dec_8e84,
-        0x3999_02e8_3311_0d48,
-        0x53b8_c95f_7545_4019,
-    ],
-    [
-        0xb0b4_3247_7e82_ee2c,
-        0x0ba5_ab3c_ed66_d7c7,
-        0xfbcb_9fa3_e1d6_1ec2,
-        0x5e29_d420_91c7_d204,
-    ],
-    [
-        0x915b_413f_6113_3680,
-        0xa423_99f3_bf26_468e,
-        0xc8b6_d08d_2ef0_82e9,
-        0x15e3_479b_90f8_60f5,
-    ],
-    [
-        0xf2fc_dd44_1484_8914,
-        0x56e0_8f91_52bd_3ed8,
-        0x9d0a_9b4c_cade_befb,
-        0x4f63_f15f_1094_2341,
-    ],
-    [
-        0xe653_14d8_fd60_a4bf,
-        0x0492_40b6_ef45_9354,
-        0x8a80_4437_5515_0a64,
-        0x11c1_9e12_d3b1_dcdc,
-    ],
-    [
-        0x66b6_20bc_5d5b_fda7,
-        0x3d4c_e0b0_e756_deb2,
-        0xca38_23b0_c27c_4efe,
-        0x256d_5622_92dd_8856,
-    ],
-    [
-        0x49fa_0da1_0b5c_4ca0,
-        0x5db8_5736_0b3f_f905,
-        0x0074_6bd0_7617_038e,
-        0x6daf_b34b_7478_5e42,
-    ],
-    [
-        0x9488_81cf_cd18_c7f2,
-        0x9c5b_b98a_99f9_788e,
-        0x52f5_8564_171b_b30f,
-        0x093f_f34d_8961_d56e,
-    ],
-    [
-        0xf223_cdaa_45a5_d144,
-        0x6251_d32d_eac8_f576,
-        0x8739_7306_3d2e_1f62,
-        0x2bcd_1a59_0351_fd2b,
-    ],
-    [
-        0xf7d6_9bb7_1212_9756,
-        0x1362_4d77_f705_65bf,
-        0x50eb_402f_5a97_c0b6,
-        0x1ee0_27d0_4709_73d7,
-    ],
-    [
-        0x1922_194d_ba64_bbca,
-        0x2c7d_1021_e9b2_db63,
-        0x4f74_2d43_03d7_f288,
-        0x045c_f71c_4895_5a19,
-    ],
-    [
-        0x7b2d_0664_f493_d6ad,
-        0xb674_a92b_aabc_d0ac,
-        0x46d6_7f2f_6826_28ae,
-        0x4741_e2f5_0ed0_f683,
-    ],
-    [
-        0x7f62_740c_4ddf_f0ab,
-        0x50fc_56da_8447_5792,
-        0xf9ac_8d6a_03fb_ba14,
-        0x5f48_9f22_b82c_bcd2,
-    ],
-    [
-        0x3be6_915a_438f_445f,
-        0x33a0_8ac7_387d_567f,
-        0xc87a_e1fc_f9cc_5de1,
-        0x2713_386e_5890_b8f1,
-    ],
-    [
-        0xbbb6_c8a0_4877_3514,
-        0x0037_dca1_74cc_86dd,
-        0xc32d_ca62_730a_d0ab,
-        0x2afe_d7f0_782f_67a8,
-    ],
-    [
-        0xaac0_4c2c_9df8_b2a3,
-        0x93c5_af1b_1efe_960f,
-        0xc164_5973_b3b9_1834,
-        0x57d5_c02c_0585_8e55,
-    ],
-    [
-        0xf07d_5a0a_73a8_1754,
-        0xcedd_08c0_ec34_d807,
-        0x3ca1_817a_6446_6051,
-        0x655d_adcb_20e0_3d7c,
-    ],
-    [
-        0xd2fc_0b8b_aae2_de76,
-        0x435f_3851_1da5_05d0,
-        0xf1f7_6518_6130_7f1a,
-        0x6180_e25e_19b2_a038,
-    ],
-    [
-        0xc1b9_058a_8e0d_ed95,
-        0xd8eb_1687_a12c_f536,
-        0xe5dd_16e8_9168_3758,
-        0x1705_f1f5_256b_fc0a,
-    ],
-    [
-        0xc0a0_98bc_ccf0_5bc7,
-        0xcd0a_b5f0_f625_9d6b,
-        0x6797_d3b3_e553_9045,
-        0x0f63_56d6_9d62_306d,
-    ],
-    [
-        0x822f_24bd_18d3_ada3,
-        0x5f75_94b7_8700_5b7b,
-        0x9ebf_6b47_3cb8_718f,
-        0x3c40_371b_2613_a52a,
-    ],
-    [
-        0xde7b_d1cc_02bf_3f8c,
-        0xcffd_9988_abf8_9f54,
-        0x2008_ecaf_6da9_97b8,
-        0x68ea_e3af_25c5_8b88,
-    ],
-    [
-        0x202a_e723_0483_da1d,
-        0x51ae_c912_c9bf_e490,
-        0x9b1b_d0f3_10e5_4ebf,
-        0x4c1b_a4f9_3409_12ab,
-    ],
-    [
-        0xdfe0_ec8b_dc41_be53,
-        0x02b5_9fe4_8cff_f2ad,
-        0xd1de_4a46_fe46_e06b,
-        0x33aa_8219_9f04_c44a,
-    ],
-    [
-        0xfcf8_0a6e_598d_9191,
-        0xfe30_5034_36fc_8263,
-        0xce85_e28b_b94b_1603,
-        0x3270_dc02_8275_0da2,
-    ],
-    [
-        0xebb0_56d3_743d_c41f,
-        0xe63a_1377_d5c5_7831,
-        0x2000_c133_9886_ff1e,
-        0x15fe_db66_10d6_07e3,
-    ],
-    [
-        0x27c2_c9d9_594e_0a2c,
-        0x6888_b7ee_9def_ce8e,
-        0x2a75_a2c9_267b_c5ca,
-        0x0067_e674_c5b6_eb85,
-    ],
-    [
-        0x161a_05ef_dc78_7597,
-        0x34dc_3e01_47a5_aeba,
-        0xf6c6_fb2b_d7d0_3ed0,
-        0x0822_c0ce_6648_91d2,
-    ],
-    [
-        0x16e6_565e_9e26_a437,
-        0xb648_94c1_9ce1_2597,
-        0x0e31_e50d_88fa_7594,
-        0x415f_ec66_e44f_1fc2,
-    ],
-    [
-        0x2cc0_41a6_cd0c_fa7d,
-        0x56b3_455f_e9a2_3b93,
-        0x2b0a_af30_15c9_d308,
-        0x3b77_9718_395b_bc6b,
-    ],
-    [
-        0xf5e8_b2fb_32a8_36d8,
-        0xc9d7_dc20_d8a3_e74d,
-        0x6116_4f84_be5b_b1bc,
-        0x5554_f0af_d180_f658,
-    ],
-    [
-        0x15c3_f6a6_ad89_877f,
-        0x8943_2316_0692_008e,
-        0x2028_6b02_0c2c_9e7e,
-        0x0cb3_2d52_7b14_feef,
-    ],
-    [
-        0xeef2_0917_ba88_b34b,
-        0xde35_19f5_63c0_d3bf,
-        0x3b76_3395_3ae1_0266,
-        0x1bfd_1cf8_0505_b8c2,
-    ],
-    [
-        0x4700_8cc0_5fc9_1534,
-        0xe67c_f484_5a3c_ef6b,
-        0x11dd_3b52_4629_94a7,
-        0x2d67_5120_2bc4_3322,
-    ],
-    [
-        0x0043_c275_2994_6067,
-        0x3ede_25f7_9faa_5c25,
-        0x832b_6909_bcb6_5c90,
-        0x4d67_e460_2a5d_1143,
-    ],
-    [
-        0x36f2_83ca_f985_54c7,
-        0x037f_3bf9_beb9_29c4,
-        0x03a8_4c58_b69d_1458,
-        0x6e58_4505_cda4_d1ff,
-    ],
-    [
-        0x47a0_2b1b_3764_50dc,
-        0x9d04_053d_8c0c_25ed,
-        0x3835_20f4_cb68_32a5,
-        0x6155_4337_70db_3f7c,
-    ],
-    [
-        0x490a_9ce8_51f1_3d39,
-        0x581c_2b90_499e_4821,
-        0xc4a3_22fd_b97f_893c,
-        0x6aee_4021_c3b8_5098,
-    ],
-    [
-        0x6c8e_e0f6_8f3a_a725,
-        0x4bbf_ade5_d933_29e9,
-        0x7ac2_af7e_f1f2_1ce0,
-        0x63ea_b345_7edb_14df,
-    ],
-];
-  
\ No newline at end of file
+/// Loads the bundled parameters on the BLS12_381 curve
+pub fn bls12_381() -> NeptuneParameters<BlsFr> {
+    bundled::<BlsFr>().expect("The bundled parameters are valid")
+}
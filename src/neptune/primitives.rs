@@ -16,33 +16,38 @@
 */
 
 //! This module contains primitives used only for testing purpose with Neptune
-//! They implement both hashing and authenticated encryption on both BLS12_381 and Vesta
+//! They implement both hashing and authenticated encryption on any field with bundled parameters
 
 #![allow(unused)] // Only used within tests
 
-pub struct NeptunePrimitivesBlsFr {}
-
-use std::io::Error;
+use std::marker::PhantomData;
 
+use ark_ff::PrimeField;
 use ark_r1cs_std::{fields::fp::FpVar, prelude::FieldVar};
 use ark_relations::r1cs::SynthesisError;
 
 use ark_bls12_381::Fr as BlsFr;
-use ark_ff::BigInteger256 as I256;
 
-use super::params::*;
+use super::{chip::NeptuneParameters, params::*};
 use crate::common::pattern::*;
 
-impl NeptunePrimitivesBlsFr {
+pub struct NeptunePrimitives<F: PrimeField> {
+    _field: PhantomData<F>,
+}
+
+pub type NeptunePrimitivesBlsFr = NeptunePrimitives<BlsFr>;
+
+impl<F: PrimeField> NeptunePrimitives<F> {
     fn internal_round(
+        parameters: &NeptuneParameters<F>,
         index: usize,
-        mut state: [FpVar<BlsFr>; 4],
-    ) -> Result<[FpVar<BlsFr>; 4], SynthesisError> {
+        mut state: [FpVar<F>; 4],
+    ) -> Result<[FpVar<F>; 4], SynthesisError> {
         // do the simple operation ^d on the first element of the state
 
-        state[0] = state[0].clone().pow_by_constant(D)?;
+        state[0] = state[0].clone().pow_by_constant(&parameters.d)?;
 
-        let sum: FpVar<BlsFr> =
+        let sum: FpVar<F> =
             state[0].clone() + state[1].clone() + state[2].clone() + state[3].clone();
 
         // Compute the state
@@ -50,60 +55,39 @@ impl NeptunePrimitivesBlsFr {
         // the matrix diagonal value is used from the parameters
         // and finally the constant is added
         // a = a * matrix_val + b + c + d + const
-        for i in 0..state.len() {
-            state[i] = state[i].clone()
-                * FpVar::Constant(BlsFr::from(I256(INTERNAL_MATRIX_BLS[i])))
-                + sum.clone()
-                - state[i].clone()
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS_BLS[i + 4 * index])));
+        for (i, item) in state.iter_mut().enumerate() {
+            *item = item.clone() * parameters.matrix_int[i].clone() + sum.clone() - item.clone()
+                + parameters.round_constants[i + 4 * index].clone();
         }
 
         Ok(state)
     }
 
     fn external_round(
+        parameters: &NeptuneParameters<F>,
         index: usize,
-        mut state: [FpVar<BlsFr>; 4],
-    ) -> Result<[FpVar<BlsFr>; 4], SynthesisError> {
+        mut state: [FpVar<F>; 4],
+    ) -> Result<[FpVar<F>; 4], SynthesisError> {
         // Compute the S(.) operation using s_func(x0,x1) -> y0, y1
-        let (a, b) = Self::s_func(
-            state[0].clone(),
-            state[1].clone(),
-            FpVar::Constant(BlsFr::from(I256(GAMMA_BLS))),
-        )?;
-        let (c, d) = Self::s_func(
-            state[2].clone(),
-            state[3].clone(),
-            FpVar::Constant(BlsFr::from(I256(GAMMA_BLS))),
-        )?;
+        let (a, b) = Self::s_func(state[0].clone(), state[1].clone(), parameters.gamma.clone())?;
+        let (c, d) = Self::s_func(state[2].clone(), state[3].clone(), parameters.gamma.clone())?;
 
         // Apply the M matrix and add the round constants
         state = [
-            a.clone()
-                + a.clone()
-                + c.clone()
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS_BLS[4 * index]))),
-            b.clone()
-                + d.clone()
-                + d.clone()
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS_BLS[4 * index + 1]))),
-            a + c.clone()
-                + c
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS_BLS[4 * index + 2]))),
-            b.clone()
-                + b
-                + d
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS_BLS[4 * index + 3]))),
+            a.clone() + a.clone() + c.clone() + parameters.round_constants[4 * index].clone(),
+            b.clone() + d.clone() + d.clone() + parameters.round_constants[4 * index + 1].clone(),
+            a + c.clone() + c + parameters.round_constants[4 * index + 2].clone(),
+            b.clone() + b + d + parameters.round_constants[4 * index + 3].clone(),
         ];
 
         Ok(state)
     }
 
     fn s_func(
-        x0: FpVar<BlsFr>,
-        x1: FpVar<BlsFr>,
-        gamma: FpVar<BlsFr>,
-    ) -> Result<(FpVar<BlsFr>, FpVar<BlsFr>), SynthesisError> {
+        x0: FpVar<F>,
+        x1: FpVar<F>,
+        gamma: FpVar<F>,
+    ) -> Result<(FpVar<F>, FpVar<F>), SynthesisError> {
         // (x0 - x1)^2
         // (gamma + (x0-2x1) - (x0-x1)^2)^2
         let x0_x1_2 = (x0.clone() - x1.clone()).square()?;
@@ -130,47 +114,47 @@ impl NeptunePrimitivesBlsFr {
         Ok((y0, y1))
     }
 
-    fn permutation(mut state: [FpVar<BlsFr>; 4]) -> [FpVar<BlsFr>; 4] {
+    fn permutation(parameters: &NeptuneParameters<F>, mut state: [FpVar<F>; 4]) -> [FpVar<F>; 4] {
+        let [neb, nee] = parameters.nb_rounds_ext;
+        let ni = parameters.nb_rounds_int;
 
-        for i in 0..NEB {
-            state = Self::external_round(i, state).unwrap();
+        for i in 0..neb {
+            state = Self::external_round(parameters, i, state).unwrap();
         }
 
-        for i in 0..NI {
-            state = Self::internal_round(i + 4, state).unwrap();
+        for i in 0..ni {
+            state = Self::internal_round(parameters, i + neb, state).unwrap();
         }
 
-        for i in 0..NEE {
-            state = Self::external_round(i + 68, state).unwrap();
+        for i in 0..nee {
+            state = Self::external_round(parameters, i + neb + ni, state).unwrap();
         }
 
         state
     }
 
-    pub fn hash(message: Vec<BlsFr>) -> Result<FpVar<BlsFr>, Error> {
+    pub fn hash(message: Vec<F>) -> Result<FpVar<F>, anyhow::Error> {
+        let parameters = bundled::<F>()?;
         let mut state = [
             FpVar::zero(),
-            FpVar::constant(BlsFr::from(
-                gen_hash_pattern(message.len(), 1).get_tag(None),
-            )),
+            FpVar::constant(F::from(gen_hash_pattern(message.len(), 1).get_tag(None))),
             FpVar::zero(),
             FpVar::zero(),
         ];
 
         for element in message {
             state[0] = state[0].clone() + element;
-            state = Self::permutation(state);
+            state = Self::permutation(&parameters, state);
         }
 
         Ok(state[0].clone())
     }
 
-    pub fn ae(message: Vec<BlsFr>, key: BlsFr, nonce: BlsFr) -> Result<Vec<FpVar<BlsFr>>, Error> {
+    pub fn ae(message: Vec<F>, key: F, nonce: F) -> Result<Vec<FpVar<F>>, anyhow::Error> {
+        let parameters = bundled::<F>()?;
         let mut state = [
             FpVar::zero(),
-            FpVar::constant(BlsFr::from(
-                gen_ae_pattern(message.len(), 1, 1).get_tag(None),
-            )),
+            FpVar::constant(F::from(gen_ae_pattern(message.len(), 1, 1).get_tag(None))),
             FpVar::zero(),
             FpVar::zero(),
         ];
@@ -179,15 +163,15 @@ impl NeptunePrimitivesBlsFr {
 
         // Absorb both the key and the nonce
         state[0] = state[0].clone() + key;
-        state = Self::permutation(state);
+        state = Self::permutation(&parameters, state);
         state[0] = state[0].clone() + nonce;
-        state = Self::permutation(state);
+        state = Self::permutation(&parameters, state);
 
         // Get the ciphertext from the sponge and the message
         for block in message {
             ret.push(state[0].clone() + block);
             state[0] = state[0].clone() + block;
-            state = Self::permutation(state);
+            state = Self::permutation(&parameters, state);
         }
 
         ret.push(state[0].clone());
@@ -23,10 +23,16 @@ use super::hash_circuit::*;
 use super::params::*;
 use super::primitives::*;
 use crate::api::Sponge;
+use crate::common::rounds::{neptune_rounds, SECURITY_LEVEL};
+use crate::common::test_utils::is_satisfied;
 
+use ark_bls12_377::Fr as Bls377Fr;
 use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::{Bn254, Fr as BnFr};
+use ark_pallas::Fr as PallasFr;
+use ark_vesta::Fr as VestaFr;
 
-use ark_ff::{BigInteger256 as I256, UniformRand};
+use ark_ff::{One, PrimeField, UniformRand};
 use ark_groth16::Groth16;
 use ark_marlin::AHPForR1CS;
 use ark_marlin::Marlin;
@@ -40,28 +46,9 @@ type NepChpBls381 = NeptuneChip<BlsFr>;
 type NepSpnBls381 = Sponge<NepChpBls381>;
 type NepHshCrcBls381 = NeptuneHashCircuit<BlsFr>;
 type NepAECrcBls381 = NeptuneAECircuit<BlsFr>;
-type NepPrmBls381 = NeptuneParameters<BlsFr>;
-
-// Convert my generic generated parameters on the curve we use to test
-fn to_bls381(array: &[[u64; 4]]) -> Vec<FpVar<BlsFr>> {
-    let mut ret = Vec::new();
-
-    for element in array {
-        ret.push(FpVar::Constant(BlsFr::from(I256(*element))));
-    }
-
-    ret
-}
 
 fn get_sponge() -> Sponge<NeptuneChip<BlsFr>> {
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [NEB, NEE],
-        nb_rounds_int: NI,
-        round_constants: to_bls381(&ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(GAMMA_BLS))),
-        d: D,
-        matrix_int: to_bls381(&INTERNAL_MATRIX_BLS),
-    };
+    let parameters = bls12_381();
     let chip = NepChpBls381::new(parameters);
 
     NepSpnBls381::new(chip)
@@ -145,14 +132,7 @@ fn marlin_hash_bls() {
 
 #[test]
 fn groth16_hash_bls() {
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [NEB, NEE],
-        nb_rounds_int: NI,
-        round_constants: to_bls381(&ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(GAMMA_BLS))),
-        d: D,
-        matrix_int: to_bls381(&INTERNAL_MATRIX_BLS),
-    };
+    let parameters = bls12_381();
     let chip = NepChpBls381::new(parameters);
 
     let sponge = NepSpnBls381::new(chip);
@@ -194,14 +174,7 @@ fn groth16_hash_bls() {
 
 #[test]
 fn marlin_ae_bls() {
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [NEB, NEE],
-        nb_rounds_int: NI,
-        round_constants: to_bls381(&ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(GAMMA_BLS))),
-        d: D,
-        matrix_int: to_bls381(&INTERNAL_MATRIX_BLS),
-    };
+    let parameters = bls12_381();
     let chip = NepChpBls381::new(parameters);
 
     let sponge = NepSpnBls381::new(chip);
@@ -246,14 +219,7 @@ fn marlin_ae_bls() {
 
 #[test]
 fn groth16_ae_bls() {
-    let parameters = NepPrmBls381 {
-        nb_rounds_ext: [NEB, NEE],
-        nb_rounds_int: NI,
-        round_constants: to_bls381(&ROUND_CONSTANTS_BLS),
-        gamma: FpVar::Constant(BlsFr::from(I256(GAMMA_BLS))),
-        d: D,
-        matrix_int: to_bls381(&INTERNAL_MATRIX_BLS),
-    };
+    let parameters = bls12_381();
     let chip = NepChpBls381::new(parameters);
 
     let sponge = NepSpnBls381::new(chip);
@@ -293,3 +259,190 @@ fn groth16_ae_bls() {
     let res = GrothSetup::verify(&vk, &public, &proof).unwrap();
     assert!(res);
 }
+
+#[test]
+fn rounds_bls() {
+    assert_eq!(
+        neptune_rounds::<BlsFr>(M, 5, SECURITY_LEVEL),
+        (NEB + NEE, NI)
+    );
+
+    let mut parameters = bls12_381();
+    parameters.nb_rounds_int = NI - 1;
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_err());
+
+    // The exponent is checked before it is read
+    let mut parameters = bls12_381();
+    parameters.d.clear();
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_err());
+}
+
+#[test]
+fn validation_bls() {
+    let parameters = bls12_381();
+    assert!(NepChpBls381::try_new(parameters.clone()).is_ok());
+
+    // The constants don't match the number of rounds anymore
+    let mut invalid = parameters.clone();
+    invalid.nb_rounds_int += 1;
+    assert!(NepChpBls381::try_new(invalid).is_err());
+
+    // 3 divides p-1 on BLS12-381
+    let mut invalid = parameters.clone();
+    invalid.d = vec![3];
+    assert!(invalid.validate().is_err());
+
+    // A matrix full of 1s isn't invertible
+    let mut invalid = parameters;
+    invalid.matrix_int = vec![FpVar::Constant(BlsFr::one()); M];
+    assert!(invalid.validate().is_err());
+}
+
+/// Checks the hash and AE circuits against the native primitives on the field F
+fn native_vs_circuit<F: PrimeField>() {
+    let chip = NeptuneChip::try_new(bundled::<F>().unwrap()).unwrap();
+    let sponge = Sponge::<NeptuneChip<F>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![F::rand(rng), F::rand(rng)];
+    let key = F::rand(rng);
+    let nonce = F::rand(rng);
+
+    let hash = NeptunePrimitives::<F>::hash(message.clone())
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = NeptuneHashCircuit {
+        sponge: sponge.clone(),
+        message: message.clone(),
+        hash,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit;
+    invalid.hash += F::one();
+    assert!(!is_satisfied(invalid));
+
+    let ciphertext = NeptunePrimitives::<F>::ae(message.clone(), key, nonce)
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = NeptuneAECircuit {
+        sponge,
+        message,
+        ciphertext,
+        key,
+        nonce,
+    };
+    assert!(is_satisfied(circuit));
+}
+
+#[test]
+fn other_fields() {
+    native_vs_circuit::<BnFr>();
+    native_vs_circuit::<Bls377Fr>();
+    native_vs_circuit::<PallasFr>();
+    native_vs_circuit::<VestaFr>();
+}
+
+#[test]
+fn groth16_hash_bn254() {
+    let chip = NeptuneChip::try_new(bundled::<BnFr>().unwrap()).unwrap();
+    let sponge = Sponge::<NeptuneChip<BnFr>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BnFr::rand(rng)];
+    let hash = NeptunePrimitives::<BnFr>::hash(message.clone())
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = NeptuneHashCircuit {
+        message,
+        hash,
+        sponge,
+    };
+
+    type GrothSetup = Groth16<Bn254>;
+
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    let res = GrothSetup::verify(&vk, &[hash], &proof).unwrap();
+    assert!(res);
+}
+
+#[test]
+fn batch_ae_bls() {
+    let chip = NeptuneChip::try_new(bundled::<BlsFr>().unwrap()).unwrap();
+    let sponge = Sponge::<NeptuneChip<BlsFr>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let key = BlsFr::rand(rng);
+    let messages: Vec<Vec<BlsFr>> = [2, 0, 3]
+        .iter()
+        .map(|len| (0..*len).map(|_| BlsFr::rand(rng)).collect())
+        .collect();
+    let nonces: Vec<BlsFr> = (0..3).map(|_| BlsFr::rand(rng)).collect();
+    let ciphertexts: Vec<Vec<BlsFr>> = messages
+        .iter()
+        .zip(&nonces)
+        .map(|(m, n)| {
+            NeptunePrimitives::<BlsFr>::ae(m.clone(), key, *n)
+                .unwrap()
+                .value()
+                .unwrap()
+        })
+        .collect();
+
+    let circuit = NeptuneAEBatchCircuit {
+        sponge,
+        messages,
+        ciphertexts,
+        key,
+        nonces,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit.clone();
+    invalid.ciphertexts[2][0] += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.key += BlsFr::one();
+    assert!(!is_satisfied(invalid));
+}
+
+/// Pins the permutation and the hash over BLS12-381, with the round constants indexed by round
+/// across the three phases (internal rounds 4..72, last external rounds 72..74)
+#[test]
+fn known_answer_bls() {
+    use std::str::FromStr;
+
+    use crate::api::Permutation;
+
+    let chip = NepChpBls381::new(bls12_381());
+    let mut state: Vec<_> = (0..4u64).map(|i| FpVar::Constant(BlsFr::from(i))).collect();
+    chip.permute(&mut state).unwrap();
+    let expected = [
+        "16531303800782094404589817695063990573310898602062549817772864748408493007165",
+        "38977236109255513500418134110101683948724862188518079778618061100717814599393",
+        "15767946876293032808298841386101720868435470398580463230005997005963770908250",
+        "4881761687673167792544114821588870985866986066188639415091084706793744969607",
+    ];
+    for (element, expected) in state.iter().zip(expected) {
+        assert_eq!(element.value().unwrap(), BlsFr::from_str(expected).unwrap());
+    }
+
+    let message = vec![BlsFr::from(1u64), BlsFr::from(2u64), BlsFr::from(3u64)];
+    let hash = NeptunePrimitivesBlsFr::hash(message).unwrap();
+    assert_eq!(
+        hash.value().unwrap(),
+        BlsFr::from_str(
+            "15650176755898418508644283137233301237383002922817533811837083709462288102390"
+        )
+        .unwrap()
+    );
+}
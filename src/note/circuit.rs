@@ -0,0 +1,66 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, EqGadget},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use super::{owner, Note, NoteVar};
+use crate::{
+    api::{ChipAPI, Sponge},
+    merkle::{gadget::MerklePathVar, tree::MerklePath},
+};
+
+/// Proves the spending of a note: its commitment is in the tree of a public root, it belongs to
+/// the owner of the nullifier key, and the public nullifier is its own
+///
+/// The note, the nullifier key and the path are witnesses. The public inputs are the root then
+/// the nullifier, the verifier rejects nullifiers that were already revealed.
+#[derive(Clone)]
+pub struct SpendCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub sponge: Sponge<C>,
+    pub root: F,
+    pub nullifier: F,
+    pub note: Note<F>,
+    pub nk: F,
+    pub path: MerklePath<F>,
+}
+
+impl<F, C> ConstraintSynthesizer<F> for SpendCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let root = FpVar::new_input(cs.clone(), || Ok(self.root))?;
+        let nullifier = FpVar::new_input(cs.clone(), || Ok(self.nullifier))?;
+        let note = NoteVar::new_witness(cs.clone(), || Ok(self.note))?;
+        let nk = FpVar::new_witness(cs.clone(), || Ok(self.nk))?;
+        let path = MerklePathVar::new_witness(cs, || Ok(&self.path))?;
+
+        owner(&self.sponge, &nk)?.enforce_equal(&note.owner)?;
+        path.root(&self.sponge, &note.commitment(&self.sponge)?)?
+            .enforce_equal(&root)?;
+        note.nullifier(&self.sponge, &nk)?
+            .enforce_equal(&nullifier)?;
+
+        Ok(())
+    }
+}
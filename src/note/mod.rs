@@ -0,0 +1,188 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Notes of a shielded pool: note commitments, nullifiers and owners
+//!
+//! A note holds a value for an owner, with a unique `rho` and a hiding randomness `r`. Its
+//! commitment `H(value, owner, rho, r)` is appended to a Merkle tree of commitments, and spending
+//! it reveals the nullifier `PRF_nk(rho)`, where `nk` is the nullifier key of the owner and
+//! `owner = H(nk)`. The nullifier can't be linked to the commitment without `nk`, and a note has
+//! a single nullifier, so it can't be spent twice.
+//!
+//! The commitments, the nullifiers and the owners are hashed with their own domain separators,
+//! the Merkle trees are the ones of `merkle`. `circuit::SpendCircuit` puts the pieces together.
+
+use std::borrow::Borrow;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, AllocationMode},
+    R1CSVar,
+};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+use ark_std::rand::Rng;
+
+use crate::{
+    api::{ChipAPI, Sponge},
+    common::domain,
+    crh::hash_with_domain,
+};
+
+pub mod circuit;
+pub mod tests;
+
+/// The commitment of a note, natively (on constants) and in a circuit
+pub fn note_commitment<F, C>(
+    sponge: &Sponge<C>,
+    value: &FpVar<F>,
+    owner: &FpVar<F>,
+    rho: &FpVar<F>,
+    r: &FpVar<F>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let elements = [value.clone(), owner.clone(), rho.clone(), r.clone()];
+
+    hash_with_domain(sponge, &elements, Some(domain::NOTE_COMMITMENT))
+}
+
+/// The nullifier of a note, i.e. the PRF keyed by the nullifier key evaluated on `rho`
+pub fn nullifier<F, C>(
+    sponge: &Sponge<C>,
+    nk: &FpVar<F>,
+    rho: &FpVar<F>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    hash_with_domain(sponge, &[nk.clone(), rho.clone()], Some(domain::NULLIFIER))
+}
+
+/// The owner of the notes spent with a nullifier key
+pub fn owner<F, C>(sponge: &Sponge<C>, nk: &FpVar<F>) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    hash_with_domain(sponge, std::slice::from_ref(nk), Some(domain::OWNER))
+}
+
+/// Computes the owner of a nullifier key natively
+pub fn owner_native<F, C>(sponge: &Sponge<C>, nk: F) -> Result<F, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    Ok(owner(sponge, &FpVar::Constant(nk))?.value()?)
+}
+
+/// A note, the value is a field element (range checks are left to the application)
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Note<F: PrimeField> {
+    pub value: F,
+    pub owner: F,
+    pub rho: F,
+    pub r: F,
+}
+
+impl<F: PrimeField> Note<F> {
+    /// A note for the owner with a random `rho` and a random `r`
+    pub fn new<R: Rng>(rng: &mut R, value: F, owner: F) -> Self {
+        Self {
+            value,
+            owner,
+            rho: F::rand(rng),
+            r: F::rand(rng),
+        }
+    }
+
+    pub fn commitment<C>(&self, sponge: &Sponge<C>) -> Result<F, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        let [value, owner, rho, r] =
+            [self.value, self.owner, self.rho, self.r].map(FpVar::Constant);
+
+        Ok(note_commitment(sponge, &value, &owner, &rho, &r)?.value()?)
+    }
+
+    /// The nullifier revealed when the note is spent, the key must be the one of the owner
+    pub fn nullifier<C>(&self, sponge: &Sponge<C>, nk: F) -> Result<F, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        if owner_native(sponge, nk)? != self.owner {
+            return Err(anyhow::Error::msg(
+                "The nullifier key isn't the one of the owner",
+            ));
+        }
+
+        Ok(nullifier(sponge, &FpVar::Constant(nk), &FpVar::Constant(self.rho))?.value()?)
+    }
+}
+
+/// A note in the circuit
+#[derive(Clone)]
+pub struct NoteVar<F: PrimeField> {
+    pub value: FpVar<F>,
+    pub owner: FpVar<F>,
+    pub rho: FpVar<F>,
+    pub r: FpVar<F>,
+}
+
+impl<F: PrimeField> NoteVar<F> {
+    pub fn commitment<C>(&self, sponge: &Sponge<C>) -> Result<FpVar<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        note_commitment(sponge, &self.value, &self.owner, &self.rho, &self.r)
+    }
+
+    /// The nullifier of the note, it doesn't check that the key is the one of the owner
+    pub fn nullifier<C>(
+        &self,
+        sponge: &Sponge<C>,
+        nk: &FpVar<F>,
+    ) -> Result<FpVar<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        nullifier(sponge, nk, &self.rho)
+    }
+}
+
+impl<F: PrimeField> AllocVar<Note<F>, F> for NoteVar<F> {
+    fn new_variable<T: Borrow<Note<F>>>(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let cs = cs.into().cs();
+        let note = *f()?.borrow();
+
+        Ok(Self {
+            value: FpVar::new_variable(cs.clone(), || Ok(note.value), mode)?,
+            owner: FpVar::new_variable(cs.clone(), || Ok(note.owner), mode)?,
+            rho: FpVar::new_variable(cs.clone(), || Ok(note.rho), mode)?,
+            r: FpVar::new_variable(cs, || Ok(note.r), mode)?,
+        })
+    }
+}
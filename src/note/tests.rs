@@ -0,0 +1,189 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_snark::SNARK;
+
+use super::{circuit::SpendCircuit, nullifier, owner, owner_native, Note, NoteVar};
+use crate::{
+    api::Sponge,
+    common::test_utils::is_satisfied,
+    crh::{self, BundledSponge},
+    griffin::chip::GriffinChip,
+    merkle::tree::MerkleTree,
+    neptune::chip::NeptuneChip,
+    prf::prf_native,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+/// Checks the gadgets against the native notes, and that the hashes are separated
+fn native_and_gadget<F, C>()
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>> + Clone,
+{
+    let rng = &mut ark_std::test_rng();
+    let sponge: Sponge<C> = C::bundled_sponge().unwrap();
+    let nk = F::rand(rng);
+    let note = Note::new(rng, F::from(42u64), owner_native(&sponge, nk).unwrap());
+    let commitment = note.commitment(&sponge).unwrap();
+    let nf = note.nullifier(&sponge, nk).unwrap();
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    let note_var = NoteVar::new_witness(cs.clone(), || Ok(note)).unwrap();
+    let nk_var = FpVar::new_witness(cs.clone(), || Ok(nk)).unwrap();
+    assert_eq!(
+        note_var.commitment(&sponge).unwrap().value().unwrap(),
+        commitment
+    );
+    assert_eq!(
+        note_var
+            .nullifier(&sponge, &nk_var)
+            .unwrap()
+            .value()
+            .unwrap(),
+        nf
+    );
+    assert_eq!(
+        owner(&sponge, &nk_var).unwrap().value().unwrap(),
+        note.owner
+    );
+    assert!(cs.is_satisfied().unwrap());
+
+    // Neither the nullifier nor the commitment is a plain hash or the PRF of `prf`
+    let (nk, rho) = (FpVar::Constant(nk), FpVar::Constant(note.rho));
+    assert_ne!(
+        crh::hash(&sponge, &[nk.clone(), rho.clone()])
+            .unwrap()
+            .value()
+            .unwrap(),
+        nf
+    );
+    assert_ne!(
+        prf_native(&sponge, nk.value().unwrap(), note.rho).unwrap(),
+        nf
+    );
+    let elements = [note.value, note.owner, note.rho, note.r].map(FpVar::Constant);
+    assert_ne!(
+        crh::hash(&sponge, &elements).unwrap().value().unwrap(),
+        commitment
+    );
+    assert_ne!(nullifier(&sponge, &rho, &nk).unwrap().value().unwrap(), nf);
+}
+
+#[test]
+fn native_and_gadget_griffin() {
+    native_and_gadget::<BlsFr, GriffinChip<BlsFr>>();
+    native_and_gadget::<BnFr, GriffinChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_neptune() {
+    native_and_gadget::<BlsFr, NeptuneChip<BlsFr>>();
+    native_and_gadget::<BnFr, NeptuneChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_rescue_prime() {
+    native_and_gadget::<BlsFr, RescuePrimeChip<BlsFr>>();
+}
+
+#[test]
+fn notes() {
+    let rng = &mut ark_std::test_rng();
+    let sponge = GriffinChip::<BlsFr>::bundled_sponge().unwrap();
+    let nk = BlsFr::rand(rng);
+    let owner = owner_native(&sponge, nk).unwrap();
+
+    // Two notes of the same value and owner have other commitments and nullifiers
+    let note = Note::new(rng, BlsFr::from(5u64), owner);
+    let other = Note::new(rng, BlsFr::from(5u64), owner);
+    assert_ne!(
+        note.commitment(&sponge).unwrap(),
+        other.commitment(&sponge).unwrap()
+    );
+    assert_ne!(
+        note.nullifier(&sponge, nk).unwrap(),
+        other.nullifier(&sponge, nk).unwrap()
+    );
+
+    // Only the owner can compute the nullifier
+    assert!(note.nullifier(&sponge, BlsFr::rand(rng)).is_err());
+}
+
+/// Builds a tree of commitments with one note of the spender and its spend circuit
+fn spend() -> SpendCircuit<BlsFr, NeptuneChip<BlsFr>> {
+    let rng = &mut ark_std::test_rng();
+    let sponge = NeptuneChip::<BlsFr>::bundled_sponge().unwrap();
+    let nk = BlsFr::rand(rng);
+    let note = Note::new(rng, BlsFr::from(100u64), owner_native(&sponge, nk).unwrap());
+
+    let mut leaves: Vec<BlsFr> = (0..5).map(|_| BlsFr::rand(rng)).collect();
+    leaves.push(note.commitment(&sponge).unwrap());
+    let tree = MerkleTree::new(sponge.clone(), 3, &leaves).unwrap();
+
+    SpendCircuit {
+        root: tree.root(),
+        nullifier: note.nullifier(&sponge, nk).unwrap(),
+        note,
+        nk,
+        path: tree.path(5).unwrap(),
+        sponge,
+    }
+}
+
+#[test]
+fn spend_circuit() {
+    let circuit = spend();
+    assert!(is_satisfied(circuit.clone()));
+
+    // Another key doesn't own the note, and doesn't give its nullifier
+    let mut wrong = circuit.clone();
+    wrong.nk = BlsFr::from(1u64);
+    assert!(!is_satisfied(wrong.clone()));
+    wrong.note.owner = owner_native(&wrong.sponge, wrong.nk).unwrap();
+    assert!(!is_satisfied(wrong));
+
+    // The nullifier must be the one of the note
+    let mut wrong = circuit.clone();
+    wrong.nullifier += BlsFr::from(1u64);
+    assert!(!is_satisfied(wrong));
+
+    // The note must be in the tree
+    let mut wrong = circuit;
+    wrong.note.value = BlsFr::from(1000u64);
+    assert!(!is_satisfied(wrong));
+}
+
+#[test]
+fn groth16_spend() {
+    let rng = &mut ark_std::test_rng();
+    let circuit = spend();
+    let public_inputs = [circuit.root, circuit.nullifier];
+
+    let (pk, vk) = Groth16::<Bls381>::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = Groth16::prove(&pk, circuit, rng).unwrap();
+    assert!(Groth16::verify(&vk, &public_inputs, &proof).unwrap());
+    assert!(!Groth16::verify(&vk, &[public_inputs[0], BlsFr::from(1u64)], &proof).unwrap());
+}
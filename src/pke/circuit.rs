@@ -0,0 +1,91 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_bls12_381::Fr as BlsFr;
+use ark_ec::AffineCurve;
+use ark_ed_on_bls12_381::{constraints::EdwardsVar, EdwardsAffine, Fr as JubjubFr};
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    groups::CurveVar,
+    prelude::{AllocVar, Boolean, EqGadget},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use super::{kdf, HybridCiphertext, HybridEncryption, NONCE};
+use crate::api::ChipAPI;
+
+/// Proves that a ciphertext encrypts a message to a recipient public key
+///
+/// The message and the ephemeral scalar are witnesses. The public inputs are the coordinates of
+/// the recipient public key, those of the ephemeral public key, then the ciphertext.
+#[derive(Clone)]
+pub struct HybridEncryptionCircuit<C: ChipAPI<Value = FpVar<BlsFr>> + Clone> {
+    pub pke: HybridEncryption<C>,
+    pub recipient: EdwardsAffine,
+    pub ciphertext: HybridCiphertext,
+    pub message: Vec<BlsFr>,
+    pub randomness: JubjubFr,
+}
+
+impl<C: ChipAPI<Value = FpVar<BlsFr>> + Clone> HybridEncryptionCircuit<C> {
+    /// The public inputs, in the order of the circuit
+    pub fn public_inputs(&self) -> Vec<BlsFr> {
+        let (pk, eph) = (self.recipient, self.ciphertext.ephemeral);
+
+        [&[pk.x, pk.y, eph.x, eph.y], &self.ciphertext.ciphertext[..]].concat()
+    }
+}
+
+impl<C: ChipAPI<Value = FpVar<BlsFr>> + Clone> ConstraintSynthesizer<BlsFr>
+    for HybridEncryptionCircuit<C>
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<BlsFr>) -> Result<(), SynthesisError> {
+        let recipient = EdwardsVar::new_input(cs.clone(), || Ok(self.recipient))?;
+        let ephemeral = EdwardsVar::new_input(cs.clone(), || Ok(self.ciphertext.ephemeral))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext.ciphertext))?;
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message))?;
+
+        let bits = self.randomness.into_repr().to_bits_le();
+        let mut r = Vec::with_capacity(JubjubFr::size_in_bits());
+        for bit in bits.into_iter().take(JubjubFr::size_in_bits()) {
+            r.push(Boolean::new_witness(cs.clone(), || Ok(bit))?);
+        }
+
+        // R = r * G and S = r * pk
+        let generator = EdwardsVar::constant(EdwardsAffine::prime_subgroup_generator().into());
+        generator
+            .scalar_mul_le(r.iter())?
+            .enforce_equal(&ephemeral)?;
+        let shared = recipient.scalar_mul_le(r.iter())?;
+
+        let points = [
+            ephemeral.x.clone(),
+            ephemeral.y.clone(),
+            shared.x.clone(),
+            shared.y.clone(),
+        ];
+        let key = kdf(&self.pke.kdf, &points, self.pke.scheme.key_len())?;
+
+        self.pke
+            .scheme
+            .encrypt(&key, &FpVar::Constant(BlsFr::from(NONCE)), &m)?
+            .enforce_equal(&ct)?;
+
+        Ok(())
+    }
+}
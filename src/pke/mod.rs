@@ -0,0 +1,176 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Hybrid public key encryption on Jubjub, the curve embedded in BLS12-381
+//!
+//! The sender picks an ephemeral scalar `r` and sends `R = r * G`. The shared point `S = r * pk`
+//! (also `sk * R`) goes through a KDF, a sponge absorbing `R` and `S` and squeezing the key of the
+//! AE scheme. As each key encrypts a single message, the nonce is 0. The circuit proves that a
+//! ciphertext was correctly encrypted to a recipient public key.
+
+use ark_bls12_381::Fr as BlsFr;
+use ark_ec::{AffineCurve, ProjectiveCurve};
+use ark_ed_on_bls12_381::{EdwardsAffine, Fr as JubjubFr};
+use ark_ff::{PrimeField, UniformRand, Zero};
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::SynthesisError;
+use ark_std::rand::{CryptoRng, RngCore};
+
+use crate::{
+    api::{ChipAPI, Sponge, SpongeAPI},
+    common::{
+        domain,
+        pattern::{IOPattern, SpongeOp},
+    },
+    predicate::Scheme,
+};
+
+pub mod circuit;
+pub mod tests;
+
+/// The nonce of the AE, each key encrypting a single message
+pub const NONCE: u64 = 0;
+
+/// An encrypted message: the ephemeral public key, then the ciphertext followed by the tag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HybridCiphertext {
+    pub ephemeral: EdwardsAffine,
+    pub ciphertext: Vec<BlsFr>,
+}
+
+/// Hybrid encryption with an AE scheme, its keys derived with a sponge
+#[derive(Clone)]
+pub struct HybridEncryption<C: ChipAPI<Value = FpVar<BlsFr>> + Clone> {
+    pub scheme: Scheme<BlsFr>,
+    pub kdf: Sponge<C>,
+}
+
+impl<C: ChipAPI<Value = FpVar<BlsFr>> + Clone> HybridEncryption<C> {
+    pub fn new(scheme: Scheme<BlsFr>, kdf: Sponge<C>) -> Self {
+        Self { scheme, kdf }
+    }
+
+    /// Generates a secret key and its public key
+    pub fn keygen<R: RngCore + CryptoRng>(rng: &mut R) -> (JubjubFr, EdwardsAffine) {
+        let sk = JubjubFr::rand(rng);
+        (sk, public_key(sk))
+    }
+
+    /// Encrypts a message to a public key, returns the ciphertext and the ephemeral scalar
+    ///
+    /// The scalar is the witness of the circuit, it must be kept secret as the key it derives.
+    pub fn encrypt<R: RngCore + CryptoRng>(
+        &self,
+        rng: &mut R,
+        recipient: &EdwardsAffine,
+        message: &[BlsFr],
+    ) -> Result<(HybridCiphertext, JubjubFr), anyhow::Error> {
+        check_point(recipient)?;
+
+        let r = JubjubFr::rand(rng);
+        let ciphertext = self.encrypt_with(r, recipient, message)?;
+        Ok((ciphertext, r))
+    }
+
+    /// Encrypts a message to a public key with a given ephemeral scalar
+    pub fn encrypt_with(
+        &self,
+        r: JubjubFr,
+        recipient: &EdwardsAffine,
+        message: &[BlsFr],
+    ) -> Result<HybridCiphertext, anyhow::Error> {
+        if r.is_zero() {
+            return Err(anyhow::Error::msg("The ephemeral scalar can't be 0"));
+        }
+
+        let ephemeral = public_key(r);
+        let shared = recipient.mul(r.into_repr()).into_affine();
+        let key = self.key(&ephemeral, &shared)?;
+
+        Ok(HybridCiphertext {
+            ephemeral,
+            ciphertext: self
+                .scheme
+                .encrypt_native(&key, BlsFr::from(NONCE), message)?,
+        })
+    }
+
+    /// Decrypts a message with a secret key, fails if the tag is invalid
+    pub fn decrypt(
+        &self,
+        sk: JubjubFr,
+        ciphertext: &HybridCiphertext,
+    ) -> Result<Vec<BlsFr>, anyhow::Error> {
+        check_point(&ciphertext.ephemeral)?;
+
+        let shared = ciphertext.ephemeral.mul(sk.into_repr()).into_affine();
+        let key = self.key(&ciphertext.ephemeral, &shared)?;
+
+        self.scheme
+            .decrypt_native(&key, BlsFr::from(NONCE), &ciphertext.ciphertext)
+    }
+
+    fn key(
+        &self,
+        ephemeral: &EdwardsAffine,
+        shared: &EdwardsAffine,
+    ) -> Result<Vec<BlsFr>, anyhow::Error> {
+        let points = [ephemeral.x, ephemeral.y, shared.x, shared.y].map(FpVar::Constant);
+        let key = kdf(&self.kdf, &points, self.scheme.key_len())?;
+
+        Ok(key.value()?)
+    }
+}
+
+/// The public key of a secret key
+pub fn public_key(sk: JubjubFr) -> EdwardsAffine {
+    EdwardsAffine::prime_subgroup_generator()
+        .mul(sk.into_repr())
+        .into_affine()
+}
+
+/// Derives `len` elements of key from the coordinates of the ephemeral and the shared points
+///
+/// It works both natively (on constants) and in a circuit.
+pub fn kdf<C>(
+    sponge: &Sponge<C>,
+    points: &[FpVar<BlsFr>; 4],
+    len: usize,
+) -> Result<Vec<FpVar<BlsFr>>, SynthesisError>
+where
+    C: ChipAPI<Value = FpVar<BlsFr>> + Clone,
+{
+    let mut sponge = sponge.clone();
+    let pattern = IOPattern::new(vec![SpongeOp::Absorb(4), SpongeOp::Squeeze(len as u32)]);
+
+    sponge.start(pattern, Some(domain::PKE_KDF));
+    sponge.absorb(4, points);
+    let key = sponge.squeeze(len as u32);
+
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(key)
+}
+
+/// Checks that a point is in the prime order subgroup, and isn't the identity
+fn check_point(point: &EdwardsAffine) -> Result<(), anyhow::Error> {
+    if point.is_zero() || !point.is_on_curve() || !point.is_in_correct_subgroup_assuming_on_curve()
+    {
+        return Err(anyhow::Error::msg("Invalid Jubjub public key"));
+    }
+    Ok(())
+}
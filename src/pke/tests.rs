@@ -0,0 +1,165 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_ed_on_bls12_381::{EdwardsAffine, Fr as JubjubFr};
+use ark_ff::{One, UniformRand, Zero};
+use ark_groth16::Groth16;
+use ark_snark::SNARK;
+
+use super::{circuit::HybridEncryptionCircuit, public_key, HybridEncryption};
+use crate::{
+    api::Sponge,
+    ciminion::{self, chip::CiminionChip},
+    common::test_utils::is_satisfied,
+    griffin::{self, chip::GriffinChip},
+    neptune::{self, chip::NeptuneChip},
+    predicate::Scheme,
+};
+
+fn neptune() -> Sponge<NeptuneChip<BlsFr>> {
+    Sponge::<NeptuneChip<BlsFr>>::new(NeptuneChip::try_new(neptune::params::bls12_381()).unwrap())
+}
+
+fn schemes() -> Vec<Scheme<BlsFr>> {
+    vec![
+        Scheme::Griffin(Sponge::<GriffinChip<BlsFr>>::new(
+            GriffinChip::try_new(griffin::params::bls12_381()).unwrap(),
+        )),
+        Scheme::Neptune(neptune()),
+        Scheme::Ciminion(CiminionChip::try_new(ciminion::params::bls12_381()).unwrap()),
+    ]
+}
+
+fn random(len: usize) -> Vec<BlsFr> {
+    let rng = &mut ark_std::test_rng();
+    (0..len).map(|_| BlsFr::rand(rng)).collect()
+}
+
+#[test]
+fn hybrid_round_trip() {
+    let rng = &mut ark_std::test_rng();
+    let (sk, pk) = HybridEncryption::<NeptuneChip<BlsFr>>::keygen(rng);
+    let (other, _) = HybridEncryption::<NeptuneChip<BlsFr>>::keygen(rng);
+
+    for scheme in schemes() {
+        let pke = HybridEncryption::new(scheme, neptune());
+
+        for len in [0, 1, 4] {
+            let message = random(len);
+            let (ciphertext, _) = pke.encrypt(rng, &pk, &message).unwrap();
+            assert_eq!(ciphertext.ciphertext.len(), len + 1);
+            assert_eq!(pke.decrypt(sk, &ciphertext).unwrap(), message);
+            assert!(pke.decrypt(other, &ciphertext).is_err());
+
+            // Each encryption uses a new ephemeral key
+            assert_ne!(pke.encrypt(rng, &pk, &message).unwrap().0, ciphertext);
+
+            let mut invalid = ciphertext.clone();
+            invalid.ciphertext[len] += BlsFr::one();
+            assert!(pke.decrypt(sk, &invalid).is_err());
+
+            let mut invalid = ciphertext;
+            invalid.ephemeral = public_key(JubjubFr::rand(rng));
+            assert!(pke.decrypt(sk, &invalid).is_err());
+        }
+    }
+}
+
+#[test]
+fn hybrid_invalid_points() {
+    let rng = &mut ark_std::test_rng();
+    let pke = HybridEncryption::new(Scheme::Neptune(neptune()), neptune());
+    let message = random(2);
+
+    assert!(pke.encrypt(rng, &EdwardsAffine::zero(), &message).is_err());
+    assert!(pke
+        .encrypt_with(JubjubFr::zero(), &public_key(JubjubFr::one()), &message)
+        .is_err());
+
+    // (0, -1) has order 2, it is rejected as a public or an ephemeral key
+    let small = EdwardsAffine::new(BlsFr::zero(), -BlsFr::one());
+    assert!(small.is_on_curve());
+    assert!(pke.encrypt(rng, &small, &message).is_err());
+
+    let (sk, pk) = HybridEncryption::<NeptuneChip<BlsFr>>::keygen(rng);
+    let (mut ciphertext, _) = pke.encrypt(rng, &pk, &message).unwrap();
+    ciphertext.ephemeral = small;
+    assert!(pke.decrypt(sk, &ciphertext).is_err());
+}
+
+#[test]
+fn hybrid_circuit() {
+    let rng = &mut ark_std::test_rng();
+    let (_, pk) = HybridEncryption::<NeptuneChip<BlsFr>>::keygen(rng);
+    let (_, other) = HybridEncryption::<NeptuneChip<BlsFr>>::keygen(rng);
+
+    for scheme in schemes() {
+        let pke = HybridEncryption::new(scheme, neptune());
+        let message = random(3);
+        let (ciphertext, randomness) = pke.encrypt(rng, &pk, &message).unwrap();
+
+        let circuit = HybridEncryptionCircuit {
+            pke,
+            recipient: pk,
+            ciphertext,
+            message,
+            randomness,
+        };
+        assert!(is_satisfied(circuit.clone()));
+
+        // Not encrypted to another recipient
+        let mut invalid = circuit.clone();
+        invalid.recipient = other;
+        assert!(!is_satisfied(invalid));
+
+        let mut invalid = circuit;
+        invalid.randomness += JubjubFr::one();
+        assert!(!is_satisfied(invalid));
+    }
+}
+
+#[test]
+fn groth16_hybrid_bls() {
+    let rng = &mut ark_std::test_rng();
+    let (sk, pk) = HybridEncryption::<NeptuneChip<BlsFr>>::keygen(rng);
+    let pke = HybridEncryption::new(Scheme::Neptune(neptune()), neptune());
+    let message = random(2);
+    let (ciphertext, randomness) = pke.encrypt(rng, &pk, &message).unwrap();
+    assert_eq!(pke.decrypt(sk, &ciphertext).unwrap(), message);
+
+    let circuit = HybridEncryptionCircuit {
+        pke,
+        recipient: pk,
+        ciphertext,
+        message,
+        randomness,
+    };
+    let public = circuit.public_inputs();
+    assert_eq!(public.len(), 4 + 3);
+
+    type GrothSetup = Groth16<Bls381>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+    let mut invalid = public;
+    invalid[6] += BlsFr::one();
+    assert!(!GrothSetup::verify(&vk, &invalid, &proof).unwrap());
+}
@@ -0,0 +1,348 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Predicate proofs over encrypted data
+//!
+//! `PredicateBuilder` collects encrypted messages and predicates on their blocks, then builds a
+//! circuit proving the encryptions and the predicates, without revealing the messages or the keys:
+//! - `range`: a block lies in [0, 2^bits), by decomposing it in bits,
+//! - `equal`: a block equals a public value,
+//! - `disclose`: a block is revealed as a public input,
+//! - `same`: two blocks, e.g. of messages under different keys, are equal.
+//!
+//! The public inputs are the ciphertext (followed by the tag) and the nonce of each message, in the
+//! order they were added, then the values of the `equal` and `disclose` predicates, in the order
+//! they were added. `PredicateCircuit::public_inputs` lists them.
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, Boolean, EqGadget},
+    R1CSVar,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::{
+    aead,
+    api::{constants, Sponge},
+    ciminion::chip::CiminionChip,
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+};
+
+pub mod tests;
+
+/// A block of a message: the index of the message in the builder, then of the block
+pub type Block = (usize, usize);
+
+/// An authenticated encryption scheme
+#[derive(Clone)]
+pub enum Scheme<F: PrimeField> {
+    Griffin(Sponge<GriffinChip<F>>),
+    Neptune(Sponge<NeptuneChip<F>>),
+    Ciminion(CiminionChip<F>),
+}
+
+impl<F: PrimeField> Scheme<F> {
+    /// The number of elements of a key, the two master keys for Ciminion
+    pub fn key_len(&self) -> usize {
+        match self {
+            Self::Ciminion(_) => 2,
+            _ => 1,
+        }
+    }
+
+    /// Encrypts a message, it works both natively (on constants) and in a circuit
+    pub(crate) fn encrypt(
+        &self,
+        key: &[FpVar<F>],
+        nonce: &FpVar<F>,
+        message: &[FpVar<F>],
+    ) -> Result<Vec<FpVar<F>>, SynthesisError> {
+        match self {
+            Self::Griffin(sponge) => aead::encrypt(sponge, &key[0], nonce, &[], message),
+            Self::Neptune(sponge) => aead::encrypt(sponge, &key[0], nonce, &[], message),
+            Self::Ciminion(chip) => {
+                let mut chip = chip.clone();
+                chip.init(key[0].clone(), key[1].clone(), message.len());
+                chip.ae(message, nonce.clone())
+            }
+        }
+    }
+
+    /// Encrypts natively, the ciphertext is followed by the tag
+    pub fn encrypt_native(
+        &self,
+        key: &[F],
+        nonce: F,
+        message: &[F],
+    ) -> Result<Vec<F>, anyhow::Error> {
+        self.check_key(key)?;
+        let ciphertext = self.encrypt(
+            &constants(key),
+            &FpVar::Constant(nonce),
+            &constants(message),
+        )?;
+
+        Ok(ciphertext.value()?)
+    }
+
+    /// Decrypts natively a ciphertext followed by its tag, fails if the tag is invalid
+    pub fn decrypt_native(
+        &self,
+        key: &[F],
+        nonce: F,
+        ciphertext: &[F],
+    ) -> Result<Vec<F>, anyhow::Error> {
+        self.check_key(key)?;
+        match self {
+            Self::Griffin(sponge) => aead::decrypt_native(sponge, key[0], nonce, &[], ciphertext),
+            Self::Neptune(sponge) => aead::decrypt_native(sponge, key[0], nonce, &[], ciphertext),
+            Self::Ciminion(chip) => {
+                let mut chip = chip.clone();
+                let len = ciphertext.len().saturating_sub(1);
+                chip.init(FpVar::Constant(key[0]), FpVar::Constant(key[1]), len);
+
+                let (message, is_valid) =
+                    chip.ad(&constants(ciphertext), FpVar::Constant(nonce), &[])?;
+                if !is_valid.value()? {
+                    return Err(anyhow::Error::msg("Invalid tag"));
+                }
+                Ok(message.value()?)
+            }
+        }
+    }
+
+    fn check_key(&self, key: &[F]) -> Result<(), anyhow::Error> {
+        if key.len() != self.key_len() {
+            return Err(anyhow::Error::msg(format!(
+                "The key has {} elements, the scheme expects {}",
+                key.len(),
+                self.key_len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// A message with its encryption
+#[derive(Clone)]
+pub struct EncryptedMessage<F: PrimeField> {
+    pub scheme: Scheme<F>,
+    pub message: Vec<F>,
+    pub key: Vec<F>,
+    pub nonce: F,
+    pub ciphertext: Vec<F>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Predicate<F: PrimeField> {
+    Range { block: Block, bits: usize },
+    Equal { block: Block, value: F },
+    Disclose { block: Block, value: F },
+    Same { left: Block, right: Block },
+}
+
+#[derive(Clone)]
+pub struct PredicateBuilder<F: PrimeField> {
+    messages: Vec<EncryptedMessage<F>>,
+    predicates: Vec<Predicate<F>>,
+}
+
+impl<F: PrimeField> Default for PredicateBuilder<F> {
+    fn default() -> Self {
+        Self {
+            messages: vec![],
+            predicates: vec![],
+        }
+    }
+}
+
+impl<F: PrimeField> PredicateBuilder<F> {
+    pub fn new() -> Self {
+        Default::default()
+    }
+
+    /// Encrypts a message natively and adds it, returns its index
+    pub fn encrypt(
+        &mut self,
+        scheme: Scheme<F>,
+        message: Vec<F>,
+        key: Vec<F>,
+        nonce: F,
+    ) -> Result<usize, anyhow::Error> {
+        let ciphertext = scheme.encrypt_native(&key, nonce, &message)?;
+
+        self.messages.push(EncryptedMessage {
+            scheme,
+            message,
+            key,
+            nonce,
+            ciphertext,
+        });
+        Ok(self.messages.len() - 1)
+    }
+
+    /// Proves that a block lies in [0, 2^bits)
+    pub fn range(&mut self, block: Block, bits: usize) -> Result<&mut Self, anyhow::Error> {
+        if bits >= F::size_in_bits() {
+            return Err(anyhow::Error::msg(format!(
+                "A range check can't use {} bits, the field has {}",
+                bits,
+                F::size_in_bits()
+            )));
+        }
+        let value = self.block(block)?.into_repr();
+        if (bits..F::size_in_bits()).any(|i| value.get_bit(i)) {
+            return Err(anyhow::Error::msg(format!(
+                "The block {:?} doesn't fit in {} bits",
+                block, bits
+            )));
+        }
+
+        self.push(Predicate::Range { block, bits })
+    }
+
+    /// Proves that a block equals a public value
+    pub fn equal(&mut self, block: Block, value: F) -> Result<&mut Self, anyhow::Error> {
+        if self.block(block)? != value {
+            return Err(anyhow::Error::msg(format!(
+                "The block {:?} doesn't equal the value",
+                block
+            )));
+        }
+
+        self.push(Predicate::Equal { block, value })
+    }
+
+    /// Reveals a block as a public input
+    pub fn disclose(&mut self, block: Block) -> Result<&mut Self, anyhow::Error> {
+        let value = self.block(block)?;
+
+        self.push(Predicate::Disclose { block, value })
+    }
+
+    /// Proves that two blocks are equal, without revealing them
+    pub fn same(&mut self, left: Block, right: Block) -> Result<&mut Self, anyhow::Error> {
+        if self.block(left)? != self.block(right)? {
+            return Err(anyhow::Error::msg(format!(
+                "The blocks {:?} and {:?} differ",
+                left, right
+            )));
+        }
+
+        self.push(Predicate::Same { left, right })
+    }
+
+    pub fn build(self) -> PredicateCircuit<F> {
+        PredicateCircuit {
+            messages: self.messages,
+            predicates: self.predicates,
+        }
+    }
+
+    fn block(&self, (message, block): Block) -> Result<F, anyhow::Error> {
+        self.messages
+            .get(message)
+            .and_then(|m| m.message.get(block))
+            .copied()
+            .ok_or_else(|| anyhow::Error::msg(format!("There is no block {:?}", (message, block))))
+    }
+
+    fn push(&mut self, predicate: Predicate<F>) -> Result<&mut Self, anyhow::Error> {
+        self.predicates.push(predicate);
+        Ok(self)
+    }
+}
+
+/// Proves the encryption of messages and predicates on their blocks, see `PredicateBuilder`
+#[derive(Clone)]
+pub struct PredicateCircuit<F: PrimeField> {
+    pub messages: Vec<EncryptedMessage<F>>,
+    pub predicates: Vec<Predicate<F>>,
+}
+
+impl<F: PrimeField> PredicateCircuit<F> {
+    /// The public inputs, in the order of the circuit
+    pub fn public_inputs(&self) -> Vec<F> {
+        let mut inputs = vec![];
+
+        for m in self.messages.iter() {
+            inputs.extend_from_slice(&m.ciphertext);
+            inputs.push(m.nonce);
+        }
+        for predicate in self.predicates.iter() {
+            match predicate {
+                Predicate::Equal { value, .. } | Predicate::Disclose { value, .. } => {
+                    inputs.push(*value)
+                }
+                _ => {}
+            }
+        }
+
+        inputs
+    }
+}
+
+impl<F: PrimeField> ConstraintSynthesizer<F> for PredicateCircuit<F> {
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let mut messages = Vec::with_capacity(self.messages.len());
+
+        for m in self.messages {
+            let message = Vec::new_witness(cs.clone(), || Ok(m.message))?;
+            let key = Vec::new_witness(cs.clone(), || Ok(m.key))?;
+            let ct = Vec::new_input(cs.clone(), || Ok(m.ciphertext))?;
+            let nonce = FpVar::new_input(cs.clone(), || Ok(m.nonce))?;
+
+            m.scheme
+                .encrypt(&key, &nonce, &message)?
+                .enforce_equal(&ct)?;
+            messages.push(message);
+        }
+
+        let block = |(message, block): Block| {
+            messages
+                .get(message)
+                .and_then(|m| m.get(block))
+                .ok_or(SynthesisError::Unsatisfiable)
+        };
+
+        for predicate in self.predicates {
+            match predicate {
+                Predicate::Range { block: b, bits } => {
+                    let b = block(b)?;
+                    let mut decomposition = Vec::with_capacity(bits);
+                    for i in 0..bits {
+                        decomposition.push(Boolean::new_witness(cs.clone(), || {
+                            Ok(b.value()?.into_repr().get_bit(i))
+                        })?);
+                    }
+                    Boolean::le_bits_to_fp_var(&decomposition)?.enforce_equal(b)?;
+                }
+                Predicate::Equal { block: b, value } | Predicate::Disclose { block: b, value } => {
+                    let value = FpVar::new_input(cs.clone(), || Ok(value))?;
+                    block(b)?.enforce_equal(&value)?;
+                }
+                Predicate::Same { left, right } => {
+                    block(left)?.enforce_equal(block(right)?)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
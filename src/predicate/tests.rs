@@ -0,0 +1,172 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_snark::SNARK;
+
+use super::{Predicate, PredicateBuilder, Scheme};
+use crate::{
+    api::Sponge,
+    ciminion::chip::CiminionChip,
+    common::test_utils::is_satisfied,
+    griffin::{self, chip::GriffinChip},
+    neptune::{self, chip::NeptuneChip},
+};
+
+fn griffin<F: PrimeField>() -> Scheme<F> {
+    Scheme::Griffin(Sponge::<GriffinChip<F>>::new(
+        GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap(),
+    ))
+}
+
+fn neptune<F: PrimeField>() -> Scheme<F> {
+    Scheme::Neptune(Sponge::<NeptuneChip<F>>::new(
+        NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap(),
+    ))
+}
+
+fn ciminion<F: PrimeField>() -> Scheme<F> {
+    Scheme::Ciminion(
+        CiminionChip::try_new(crate::ciminion::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+/// Two records under different keys and schemes, sharing their first block
+fn records<F: PrimeField>(builder: &mut PredicateBuilder<F>) -> (usize, usize) {
+    let rng = &mut ark_std::test_rng();
+    let id = F::rand(rng);
+    let first = vec![id, F::from(42u64), F::rand(rng), F::from(u32::MAX as u64)];
+    let second = vec![id, F::rand(rng)];
+
+    let a = builder
+        .encrypt(neptune(), first, vec![F::rand(rng)], F::rand(rng))
+        .unwrap();
+    let b = builder
+        .encrypt(
+            ciminion(),
+            second,
+            vec![F::rand(rng), F::rand(rng)],
+            F::rand(rng),
+        )
+        .unwrap();
+    (a, b)
+}
+
+#[test]
+fn builder_checks() {
+    let mut builder = PredicateBuilder::<BnFr>::new();
+    let (a, b) = records(&mut builder);
+
+    // The predicates are checked natively
+    assert!(builder.range((a, 3), 32).is_ok());
+    assert!(builder.range((a, 3), 31).is_err());
+    assert!(builder.range((a, 2), 32).is_err());
+    assert!(builder.range((a, 1), BnFr::size_in_bits()).is_err());
+    assert!(builder.equal((a, 1), BnFr::from(42u64)).is_ok());
+    assert!(builder.equal((a, 1), BnFr::from(43u64)).is_err());
+    assert!(builder.same((a, 0), (b, 0)).is_ok());
+    assert!(builder.same((a, 1), (b, 1)).is_err());
+    assert!(builder.disclose((b, 2)).is_err());
+    assert!(builder.disclose((2, 0)).is_err());
+    assert!(builder
+        .encrypt(ciminion(), vec![], vec![BnFr::from(1u64)], BnFr::from(1u64))
+        .is_err());
+
+    let circuit = builder.build();
+    assert_eq!(circuit.predicates.len(), 3);
+    assert!(is_satisfied(circuit));
+}
+
+#[test]
+fn predicate_circuit() {
+    let mut builder = PredicateBuilder::<BnFr>::new();
+    let (a, b) = records(&mut builder);
+    builder
+        .range((a, 3), 32)
+        .unwrap()
+        .equal((a, 1), BnFr::from(42u64))
+        .unwrap()
+        .same((a, 0), (b, 0))
+        .unwrap()
+        .disclose((b, 1))
+        .unwrap();
+    let circuit = builder.build();
+    assert!(is_satisfied(circuit.clone()));
+
+    // The layout: both ciphertexts and nonces, then the value and the disclosed block
+    let public = circuit.public_inputs();
+    assert_eq!(public.len(), (5 + 1) + (3 + 1) + 2);
+    assert_eq!(public[10], BnFr::from(42u64));
+    assert_eq!(public[11], circuit.messages[b].message[1]);
+
+    // A witness breaking any predicate doesn't satisfy the circuit
+    let mut invalid = circuit.clone();
+    invalid.predicates[0] = Predicate::Range {
+        block: (a, 3),
+        bits: 31,
+    };
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit.clone();
+    invalid.predicates[1] = Predicate::Equal {
+        block: (a, 1),
+        value: BnFr::from(43u64),
+    };
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit.clone();
+    invalid.predicates[2] = Predicate::Same {
+        left: (a, 0),
+        right: (b, 1),
+    };
+    assert!(!is_satisfied(invalid));
+
+    let mut invalid = circuit;
+    invalid.messages[b].message[1] += BnFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_predicate_bls() {
+    let rng = &mut ark_std::test_rng();
+    let mut builder = PredicateBuilder::<BlsFr>::new();
+    let a = builder
+        .encrypt(
+            griffin(),
+            vec![BlsFr::from(1000u64), BlsFr::rand(rng)],
+            vec![BlsFr::rand(rng)],
+            BlsFr::rand(rng),
+        )
+        .unwrap();
+    builder.range((a, 0), 16).unwrap().disclose((a, 1)).unwrap();
+    let circuit = builder.build();
+    let public = circuit.public_inputs();
+
+    type GrothSetup = Groth16<Bls381>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+    let mut invalid = public;
+    invalid[4] += BlsFr::from(1u64);
+    assert!(!GrothSetup::verify(&vk, &invalid, &proof).unwrap());
+}
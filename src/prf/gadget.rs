@@ -0,0 +1,75 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::marker::PhantomData;
+
+use ark_crypto_primitives::prf::PRFGadget;
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{fields::fp::FpVar, uint8::UInt8};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+
+use super::{prf, SpongePRF};
+use crate::{
+    api::ChipAPI,
+    crh::{gadget::read_element, BundledSponge},
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+/// The gadget of `SpongePRF`, the bytes of the seed and of the input are read as field elements
+pub struct SpongePRFGadget<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> {
+    _field: PhantomData<F>,
+    _chip: PhantomData<C>,
+}
+
+pub type GriffinPRFGadget<F> = SpongePRFGadget<F, GriffinChip<F>>;
+pub type NeptunePRFGadget<F> = SpongePRFGadget<F, NeptuneChip<F>>;
+pub type RescuePrimePRFGadget<F> = SpongePRFGadget<F, RescuePrimeChip<F>>;
+
+impl<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> SpongePRFGadget<F, C> {
+    /// Allocates the bytes of the seed as witnesses, returning the error of the allocation
+    pub fn try_new_seed(
+        cs: impl Into<Namespace<F>>,
+        seed: &F,
+    ) -> Result<Vec<UInt8<F>>, SynthesisError> {
+        UInt8::new_witness_vec(cs.into(), &seed.into_repr().to_bytes_le())
+    }
+}
+
+impl<F, C> PRFGadget<SpongePRF<F, C>, F> for SpongePRFGadget<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type OutputVar = FpVar<F>;
+
+    /// The trait doesn't let the allocation fail: on an error, the seed is empty and `evaluate`
+    /// rejects it (`try_new_seed` returns the error itself)
+    fn new_seed(cs: impl Into<Namespace<F>>, seed: &F) -> Vec<UInt8<F>> {
+        Self::try_new_seed(cs, seed).unwrap_or_default()
+    }
+
+    fn evaluate(seed: &[UInt8<F>], input: &[UInt8<F>]) -> Result<Self::OutputVar, SynthesisError> {
+        if seed.is_empty() {
+            return Err(SynthesisError::AssignmentMissing);
+        }
+        let sponge = C::bundled_sponge().map_err(|_| SynthesisError::AssignmentMissing)?;
+
+        prf(&sponge, &read_element(seed)?, &read_element(input)?)
+    }
+}
@@ -0,0 +1,168 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! A PRF and a KDF over the SAFE sponges
+//!
+//! `prf(key, input)` absorbs the key and the input and squeezes one element, `derive(key, context,
+//! n)` absorbs the key and the context and squeezes `n` elements. Their IOPatterns are fixed by the
+//! lengths (so outputs of different lengths are independent, not prefixes of each other) and each
+//! has its own domain separator. They work both natively (on constants) and in a circuit.
+//!
+//! `SpongePRF` implements the `PRF` trait of ark-crypto-primitives with the bundled parameters,
+//! the seed, the input and the output being field elements.
+
+use std::marker::PhantomData;
+
+use ark_crypto_primitives::{prf::PRF, CryptoError};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{
+    api::{constants, ChipAPI, Sponge, SpongeAPI},
+    common::{
+        domain,
+        pattern::{IOPattern, SpongeOp},
+    },
+    crh::BundledSponge,
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+pub mod gadget;
+pub mod tests;
+
+/// Evaluates the PRF keyed by `key` on `input`
+///
+/// As for `merkle::tree::hash_pair`, the sponge should be freshly created (i.e. never started).
+pub fn prf<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    input: &FpVar<F>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let mut sponge = sponge.clone();
+    let pattern = IOPattern::new(vec![SpongeOp::Absorb(2), SpongeOp::Squeeze(1)]);
+
+    sponge.start(pattern, Some(domain::PRF));
+    sponge.absorb(2, &[key.clone(), input.clone()]);
+    let output = sponge.squeeze(1)[0].clone();
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(output)
+}
+
+/// Derives `n` elements from a key and a context, e.g. a label and a counter
+pub fn derive<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    context: &[FpVar<F>],
+    n: usize,
+) -> Result<Vec<FpVar<F>>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    if n == 0 {
+        return Ok(vec![]);
+    }
+
+    let input = [std::slice::from_ref(key), context].concat();
+    let mut sponge = sponge.clone();
+    let pattern = IOPattern::new(vec![
+        SpongeOp::Absorb(input.len() as u32),
+        SpongeOp::Squeeze(n as u32),
+    ]);
+
+    sponge.start(pattern, Some(domain::KDF));
+    sponge.absorb(input.len() as u32, &input);
+    let output = sponge.squeeze(n as u32);
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(output)
+}
+
+/// Evaluates the PRF natively
+pub fn prf_native<F, C>(sponge: &Sponge<C>, key: F, input: F) -> Result<F, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    Ok(prf(sponge, &FpVar::Constant(key), &FpVar::Constant(input))?.value()?)
+}
+
+/// Derives `n` elements natively
+pub fn derive_native<F, C>(
+    sponge: &Sponge<C>,
+    key: F,
+    context: &[F],
+    n: usize,
+) -> Result<Vec<F>, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    Ok(derive(sponge, &FpVar::Constant(key), &constants(context), n)?.value()?)
+}
+
+/// The PRF over a SAFE sponge with the bundled parameters
+pub struct SpongePRF<F: PrimeField, C: ChipAPI<Value = FpVar<F>>> {
+    _field: PhantomData<F>,
+    _chip: PhantomData<C>,
+}
+
+pub type GriffinPRF<F> = SpongePRF<F, GriffinChip<F>>;
+pub type NeptunePRF<F> = SpongePRF<F, NeptuneChip<F>>;
+pub type RescuePrimePRF<F> = SpongePRF<F, RescuePrimeChip<F>>;
+
+impl<F, C> SpongePRF<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    /// Evaluates the PRF with the bundled parameters, keeping the cause of a failure
+    pub fn try_evaluate(seed: F, input: F) -> Result<F, anyhow::Error> {
+        let sponge = C::bundled_sponge()?;
+
+        prf_native(&sponge, seed, input)
+    }
+}
+
+impl<F, C> PRF for SpongePRF<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    type Input = F;
+    type Output = F;
+    type Seed = F;
+
+    /// Fails if there are no bundled parameters for the field
+    ///
+    /// `CryptoError` has no variant for that, so the error is an input of length 0: the cause is
+    /// logged through the `log` crate, and `try_evaluate` returns it.
+    fn evaluate(seed: &Self::Seed, input: &Self::Input) -> Result<Self::Output, CryptoError> {
+        Self::try_evaluate(*seed, *input).map_err(|e| {
+            log::error!("{}", e);
+            CryptoError::IncorrectInputLength(0)
+        })
+    }
+}
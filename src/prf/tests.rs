@@ -0,0 +1,170 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::Fr as BlsFr;
+use ark_bn254::Fr as BnFr;
+use ark_crypto_primitives::prf::{PRFGadget, PRF};
+use ark_ff::{PrimeField, ToBytes, UniformRand};
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, R1CSVar, ToBytesGadget};
+use ark_relations::r1cs::ConstraintSystem;
+
+use super::{
+    derive, derive_native,
+    gadget::{NeptunePRFGadget, SpongePRFGadget},
+    prf, prf_native, GriffinPRF, NeptunePRF, RescuePrimePRF,
+};
+use crate::{
+    api::Sponge,
+    crh::{self, BundledSponge},
+    fields::goldilocks::Goldilocks,
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+    rescue_prime::chip::RescuePrimeChip,
+};
+
+/// Checks that the gadgets agree with the native functions and that the outputs are separated
+fn native_and_gadget<F, C>()
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>> + Clone,
+{
+    let rng = &mut ark_std::test_rng();
+    let sponge: Sponge<C> = C::bundled_sponge().unwrap();
+    let (key, input) = (F::rand(rng), F::rand(rng));
+    let context: Vec<F> = (0..3).map(|_| F::rand(rng)).collect();
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    let key_var = FpVar::new_witness(cs.clone(), || Ok(key)).unwrap();
+    let input_var = FpVar::new_input(cs.clone(), || Ok(input)).unwrap();
+    let context_var = Vec::new_input(cs.clone(), || Ok(context.clone())).unwrap();
+
+    let output = prf_native(&sponge, key, input).unwrap();
+    assert_eq!(
+        prf(&sponge, &key_var, &input_var).unwrap().value().unwrap(),
+        output
+    );
+    let keys = derive_native(&sponge, key, &context, 4).unwrap();
+    assert_eq!(keys.len(), 4);
+    assert_eq!(
+        derive(&sponge, &key_var, &context_var, 4)
+            .unwrap()
+            .value()
+            .unwrap(),
+        keys
+    );
+    assert!(cs.is_satisfied().unwrap());
+
+    // The domain separators make the PRF differ from the KDF and from the hash of the same inputs
+    assert_ne!(derive_native(&sponge, key, &[input], 1).unwrap()[0], output);
+    let hash = crh::hash(&sponge, &[FpVar::Constant(key), FpVar::Constant(input)]).unwrap();
+    assert_ne!(hash.value().unwrap(), output);
+
+    // The number of outputs is part of the pattern, so a shorter derivation isn't a prefix
+    let fewer = derive_native(&sponge, key, &context, 3).unwrap();
+    assert_eq!(fewer.len(), 3);
+    assert_ne!(fewer[..], keys[..3]);
+    assert_ne!(derive_native(&sponge, key, &context[..2], 4).unwrap(), keys);
+    assert_ne!(derive_native(&sponge, input, &context, 4).unwrap(), keys);
+    assert!(derive_native(&sponge, key, &context, 0).unwrap().is_empty());
+}
+
+#[test]
+fn native_and_gadget_griffin() {
+    native_and_gadget::<BlsFr, GriffinChip<BlsFr>>();
+    native_and_gadget::<BnFr, GriffinChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_neptune() {
+    native_and_gadget::<BlsFr, NeptuneChip<BlsFr>>();
+    native_and_gadget::<BnFr, NeptuneChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_rescue_prime() {
+    native_and_gadget::<BlsFr, RescuePrimeChip<BlsFr>>();
+}
+
+#[test]
+fn prf_trait() {
+    let rng = &mut ark_std::test_rng();
+    let (seed, input) = (BlsFr::rand(rng), BlsFr::rand(rng));
+
+    let griffin = GriffinChip::<BlsFr>::bundled_sponge().unwrap();
+    let neptune = NeptuneChip::<BlsFr>::bundled_sponge().unwrap();
+    let rescue_prime = RescuePrimeChip::<BlsFr>::bundled_sponge().unwrap();
+    assert_eq!(
+        GriffinPRF::evaluate(&seed, &input).unwrap(),
+        prf_native(&griffin, seed, input).unwrap()
+    );
+    assert_eq!(
+        RescuePrimePRF::evaluate(&seed, &input).unwrap(),
+        prf_native(&rescue_prime, seed, input).unwrap()
+    );
+    let output = NeptunePRF::evaluate(&seed, &input).unwrap();
+    assert_eq!(NeptunePRF::try_evaluate(seed, input).unwrap(), output);
+    assert_eq!(output, prf_native(&neptune, seed, input).unwrap());
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let seed_var = NeptunePRFGadget::new_seed(cs.clone(), &seed);
+    let input_var = FpVar::new_input(cs.clone(), || Ok(input)).unwrap();
+    let output_var = <SpongePRFGadget<_, _> as PRFGadget<NeptunePRF<BlsFr>, _>>::evaluate(
+        &seed_var,
+        &input_var.to_bytes().unwrap(),
+    )
+    .unwrap();
+    assert_eq!(output_var.value().unwrap(), output);
+    assert!(cs.is_satisfied().unwrap());
+
+    // The seed bytes must be a field element
+    let mut bytes = vec![];
+    seed.write(&mut bytes).unwrap();
+    assert_eq!(seed_var.len(), bytes.len());
+    let too_long = [seed_var.clone(), seed_var].concat();
+    assert!(
+        <SpongePRFGadget<_, _> as PRFGadget<NeptunePRF<BlsFr>, _>>::evaluate(
+            &too_long,
+            &input_var.to_bytes().unwrap(),
+        )
+        .is_err()
+    );
+
+    // An empty seed, given by `new_seed` when the allocation fails, is rejected
+    assert!(
+        <SpongePRFGadget<_, _> as PRFGadget<NeptunePRF<BlsFr>, _>>::evaluate(
+            &[],
+            &input_var.to_bytes().unwrap(),
+        )
+        .is_err()
+    );
+}
+
+#[test]
+fn errors() {
+    // There are no Griffin parameters on Goldilocks
+    assert!(
+        GriffinPRF::<Goldilocks>::evaluate(&Goldilocks::from(1u64), &Goldilocks::from(2u64))
+            .is_err()
+    );
+    // The cause isn't lost
+    let error =
+        GriffinPRF::<Goldilocks>::try_evaluate(Goldilocks::from(1u64), Goldilocks::from(2u64))
+            .unwrap_err();
+    assert!(error.to_string().starts_with("No parameters available"));
+}
@@ -16,106 +16,182 @@
 */
 
 use crate::{
-    api::{ChipAPI, Sponge},
-    common::pattern::IOPattern,
+    api::{permute_native, ChipAPI, Permutation, Sponge},
+    common::{
+        params_file::ParameterFile,
+        rounds::{rescue_prime_rounds, SECURITY_LEVEL},
+        validation::*,
+    },
 };
 use ark_ff::PrimeField;
 use ark_r1cs_std::{fields::fp::FpVar, prelude::FieldVar};
 
-use super::params::{M, N, R};
+use super::params::R;
 
-// All parameters are generic in this one
+// All parameters are generic in this one, including the width of the state
 #[derive(Clone)]
 pub struct RescuePrimeParameters<F: PrimeField> {
+    pub width: usize,
+    pub nb_rounds: usize,
     pub round_constants: Vec<FpVar<F>>,
     pub mds: Vec<FpVar<F>>,
-    pub alpha_inv: [u64; 4],
-    pub alpha: [u64; 4],
+    pub alpha_inv: Vec<u64>,
+    pub alpha: Vec<u64>,
+}
+
+impl<F: PrimeField> RescuePrimeParameters<F> {
+    /// Loads the parameters from a parameter file over F
+    ///
+    /// It expects the `rounds` number of rounds and the `alpha`, `alpha_inv`, `mds`
+    /// (row by row) and `round_constants` constants. The width is the one of the file,
+    /// e.g. small fields such as Goldilocks need a wider state for the same capacity.
+    pub fn from_file(file: &ParameterFile) -> Result<Self, anyhow::Error> {
+        file.check_field::<F>("rescue_prime")?;
+
+        Ok(Self {
+            width: file.width,
+            nb_rounds: file.rounds("rounds")?,
+            round_constants: file.constants("round_constants")?,
+            mds: file.constants("mds")?,
+            alpha_inv: file.exponent("alpha_inv")?,
+            alpha: file.exponent("alpha")?,
+        })
+    }
+
+    /// Checks that the parameters define a valid Rescue Prime permutation
+    pub fn validate(&self) -> Result<(), anyhow::Error> {
+        // The sponge needs the rate and at least one element of capacity
+        if self.width <= R {
+            return Err(anyhow::Error::msg(format!(
+                "The width must be larger than the rate ({})",
+                R
+            )));
+        }
+        check_count(
+            "round constants",
+            self.round_constants.len(),
+            2 * self.width * self.nb_rounds,
+        )?;
+        check_count("MDS elements", self.mds.len(), self.width * self.width)?;
+        check_exponent::<F>(&self.alpha)?;
+        check_exponent_inverse::<F>(&self.alpha, &self.alpha_inv)?;
+
+        let mds = self.mds.iter().map(value).collect::<Result<Vec<_>, _>>()?;
+        check_mds(&mds, self.width)
+    }
+
+    /// Checks that the number of rounds reaches the recommended one for a security level (in bits)
+    pub fn check_rounds(&self, security_level: usize) -> Result<(), anyhow::Error> {
+        let alpha = small_exponent("alpha", &self.alpha)?;
+        let recommended = rescue_prime_rounds(self.width, self.width - R, alpha, security_level)?;
+        if self.nb_rounds < recommended {
+            return Err(anyhow::Error::msg(format!(
+                "Rescue Prime uses {} rounds, {} are recommended for {} bits of security",
+                self.nb_rounds, recommended, security_level
+            )));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Clone)]
 pub struct RescuePrimeChip<F: PrimeField> {
     parameters: RescuePrimeParameters<F>,
-    state: [FpVar<F>; M],
 }
 
 impl<F: PrimeField> RescuePrimeChip<F> {
+    /// Creates a chip, panics if the parameters are invalid
+    ///
+    /// Parameters with fewer rounds than recommended are accepted, with a warning logged through
+    /// the `log` crate
     pub fn new(parameters: RescuePrimeParameters<F>) -> Self {
-        Self {
-            parameters,
-            state: [FpVar::zero(), FpVar::zero(), FpVar::zero()],
+        if let Err(e) = parameters.validate() {
+            panic!("Invalid Rescue Prime parameters: {}", e);
+        }
+        if let Err(e) = parameters.check_rounds(SECURITY_LEVEL) {
+            log::warn!("{}", e);
         }
+
+        Self { parameters }
     }
-}
 
-// Implement the chip
-impl<F: PrimeField> ChipAPI for RescuePrimeChip<F> {
-    type Value = FpVar<F>;
+    /// Creates a chip, rejecting invalid parameters and those below 128 bits of security
+    pub fn try_new(parameters: RescuePrimeParameters<F>) -> Result<Self, anyhow::Error> {
+        parameters.validate()?;
+        parameters.check_rounds(SECURITY_LEVEL)?;
 
-    fn init(&mut self, tag: u128) -> Result<(), anyhow::Error> {
-        self.state[1] = FpVar::Constant(F::from(tag));
-        Ok(())
+        Ok(Self { parameters })
     }
+}
 
-    fn read(&mut self) -> FpVar<F> {
-        self.state[0].clone()
+impl<F: PrimeField> Permutation<FpVar<F>> for RescuePrimeChip<F> {
+    fn width(&self) -> usize {
+        self.parameters.width
     }
 
-    fn add(&mut self, val: &FpVar<F>) -> Result<(), anyhow::Error> {
-        self.state[0] += val;
-        Ok(())
-    }
+    fn permute(&self, state: &mut [FpVar<F>]) -> Result<(), anyhow::Error> {
+        let m = self.parameters.width;
+        check_count("state elements", state.len(), m)?;
 
-    fn permutation(&mut self) -> Result<(), anyhow::Error> {
-        for i in 0..N {
-            for j in 0..M {
-                self.state[j] = self.state[j].pow_by_constant(self.parameters.alpha)?;
+        for i in 0..self.parameters.nb_rounds {
+            for item in state.iter_mut() {
+                *item = item.pow_by_constant(&self.parameters.alpha)?;
             }
+            linear_layer(
+                state,
+                &self.parameters.mds,
+                &self.parameters.round_constants[2 * m * i..(2 * i + 1) * m],
+            );
 
-            self.state[0] = self.state[0].clone() * self.parameters.mds[0].clone()
-                + self.state[1].clone() * self.parameters.mds[1].clone()
-                + self.state[2].clone() * self.parameters.mds[2].clone()
-                + self.parameters.round_constants[6 * i].clone();
-            self.state[1] = self.state[0].clone() * self.parameters.mds[3].clone()
-                + self.state[1].clone() * self.parameters.mds[4].clone()
-                + self.state[2].clone() * self.parameters.mds[5].clone()
-                + self.parameters.round_constants[6 * i + 1].clone();
-            self.state[2] = self.state[0].clone() * self.parameters.mds[6].clone()
-                + self.state[1].clone() * self.parameters.mds[7].clone()
-                + self.state[2].clone() * self.parameters.mds[8].clone()
-                + self.parameters.round_constants[6 * i + 2].clone();
-
-            for j in 0..M {
-                self.state[j] = self.state[j].pow_by_constant(self.parameters.alpha_inv)?;
+            for item in state.iter_mut() {
+                *item = item.pow_by_constant(&self.parameters.alpha_inv)?;
             }
-
-            self.state[0] = self.state[0].clone() * self.parameters.mds[0].clone()
-                + self.state[1].clone() * self.parameters.mds[1].clone()
-                + self.state[2].clone() * self.parameters.mds[2].clone()
-                + self.parameters.round_constants[6 * i + 3].clone();
-            self.state[1] = self.state[0].clone() * self.parameters.mds[3].clone()
-                + self.state[1].clone() * self.parameters.mds[4].clone()
-                + self.state[2].clone() * self.parameters.mds[5].clone()
-                + self.parameters.round_constants[6 * i + 4].clone();
-            self.state[2] = self.state[0].clone() * self.parameters.mds[6].clone()
-                + self.state[1].clone() * self.parameters.mds[7].clone()
-                + self.state[2].clone() * self.parameters.mds[8].clone()
-                + self.parameters.round_constants[6 * i + 5].clone();
+            linear_layer(
+                state,
+                &self.parameters.mds,
+                &self.parameters.round_constants[(2 * i + 1) * m..2 * m * (i + 1)],
+            );
         }
 
         Ok(())
     }
 }
 
+impl<F: PrimeField> Permutation<F> for RescuePrimeChip<F> {
+    fn width(&self) -> usize {
+        self.parameters.width
+    }
+
+    fn permute(&self, state: &mut [F]) -> Result<(), anyhow::Error> {
+        permute_native(self, state)
+    }
+}
+
+impl<F: PrimeField> ChipAPI for RescuePrimeChip<F> {
+    type Value = FpVar<F>;
+
+    fn constant(x: u128) -> FpVar<F> {
+        FpVar::Constant(F::from(x))
+    }
+}
+
+/// Multiplies the state by the MDS matrix (given row by row) and adds the round constants
+pub(crate) fn linear_layer<F: PrimeField>(
+    state: &mut [FpVar<F>],
+    mds: &[FpVar<F>],
+    constants: &[FpVar<F>],
+) {
+    let m = state.len();
+    let input = state.to_vec();
+    for (j, item) in state.iter_mut().enumerate() {
+        *item = (0..m).fold(constants[j].clone(), |acc, k| {
+            acc + input[k].clone() * mds[j * m + k].clone()
+        });
+    }
+}
+
 impl<F: PrimeField> Sponge<RescuePrimeChip<F>> {
     pub fn new(chip: RescuePrimeChip<F>) -> Self {
-        Self {
-            chip,
-            absord_pos: 0,
-            squeeze_pos: 0,
-            op_count: 0,
-            pattern: IOPattern::new(vec![]),
-            rate: R,
-        }
+        Self::with_rate(chip, R)
     }
 }
@@ -51,8 +51,7 @@ impl<F: PrimeField> RescuePrimeHashCircuit<F> {
         sponge.start(pattern, None);
         sponge.absorb(message.len() as u32, message);
         let hash = sponge.squeeze(1)[0].clone();
-        let res = sponge.finish();
-        assert!(res.is_ok(), "The sponge didn't finish properly!");
+        sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
 
         Ok(hash)
     }
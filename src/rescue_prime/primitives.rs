@@ -17,74 +17,68 @@
 
 /*!
 This module contains primitives used only for testing purpose with Rescue
-They implement hashing on the BLS12_381 pairing-friendly curve.
+They implement hashing on any field with bundled parameters.
 */
 
 #![allow(unused)] // Only used within tests
 
-pub struct RescuePrimePrimitivesBlsFr {}
+use std::marker::PhantomData;
 
+use ark_ff::PrimeField;
 use ark_r1cs_std::{fields::fp::FpVar, prelude::FieldVar};
 use ark_relations::r1cs::SynthesisError;
 
 use ark_bls12_381::Fr as BlsFr;
-use ark_ff::BigInteger256 as I256;
 
-use crate::{common::pattern::*, rescue_prime::params::*};
+use crate::{
+    common::pattern::*,
+    rescue_prime::{
+        chip::{linear_layer, RescuePrimeParameters},
+        params::*,
+    },
+};
 
-impl RescuePrimePrimitivesBlsFr {
-    fn permutation(mut state: [FpVar<BlsFr>; 3]) -> Result<[FpVar<BlsFr>; 3], anyhow::Error> {
-        for i in 0..N {
-            for j in 0..M {
-                state[j] = state[j].pow_by_constant(ALPHA_BLS381)?;
-            }
+pub struct RescuePrimePrimitives<F: PrimeField> {
+    _field: PhantomData<F>,
+}
 
-            state[0] = state[0].clone() * FpVar::Constant(BlsFr::from(I256(MDS[0])))
-                + state[1].clone() * FpVar::Constant(BlsFr::from(I256(MDS[1]))).clone()
-                + state[2].clone() * FpVar::Constant(BlsFr::from(I256(MDS[2]))).clone()
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[6 * i])));
-            state[1] = state[0].clone() * FpVar::Constant(BlsFr::from(I256(MDS[3]))).clone()
-                + state[1].clone() * FpVar::Constant(BlsFr::from(I256(MDS[4])))
-                + state[2].clone() * FpVar::Constant(BlsFr::from(I256(MDS[5])))
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[6 * i + 1])));
-            state[2] = state[0].clone() * FpVar::Constant(BlsFr::from(I256(MDS[6])))
-                + state[1].clone() * FpVar::Constant(BlsFr::from(I256(MDS[7])))
-                + state[2].clone() * FpVar::Constant(BlsFr::from(I256(MDS[8])))
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[6 * i + 2])));
+pub type RescuePrimePrimitivesBlsFr = RescuePrimePrimitives<BlsFr>;
 
-            for j in 0..M {
-                state[j] = state[j].pow_by_constant(ALPHAINV_BLS381)?;
+impl<F: PrimeField> RescuePrimePrimitives<F> {
+    fn permutation(
+        parameters: &RescuePrimeParameters<F>,
+        state: &mut [FpVar<F>],
+    ) -> Result<(), anyhow::Error> {
+        let m = parameters.width;
+        let rc = &parameters.round_constants;
+
+        for i in 0..parameters.nb_rounds {
+            for item in state.iter_mut() {
+                *item = item.pow_by_constant(&parameters.alpha)?;
             }
+            linear_layer(state, &parameters.mds, &rc[2 * m * i..(2 * i + 1) * m]);
 
-            state[0] = state[0].clone() * FpVar::Constant(BlsFr::from(I256(MDS[0])))
-                + state[1].clone() * FpVar::Constant(BlsFr::from(I256(MDS[1]))).clone()
-                + state[2].clone() * FpVar::Constant(BlsFr::from(I256(MDS[2]))).clone()
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[6 * i + 3])));
-            state[1] = state[0].clone() * FpVar::Constant(BlsFr::from(I256(MDS[3]))).clone()
-                + state[1].clone() * FpVar::Constant(BlsFr::from(I256(MDS[4])))
-                + state[2].clone() * FpVar::Constant(BlsFr::from(I256(MDS[5])))
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[6 * i + 4])));
-            state[2] = state[0].clone() * FpVar::Constant(BlsFr::from(I256(MDS[6])))
-                + state[1].clone() * FpVar::Constant(BlsFr::from(I256(MDS[7])))
-                + state[2].clone() * FpVar::Constant(BlsFr::from(I256(MDS[8])))
-                + FpVar::Constant(BlsFr::from(I256(ROUND_CONSTANTS[6 * i + 5])));
+            for item in state.iter_mut() {
+                *item = item.pow_by_constant(&parameters.alpha_inv)?;
+            }
+            linear_layer(
+                state,
+                &parameters.mds,
+                &rc[(2 * i + 1) * m..2 * m * (i + 1)],
+            );
         }
 
-        Ok(state)
+        Ok(())
     }
 
-    pub fn hash(message: Vec<BlsFr>) -> Result<FpVar<BlsFr>, anyhow::Error> {
-        let mut state = [
-            FpVar::zero(),
-            FpVar::constant(BlsFr::from(
-                gen_hash_pattern(message.len(), 1).get_tag(None),
-            )),
-            FpVar::zero(),
-        ];
+    pub fn hash(message: Vec<F>) -> Result<FpVar<F>, anyhow::Error> {
+        let parameters = bundled::<F>()?;
+        let mut state = vec![FpVar::zero(); parameters.width];
+        state[1] = FpVar::constant(F::from(gen_hash_pattern(message.len(), 1).get_tag(None)));
 
         for element in message {
             state[0] = state[0].clone() + element;
-            state = Self::permutation(state)?;
+            Self::permutation(&parameters, &mut state)?;
         }
 
         Ok(state[0].clone())
@@ -17,35 +17,36 @@
 
 #![cfg(test)]
 
+use ark_bls12_377::Fr as Bls377Fr;
 use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::{Bn254, Fr as BnFr};
+use ark_pallas::Fr as PallasFr;
+use ark_vesta::Fr as VestaFr;
 
-use super::chip::{RescuePrimeChip, RescuePrimeParameters};
+use super::chip::RescuePrimeChip;
 use super::circuit::RescuePrimeHashCircuit;
 use super::params::*;
 use crate::api::Sponge;
-use crate::common::test_utils::to_bls;
-use crate::rescue_prime::primitives::RescuePrimePrimitivesBlsFr;
-use ark_ff::UniformRand;
+use crate::common::rounds::{rescue_prime_rounds, SECURITY_LEVEL};
+use crate::common::test_utils::is_satisfied;
+use crate::common::validation::{check_mds, MAX_MDS_WIDTH};
+use crate::fields::goldilocks::Goldilocks;
+use crate::rescue_prime::primitives::{RescuePrimePrimitives, RescuePrimePrimitivesBlsFr};
+use ark_ff::{One, PrimeField, UniformRand, Zero};
 use ark_groth16::Groth16;
 use ark_marlin::{AHPForR1CS, Marlin};
 use ark_poly::univariate::DensePolynomial;
 use ark_poly_commit::marlin_pc::MarlinKZG10;
-use ark_r1cs_std::R1CSVar;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
 use ark_snark::SNARK;
 use blake2::Blake2s;
 
 type RpChpBls381 = RescuePrimeChip<BlsFr>;
 type RpHshCrcBls381 = RescuePrimeHashCircuit<BlsFr>;
-type RpPrmBls381 = RescuePrimeParameters<BlsFr>;
 type RpSpnBls381 = Sponge<RpChpBls381>;
 
 pub fn get_sponge() -> RpSpnBls381 {
-    let parameters = RpPrmBls381 {
-        round_constants: to_bls(&ROUND_CONSTANTS),
-        mds: to_bls(&MDS),
-        alpha_inv: ALPHAINV_BLS381,
-        alpha: ALPHA_BLS381,
-    };
+    let parameters = bls12_381();
     let chip = RpChpBls381::new(parameters);
 
     RpSpnBls381::new(chip)
@@ -102,3 +103,165 @@ fn groth16_bls381() {
     let res = GrothSetup::verify(&vk, &[hash], &proof).unwrap();
     assert!(res);
 }
+
+#[test]
+fn rounds_bls() {
+    assert_eq!(rescue_prime_rounds(M, M - R, 5, SECURITY_LEVEL).unwrap(), N);
+    // Like parameters.sage, the search ends at 24 rounds
+    assert!(rescue_prime_rounds(M, M - R, 5, 100_000).is_err());
+    assert!(rescue_prime_rounds(M, M, 5, SECURITY_LEVEL).is_err());
+
+    let parameters = bls12_381();
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_ok());
+    assert!(parameters.check_rounds(256).is_err());
+
+    let mut parameters = parameters;
+    parameters.nb_rounds = N - 1;
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_err());
+
+    // The exponent is checked before it is read
+    let mut parameters = bls12_381();
+    parameters.alpha.clear();
+    assert!(parameters.check_rounds(SECURITY_LEVEL).is_err());
+}
+
+#[test]
+fn validation_bls() {
+    let parameters = bls12_381();
+    assert!(RpChpBls381::try_new(parameters.clone()).is_ok());
+
+    let mut invalid = parameters.clone();
+    invalid.round_constants.truncate(2 * M);
+    assert!(RpChpBls381::try_new(invalid).is_err());
+
+    // The constants don't match the number of rounds anymore
+    let mut invalid = parameters.clone();
+    invalid.nb_rounds += 1;
+    assert!(RpChpBls381::try_new(invalid).is_err());
+
+    let mut invalid = parameters.clone();
+    invalid.alpha_inv = invalid.alpha.clone();
+    assert!(invalid.validate().is_err());
+
+    // A null coefficient is a singular (1 x 1) submatrix
+    let mut invalid = parameters;
+    invalid.mds[4] = FpVar::Constant(BlsFr::zero());
+    assert!(invalid.validate().is_err());
+
+    // Wider matrices are rejected instead of checking all their submatrices
+    let width = MAX_MDS_WIDTH + 1;
+    assert!(check_mds(&vec![BlsFr::one(); width * width], width).is_err());
+}
+
+/// Checks the hash circuit against the native primitive on the field F
+fn native_vs_circuit<F: PrimeField>() {
+    let chip = RescuePrimeChip::try_new(bundled::<F>().unwrap()).unwrap();
+    let sponge = Sponge::<RescuePrimeChip<F>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![F::rand(rng), F::rand(rng)];
+    let hash = RescuePrimePrimitives::<F>::hash(message.clone())
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = RescuePrimeHashCircuit {
+        hash,
+        message,
+        sponge,
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut invalid = circuit;
+    invalid.hash += F::one();
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn other_fields() {
+    native_vs_circuit::<BnFr>();
+    native_vs_circuit::<Bls377Fr>();
+    native_vs_circuit::<PallasFr>();
+    native_vs_circuit::<VestaFr>();
+}
+
+/// Checks the hash of [1, 2, 3] on the field F against the value given by `reference.py`
+fn known_answer<F: PrimeField>(expected: &str) {
+    let message = vec![F::from(1u64), F::from(2u64), F::from(3u64)];
+    let hash = RescuePrimePrimitives::<F>::hash(message)
+        .unwrap()
+        .value()
+        .unwrap();
+    assert_eq!(hash, F::from_str(expected).ok().unwrap());
+}
+
+#[test]
+fn known_answers() {
+    known_answer::<BlsFr>(
+        "13464896846878561032587884951772017257082614311593124262594257462126306865536",
+    );
+    known_answer::<BnFr>(
+        "5274332320343992121845805198956185316751006147375081148689718022964361475312",
+    );
+    known_answer::<Bls377Fr>(
+        "6804940049947545384032913563237941728791671341997616796401648736669633144270",
+    );
+    known_answer::<PallasFr>(
+        "11184348488752010077411000650437951497628833470862674252645281699350996792259",
+    );
+    known_answer::<VestaFr>(
+        "23826932097796889836730196341284584421860657879040748329634327439567931144500",
+    );
+}
+
+#[test]
+fn groth16_bn254() {
+    let chip = RescuePrimeChip::try_new(bundled::<BnFr>().unwrap()).unwrap();
+    let sponge = Sponge::<RescuePrimeChip<BnFr>>::new(chip);
+
+    let rng = &mut ark_std::test_rng();
+    let message = vec![BnFr::rand(rng)];
+    let hash = RescuePrimePrimitives::<BnFr>::hash(message.clone())
+        .unwrap()
+        .value()
+        .unwrap();
+
+    let circuit = RescuePrimeHashCircuit {
+        hash,
+        message,
+        sponge,
+    };
+
+    type GrothSetup = Groth16<Bn254>;
+
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    let res = GrothSetup::verify(&vk, &[hash], &proof).unwrap();
+    assert!(res);
+}
+
+#[test]
+fn goldilocks() {
+    let parameters = bundled::<Goldilocks>().unwrap();
+    assert_eq!(parameters.width, 5);
+    assert_eq!(rescue_prime_rounds(5, 4, 7, SECURITY_LEVEL).unwrap(), 8);
+    assert!(RescuePrimeChip::try_new(parameters).is_ok());
+
+    // Given by `python3 reference.py goldilocks`, a plain Python model of the permutation and the hash
+    let vectors: [(Vec<u64>, u64); 3] = [
+        (vec![1, 2, 3], 13265727386979617115),
+        (vec![0], 11516345567030658540),
+        (vec![0xffffffff00000000, 1 << 32, 7], 8624083778641992521),
+    ];
+    for (message, expected) in vectors {
+        let message = message.into_iter().map(Goldilocks::from).collect();
+        let hash = RescuePrimePrimitives::<Goldilocks>::hash(message)
+            .unwrap()
+            .value()
+            .unwrap();
+        assert_eq!(hash, Goldilocks::from(expected));
+    }
+
+    native_vs_circuit::<Goldilocks>();
+}
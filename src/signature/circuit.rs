@@ -0,0 +1,65 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, EqGadget},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use super::{
+    gadget::XmssSignatureVar,
+    wots::Wots,
+    xmss::{XmssPublicKey, XmssSignature},
+};
+use crate::api::ChipAPI;
+
+/// Proves that a private signature of a public message is valid for a public XMSS-lite key
+///
+/// The public inputs are the root, the public seed and the message. The circuit depends on the
+/// parameters of WOTS+ and on the height of the tree.
+#[derive(Clone)]
+pub struct XmssCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub wots: Wots<F, C>,
+    pub public_key: XmssPublicKey<F>,
+    pub message: F,
+    pub signature: XmssSignature<F>,
+}
+
+impl<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> XmssCircuit<F, C> {
+    pub fn public_inputs(&self) -> Vec<F> {
+        vec![self.public_key.root, self.public_key.pub_seed, self.message]
+    }
+}
+
+impl<F, C> ConstraintSynthesizer<F> for XmssCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let root = FpVar::new_input(cs.clone(), || Ok(self.public_key.root))?;
+        let pub_seed = FpVar::new_input(cs.clone(), || Ok(self.public_key.pub_seed))?;
+        let message = FpVar::new_input(cs.clone(), || Ok(self.message))?;
+        let signature = XmssSignatureVar::new_witness(cs, || Ok(&self.signature))?;
+
+        signature
+            .root(&self.wots.sponge, &self.wots.params, &pub_seed, &message)?
+            .enforce_equal(&root)
+    }
+}
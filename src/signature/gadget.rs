@@ -0,0 +1,159 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::borrow::Borrow;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, AllocationMode, Boolean, CondSelectGadget, EqGadget, FieldVar},
+    ToBitsGadget,
+};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+
+use super::{chain_hash, compress, xmss::XmssSignature, WotsParameters};
+use crate::{
+    api::{ChipAPI, Sponge},
+    merkle::gadget::MerklePathVar,
+};
+
+/// The base-w digits of the message followed by those of the checksum
+///
+/// It enforces that the message has at most `message_bits` bits.
+fn digits<F: PrimeField>(
+    params: &WotsParameters,
+    message: &FpVar<F>,
+) -> Result<Vec<FpVar<F>>, SynthesisError> {
+    let bits = message.to_bits_le()?;
+    for bit in bits.iter().skip(params.message_bits()) {
+        bit.enforce_equal(&Boolean::FALSE)?;
+    }
+
+    let mut digits = bits[..params.message_bits()]
+        .chunks(params.log_w())
+        .map(Boolean::le_bits_to_fp_var)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // The checksum is less than w^checksum_digits, its low bits are its digits
+    let max = FpVar::Constant(F::from((params.w() - 1) as u64));
+    let checksum = digits
+        .iter()
+        .fold(FpVar::zero(), |checksum, digit| checksum + &max - digit);
+    let bits = checksum.to_bits_le()?;
+    for chunk in bits[..params.checksum_digits() * params.log_w()].chunks(params.log_w()) {
+        digits.push(Boolean::le_bits_to_fp_var(chunk)?);
+    }
+
+    Ok(digits)
+}
+
+/// Recomputes the WOTS+ public key from a signature, the digits of the message stay private
+///
+/// `leaf` is the index of the key in the tweaks of its chains. Each chain takes `w - 1` hashes,
+/// the hashes before the digit being discarded.
+pub fn wots_public_key<F, C>(
+    sponge: &Sponge<C>,
+    params: &WotsParameters,
+    pub_seed: &FpVar<F>,
+    leaf: &FpVar<F>,
+    message: &FpVar<F>,
+    signature: &[FpVar<F>],
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    if signature.len() != params.chains() {
+        return Err(SynthesisError::Unsatisfiable);
+    }
+
+    let mut ends = Vec::with_capacity(params.chains());
+    for (index, (digit, x)) in digits(params, message)?.iter().zip(signature).enumerate() {
+        let mut x = x.clone();
+        let mut started = Boolean::FALSE;
+
+        for step in 0..params.w() - 1 {
+            started = started.or(&digit.is_eq(&FpVar::Constant(F::from(step as u64)))?)?;
+            let address = params.address(leaf, index, step);
+            let next = chain_hash(sponge, pub_seed, &address, &x)?;
+            x = FpVar::conditionally_select(&started, &next, &x)?;
+        }
+
+        ends.push(x);
+    }
+
+    compress(sponge, pub_seed, &ends)
+}
+
+/// An XMSS-lite signature in the circuit, the position of the WOTS+ key stays private
+#[derive(Clone)]
+pub struct XmssSignatureVar<F: PrimeField> {
+    pub wots: Vec<FpVar<F>>,
+    pub path: MerklePathVar<F>,
+}
+
+impl<F: PrimeField> XmssSignatureVar<F> {
+    /// Recomputes the root of the signer
+    pub fn root<C>(
+        &self,
+        sponge: &Sponge<C>,
+        params: &WotsParameters,
+        pub_seed: &FpVar<F>,
+        message: &FpVar<F>,
+    ) -> Result<FpVar<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        // The index of the WOTS+ key, its bits are the directions of the path
+        let index = Boolean::le_bits_to_fp_var(&self.path.directions)?;
+        let leaf = wots_public_key(sponge, params, pub_seed, &index, message, &self.wots)?;
+
+        self.path.root(sponge, &leaf)
+    }
+
+    /// Returns whether the signature of the message is valid for the given public key
+    pub fn verify<C>(
+        &self,
+        sponge: &Sponge<C>,
+        params: &WotsParameters,
+        root: &FpVar<F>,
+        pub_seed: &FpVar<F>,
+        message: &FpVar<F>,
+    ) -> Result<Boolean<F>, SynthesisError>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        self.root(sponge, params, pub_seed, message)?.is_eq(root)
+    }
+}
+
+impl<F: PrimeField> AllocVar<XmssSignature<F>, F> for XmssSignatureVar<F> {
+    fn new_variable<T: Borrow<XmssSignature<F>>>(
+        cs: impl Into<Namespace<F>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let cs = cs.into().cs();
+        let signature = f()?;
+        let signature = signature.borrow();
+
+        Ok(Self {
+            wots: Vec::new_variable(cs.clone(), || Ok(signature.wots.clone()), mode)?,
+            path: MerklePathVar::new_variable(cs, || Ok(&signature.path), mode)?,
+        })
+    }
+}
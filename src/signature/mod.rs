@@ -0,0 +1,194 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Hash-based signatures over the SAFE sponges: WOTS+ and a Merkle tree of WOTS+ keys (XMSS-lite)
+//!
+//! A WOTS+ key signs a single message, a field element of at most `message_bits` bits (e.g. a
+//! digest). The message is written in base `w = 2^log_w` followed by a checksum, each digit `d`
+//! of the signature is the `d`-th element of a hash chain starting at a secret element, and the
+//! public key is the hash of the ends of the chains. The chain hashes are tweaked by a public
+//! seed and by an address, the index of the key (its leaf in XMSS-lite) and the position in its
+//! chains, so that no two hashes under the same public seed share a tweak.
+//!
+//! An XMSS-lite key is the root of a Merkle tree (the trees of `merkle`) whose leaves are the
+//! public keys of 2^height WOTS+ keys, hence it signs 2^height messages. A tree of height 0 is a
+//! single WOTS+ key. `gadget::XmssSignatureVar` verifies a signature against a root in a circuit.
+
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{
+    api::{ChipAPI, Sponge},
+    common::domain,
+    crh::hash_with_domain,
+};
+
+pub mod circuit;
+pub mod gadget;
+pub mod tests;
+pub mod wots;
+pub mod xmss;
+
+/// The parameters of WOTS+, the size of the messages and the Winternitz parameter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct WotsParameters {
+    message_bits: usize,
+    log_w: usize,
+}
+
+impl WotsParameters {
+    /// Signs messages of `message_bits` bits with chains of `2^log_w` elements
+    pub fn new(message_bits: usize, log_w: usize) -> Result<Self, anyhow::Error> {
+        if message_bits == 0 || !(1..=8).contains(&log_w) {
+            return Err(anyhow::Error::msg(format!(
+                "Invalid WOTS+ parameters: {} message bits with log_w = {}",
+                message_bits, log_w
+            )));
+        }
+
+        Ok(Self {
+            message_bits,
+            log_w,
+        })
+    }
+
+    /// Signs any element of the field with w = 16
+    pub fn for_field<F: PrimeField>() -> Self {
+        Self {
+            message_bits: F::size_in_bits(),
+            log_w: 4,
+        }
+    }
+
+    pub fn message_bits(&self) -> usize {
+        self.message_bits
+    }
+
+    pub fn log_w(&self) -> usize {
+        self.log_w
+    }
+
+    /// The number of elements of a chain
+    pub fn w(&self) -> usize {
+        1 << self.log_w
+    }
+
+    /// The number of digits of a message
+    pub fn message_digits(&self) -> usize {
+        self.message_bits.div_ceil(self.log_w)
+    }
+
+    /// The number of digits of the checksum
+    pub fn checksum_digits(&self) -> usize {
+        let mut max = self.message_digits() * (self.w() - 1);
+        let mut digits = 1;
+        while max >= self.w() {
+            max >>= self.log_w;
+            digits += 1;
+        }
+
+        digits
+    }
+
+    /// The number of chains, i.e. the length of the keys and of the signatures
+    pub fn chains(&self) -> usize {
+        self.message_digits() + self.checksum_digits()
+    }
+
+    /// Checks that the messages are elements of the field
+    pub(crate) fn check_field<F: PrimeField>(&self) -> Result<(), anyhow::Error> {
+        if self.message_bits > F::size_in_bits() {
+            return Err(anyhow::Error::msg(format!(
+                "A message of {} bits isn't an element of a field of {} bits",
+                self.message_bits,
+                F::size_in_bits()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// The base-w digits of the message (little-endian) followed by those of the checksum
+    pub fn digits<F: PrimeField>(&self, message: F) -> Result<Vec<usize>, anyhow::Error> {
+        let bits = message.into_repr().to_bits_le();
+        if bits.iter().skip(self.message_bits).any(|bit| *bit) {
+            return Err(anyhow::Error::msg(format!(
+                "The message has more than {} bits",
+                self.message_bits
+            )));
+        }
+
+        let to_digit = |bits: &[bool]| {
+            bits.iter()
+                .rev()
+                .fold(0, |digit, bit| (digit << 1) | *bit as usize)
+        };
+        let mut digits: Vec<_> = bits[..self.message_bits]
+            .chunks(self.log_w)
+            .map(to_digit)
+            .collect();
+
+        let mut checksum: usize = digits.iter().map(|digit| self.w() - 1 - digit).sum();
+        for _ in 0..self.checksum_digits() {
+            digits.push(checksum & (self.w() - 1));
+            checksum >>= self.log_w;
+        }
+
+        Ok(digits)
+    }
+
+    /// The tweak of the hash from the element `step` of the chain `index` of the key `leaf`
+    ///
+    /// It is a constant if `leaf` is, and a linear combination of it otherwise.
+    fn address<F: PrimeField>(&self, leaf: &FpVar<F>, index: usize, step: usize) -> FpVar<F> {
+        let chain = FpVar::Constant(F::from((index * self.w() + step) as u64));
+
+        leaf * F::from((self.chains() * self.w()) as u64) + chain
+    }
+}
+
+/// Hashes an element of a chain to the next one, natively (on constants) and in a circuit
+pub(crate) fn chain_hash<F, C>(
+    sponge: &Sponge<C>,
+    pub_seed: &FpVar<F>,
+    address: &FpVar<F>,
+    x: &FpVar<F>,
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let elements = [pub_seed.clone(), address.clone(), x.clone()];
+
+    hash_with_domain(sponge, &elements, Some(domain::WOTS_CHAIN))
+}
+
+/// Hashes the ends of the chains to the public key
+pub(crate) fn compress<F, C>(
+    sponge: &Sponge<C>,
+    pub_seed: &FpVar<F>,
+    ends: &[FpVar<F>],
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let elements = [std::slice::from_ref(pub_seed), ends].concat();
+
+    hash_with_domain(sponge, &elements, Some(domain::WOTS_PUBLIC_KEY))
+}
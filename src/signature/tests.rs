@@ -0,0 +1,276 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_snark::SNARK;
+
+use super::{
+    circuit::XmssCircuit,
+    gadget::{wots_public_key, XmssSignatureVar},
+    wots::{Wots, WotsKeypair},
+    xmss::XmssKeypair,
+    WotsParameters,
+};
+use crate::{
+    common::test_utils::is_satisfied, crh::BundledSponge, griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip, rescue_prime::chip::RescuePrimeChip,
+};
+
+/// Small parameters to keep the tests fast: 16-bit messages and w = 4
+fn small<F, C>() -> Wots<F, C>
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    Wots::new(
+        C::bundled_sponge().unwrap(),
+        WotsParameters::new(16, 2).unwrap(),
+    )
+    .unwrap()
+}
+
+#[test]
+fn parameters() {
+    let params = WotsParameters::for_field::<BlsFr>();
+    assert_eq!(params.w(), 16);
+    assert_eq!(params.message_digits(), 64);
+    assert_eq!(params.checksum_digits(), 3);
+    assert_eq!(params.chains(), 67);
+
+    let params = WotsParameters::new(8, 2).unwrap();
+    assert_eq!(params.chains(), 6);
+    // 210 = 2 + 0 * 4 + 1 * 16 + 3 * 64, and the checksum is 1 + 3 + 2 + 0 = 6 = 2 + 1 * 4
+    assert_eq!(
+        params.digits(BlsFr::from(210u64)).unwrap(),
+        vec![2, 0, 1, 3, 2, 1]
+    );
+    assert!(params.digits(BlsFr::from(256u64)).is_err());
+
+    assert!(WotsParameters::new(0, 4).is_err());
+    assert!(WotsParameters::new(16, 9).is_err());
+    let sponge = NeptuneChip::<BlsFr>::bundled_sponge().unwrap();
+    assert!(Wots::new(sponge, WotsParameters::new(300, 4).unwrap()).is_err());
+}
+
+#[test]
+fn wots() {
+    let rng = &mut ark_std::test_rng();
+    let wots = small::<BlsFr, GriffinChip<BlsFr>>();
+    let keypair = wots.keygen(rng).unwrap();
+    let (message, other) = (BlsFr::from(0xbeefu64), BlsFr::from(0xbeeeu64));
+
+    let signature = wots.sign(&keypair, message).unwrap();
+    assert_eq!(signature.len(), wots.params.chains());
+    assert!(wots
+        .verify(keypair.pub_seed, 0, keypair.public, message, &signature)
+        .unwrap());
+    assert!(!wots
+        .verify(keypair.pub_seed, 0, keypair.public, other, &signature)
+        .unwrap());
+    assert!(!wots
+        .verify(BlsFr::rand(rng), 0, keypair.public, message, &signature)
+        .unwrap());
+    assert!(wots
+        .verify(
+            keypair.pub_seed,
+            0,
+            keypair.public,
+            message,
+            &signature[1..]
+        )
+        .is_err());
+    assert!(!wots
+        .verify(keypair.pub_seed, 1, keypair.public, message, &signature)
+        .unwrap());
+    assert!(wots.sign(&keypair, BlsFr::from(1u64 << 16)).is_err());
+
+    // The keys derived from a seed are deterministic and distinct for each index
+    let (sk_seed, pub_seed) = (BlsFr::rand(rng), BlsFr::rand(rng));
+    let keypair = wots.keygen_from_seed(sk_seed, pub_seed, 0).unwrap();
+    assert_eq!(
+        keypair,
+        wots.keygen_from_seed(sk_seed, pub_seed, 0).unwrap()
+    );
+    assert_ne!(
+        keypair.public,
+        wots.keygen_from_seed(sk_seed, pub_seed, 1).unwrap().public
+    );
+
+    // The same secret elements under the same public seed give distinct chains at distinct leaves
+    let other = WotsKeypair {
+        leaf: 1,
+        ..keypair.clone()
+    };
+    let signature = wots.sign(&keypair, message).unwrap();
+    assert_ne!(signature, wots.sign(&other, message).unwrap());
+    assert_ne!(
+        wots.public_key_from_signature(pub_seed, 0, message, &signature)
+            .unwrap(),
+        wots.public_key_from_signature(pub_seed, 1, message, &signature)
+            .unwrap()
+    );
+}
+
+/// Checks the WOTS+ gadget against the native public key
+fn native_and_gadget<F, C>()
+where
+    F: PrimeField,
+    C: BundledSponge<Value = FpVar<F>>,
+{
+    let rng = &mut ark_std::test_rng();
+    let wots = small::<F, C>();
+    let keypair = wots
+        .keygen_from_seed(F::rand(rng), F::rand(rng), 5)
+        .unwrap();
+    let message = F::from(0x1234u64);
+    let signature = wots.sign(&keypair, message).unwrap();
+
+    let cs = ConstraintSystem::<F>::new_ref();
+    let pub_seed = FpVar::new_input(cs.clone(), || Ok(keypair.pub_seed)).unwrap();
+    let leaf = FpVar::new_witness(cs.clone(), || Ok(F::from(keypair.leaf))).unwrap();
+    let message_var = FpVar::new_input(cs.clone(), || Ok(message)).unwrap();
+    let signature_var = Vec::new_witness(cs.clone(), || Ok(signature.clone())).unwrap();
+    let public = wots_public_key(
+        &wots.sponge,
+        &wots.params,
+        &pub_seed,
+        &leaf,
+        &message_var,
+        &signature_var,
+    )
+    .unwrap();
+    assert_eq!(public.value().unwrap(), keypair.public);
+    assert!(cs.is_satisfied().unwrap());
+
+    // The public key depends on the index of the key
+    let leaf = FpVar::Constant(F::from(keypair.leaf + 1));
+    let public = wots_public_key(
+        &wots.sponge,
+        &wots.params,
+        &pub_seed,
+        &leaf,
+        &message_var,
+        &signature_var,
+    )
+    .unwrap();
+    assert_ne!(public.value().unwrap(), keypair.public);
+
+    // A message of more bits than the parameters is rejected
+    let cs = ConstraintSystem::<F>::new_ref();
+    let message_var = FpVar::new_input(cs.clone(), || Ok(F::from(1u64 << 16))).unwrap();
+    let signature_var = Vec::new_witness(cs.clone(), || Ok(signature)).unwrap();
+    let pub_seed = FpVar::new_input(cs.clone(), || Ok(keypair.pub_seed)).unwrap();
+    let leaf = FpVar::Constant(F::from(keypair.leaf));
+    let public = wots_public_key(
+        &wots.sponge,
+        &wots.params,
+        &pub_seed,
+        &leaf,
+        &message_var,
+        &signature_var,
+    )
+    .unwrap();
+    assert_ne!(public.value().unwrap(), keypair.public);
+    assert!(!cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn native_and_gadget_griffin() {
+    native_and_gadget::<BlsFr, GriffinChip<BlsFr>>();
+    native_and_gadget::<BnFr, GriffinChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_neptune() {
+    native_and_gadget::<BlsFr, NeptuneChip<BlsFr>>();
+    native_and_gadget::<BnFr, NeptuneChip<BnFr>>();
+}
+
+#[test]
+fn native_and_gadget_rescue_prime() {
+    native_and_gadget::<BlsFr, RescuePrimeChip<BlsFr>>();
+}
+
+#[test]
+fn xmss() {
+    let rng = &mut ark_std::test_rng();
+    let wots = small::<BlsFr, NeptuneChip<BlsFr>>();
+    let mut keypair = XmssKeypair::new(rng, wots.clone(), 2).unwrap();
+    let public = keypair.public_key();
+
+    let messages: Vec<_> = (0..4u64).map(|i| BlsFr::from(1000 + i)).collect();
+    for (i, message) in messages.iter().enumerate() {
+        let signature = keypair.sign(*message).unwrap();
+        assert_eq!(signature.path.index(), i);
+        assert!(public.verify(&wots, *message, &signature).unwrap());
+        assert!(!public
+            .verify(&wots, messages[(i + 1) % 4], &signature)
+            .unwrap());
+
+        let cs = ConstraintSystem::<BlsFr>::new_ref();
+        let root = FpVar::new_input(cs.clone(), || Ok(public.root)).unwrap();
+        let pub_seed = FpVar::new_input(cs.clone(), || Ok(public.pub_seed)).unwrap();
+        let message = FpVar::new_input(cs.clone(), || Ok(*message)).unwrap();
+        let signature_var = XmssSignatureVar::new_witness(cs.clone(), || Ok(&signature)).unwrap();
+        let valid = signature_var
+            .verify(&wots.sponge, &wots.params, &root, &pub_seed, &message)
+            .unwrap();
+        assert!(valid.value().unwrap());
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    assert_eq!(keypair.remaining(), 0);
+    assert!(keypair.sign(messages[0]).is_err());
+}
+
+#[test]
+fn xmss_circuit() {
+    let rng = &mut ark_std::test_rng();
+    let wots = small::<BlsFr, NeptuneChip<BlsFr>>();
+    let mut keypair = XmssKeypair::new(rng, wots.clone(), 3).unwrap();
+    keypair.sign(BlsFr::from(1u64)).unwrap();
+    let message = BlsFr::from(0xcafeu64);
+
+    let circuit = XmssCircuit {
+        wots,
+        public_key: keypair.public_key(),
+        message,
+        signature: keypair.sign(message).unwrap(),
+    };
+    assert!(is_satisfied(circuit.clone()));
+
+    let mut wrong = circuit.clone();
+    wrong.message = BlsFr::from(0xcaffu64);
+    assert!(!is_satisfied(wrong));
+    let mut wrong = circuit.clone();
+    wrong.public_key.pub_seed = BlsFr::rand(rng);
+    assert!(!is_satisfied(wrong));
+
+    let (pk, vk) = Groth16::<Bls381>::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = Groth16::prove(&pk, circuit.clone(), rng).unwrap();
+    let mut public_inputs = circuit.public_inputs();
+    assert!(Groth16::verify(&vk, &public_inputs, &proof).unwrap());
+    public_inputs[2] = BlsFr::from(0xcaffu64);
+    assert!(!Groth16::verify(&vk, &public_inputs, &proof).unwrap());
+}
@@ -0,0 +1,199 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use std::marker::PhantomData;
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{fields::fp::FpVar, R1CSVar};
+use ark_std::rand::Rng;
+
+use super::{chain_hash, compress, WotsParameters};
+use crate::{
+    api::{constants, ChipAPI, Sponge},
+    prf::derive_native,
+};
+
+/// A WOTS+ key pair, the secret key must sign a single message
+///
+/// `leaf` is the index of the key in the tweaks of its chains, its position in an XMSS-lite tree.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WotsKeypair<F: PrimeField> {
+    pub secret: Vec<F>,
+    pub pub_seed: F,
+    pub leaf: u64,
+    pub public: F,
+}
+
+/// WOTS+ over a SAFE sponge
+#[derive(Clone)]
+pub struct Wots<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub sponge: Sponge<C>,
+    pub params: WotsParameters,
+    _field: PhantomData<F>,
+}
+
+impl<F, C> Wots<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    pub fn new(sponge: Sponge<C>, params: WotsParameters) -> Result<Self, anyhow::Error> {
+        params.check_field::<F>()?;
+
+        Ok(Self {
+            sponge,
+            params,
+            _field: PhantomData,
+        })
+    }
+
+    /// A key pair with random secret elements and a random public seed, its index is 0
+    pub fn keygen<R: Rng>(&self, rng: &mut R) -> Result<WotsKeypair<F>, anyhow::Error> {
+        let secret = (0..self.params.chains()).map(|_| F::rand(rng)).collect();
+
+        self.keypair(secret, F::rand(rng), 0)
+    }
+
+    /// The key pair of the given index, its secret elements are derived from a secret seed
+    pub fn keygen_from_seed(
+        &self,
+        sk_seed: F,
+        pub_seed: F,
+        index: u64,
+    ) -> Result<WotsKeypair<F>, anyhow::Error> {
+        let context = [pub_seed, F::from(index)];
+        let secret = derive_native(&self.sponge, sk_seed, &context, self.params.chains())?;
+
+        self.keypair(secret, pub_seed, index)
+    }
+
+    fn keypair(
+        &self,
+        secret: Vec<F>,
+        pub_seed: F,
+        leaf: u64,
+    ) -> Result<WotsKeypair<F>, anyhow::Error> {
+        let ends = secret
+            .iter()
+            .enumerate()
+            .map(|(index, x)| self.chain(pub_seed, leaf, index, 0, self.params.w() - 1, *x))
+            .collect::<Result<Vec<_>, _>>()?;
+        let public = self.compress(pub_seed, &ends)?;
+
+        Ok(WotsKeypair {
+            secret,
+            pub_seed,
+            leaf,
+            public,
+        })
+    }
+
+    pub fn sign(&self, keypair: &WotsKeypair<F>, message: F) -> Result<Vec<F>, anyhow::Error> {
+        if keypair.secret.len() != self.params.chains() {
+            return Err(anyhow::Error::msg(
+                "The secret key doesn't fit the parameters",
+            ));
+        }
+
+        self.params
+            .digits(message)?
+            .into_iter()
+            .zip(keypair.secret.iter())
+            .enumerate()
+            .map(|(index, (digit, x))| {
+                self.chain(keypair.pub_seed, keypair.leaf, index, 0, digit, *x)
+            })
+            .collect()
+    }
+
+    /// Recomputes the public key from a signature, it's the one of the signer if it is valid
+    pub fn public_key_from_signature(
+        &self,
+        pub_seed: F,
+        leaf: u64,
+        message: F,
+        signature: &[F],
+    ) -> Result<F, anyhow::Error> {
+        if signature.len() != self.params.chains() {
+            return Err(anyhow::Error::msg(format!(
+                "A signature has {} elements, got {}",
+                self.params.chains(),
+                signature.len()
+            )));
+        }
+
+        let ends = self
+            .params
+            .digits(message)?
+            .into_iter()
+            .zip(signature.iter())
+            .enumerate()
+            .map(|(index, (digit, x))| {
+                self.chain(
+                    pub_seed,
+                    leaf,
+                    index,
+                    digit,
+                    self.params.w() - 1 - digit,
+                    *x,
+                )
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.compress(pub_seed, &ends)
+    }
+
+    pub fn verify(
+        &self,
+        pub_seed: F,
+        leaf: u64,
+        public: F,
+        message: F,
+        signature: &[F],
+    ) -> Result<bool, anyhow::Error> {
+        Ok(self.public_key_from_signature(pub_seed, leaf, message, signature)? == public)
+    }
+
+    /// Walks `steps` elements of the chain `index` of the key `leaf` from the element `start`
+    fn chain(
+        &self,
+        pub_seed: F,
+        leaf: u64,
+        index: usize,
+        start: usize,
+        steps: usize,
+        x: F,
+    ) -> Result<F, anyhow::Error> {
+        let pub_seed = FpVar::Constant(pub_seed);
+        let leaf = FpVar::Constant(F::from(leaf));
+        let mut x = FpVar::Constant(x);
+        for step in start..start + steps {
+            x = chain_hash(
+                &self.sponge,
+                &pub_seed,
+                &self.params.address(&leaf, index, step),
+                &x,
+            )?;
+        }
+
+        Ok(x.value()?)
+    }
+
+    fn compress(&self, pub_seed: F, ends: &[F]) -> Result<F, anyhow::Error> {
+        Ok(compress(&self.sponge, &FpVar::Constant(pub_seed), &constants(ends))?.value()?)
+    }
+}
@@ -0,0 +1,130 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::fields::fp::FpVar;
+use ark_std::rand::Rng;
+
+use super::wots::Wots;
+use crate::{
+    api::ChipAPI,
+    merkle::tree::{MerklePath, MerkleTree},
+};
+
+/// The public key of XMSS-lite, the root of the tree of the WOTS+ public keys
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct XmssPublicKey<F: PrimeField> {
+    pub root: F,
+    pub pub_seed: F,
+}
+
+/// A WOTS+ signature and the path of the WOTS+ public key in the tree
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XmssSignature<F: PrimeField> {
+    pub wots: Vec<F>,
+    pub path: MerklePath<F>,
+}
+
+impl<F: PrimeField> XmssPublicKey<F> {
+    pub fn verify<C>(
+        &self,
+        wots: &Wots<F, C>,
+        message: F,
+        signature: &XmssSignature<F>,
+    ) -> Result<bool, anyhow::Error>
+    where
+        C: ChipAPI<Value = FpVar<F>> + Clone,
+    {
+        let index = signature.path.index() as u64;
+        let leaf =
+            wots.public_key_from_signature(self.pub_seed, index, message, &signature.wots)?;
+
+        signature.path.verify(&wots.sponge, leaf, self.root)
+    }
+}
+
+/// An XMSS-lite key pair, it signs 2^height messages with the WOTS+ keys in turn
+///
+/// The state (the next key) must be kept: signing twice with a WOTS+ key breaks its security.
+pub struct XmssKeypair<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    wots: Wots<F, C>,
+    sk_seed: F,
+    public: XmssPublicKey<F>,
+    tree: MerkleTree<F, C>,
+    next: usize,
+}
+
+impl<F, C> XmssKeypair<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    /// Generates the 2^height WOTS+ keys and their tree
+    pub fn new<R: Rng>(
+        rng: &mut R,
+        wots: Wots<F, C>,
+        height: usize,
+    ) -> Result<Self, anyhow::Error> {
+        if height >= usize::BITS as usize {
+            return Err(anyhow::Error::msg(format!("Invalid height {}", height)));
+        }
+
+        let (sk_seed, pub_seed) = (F::rand(rng), F::rand(rng));
+        let leaves = (0..1u64 << height)
+            .map(|index| Ok(wots.keygen_from_seed(sk_seed, pub_seed, index)?.public))
+            .collect::<Result<Vec<_>, anyhow::Error>>()?;
+        let tree = MerkleTree::new(wots.sponge.clone(), height, &leaves)?;
+        let public = XmssPublicKey {
+            root: tree.root(),
+            pub_seed,
+        };
+
+        Ok(Self {
+            wots,
+            sk_seed,
+            public,
+            tree,
+            next: 0,
+        })
+    }
+
+    pub fn public_key(&self) -> XmssPublicKey<F> {
+        self.public
+    }
+
+    /// The number of messages which can still be signed
+    pub fn remaining(&self) -> usize {
+        self.tree.capacity() - self.next
+    }
+
+    pub fn sign(&mut self, message: F) -> Result<XmssSignature<F>, anyhow::Error> {
+        if self.remaining() == 0 {
+            return Err(anyhow::Error::msg("All the WOTS+ keys were used"));
+        }
+
+        let keypair =
+            self.wots
+                .keygen_from_seed(self.sk_seed, self.public.pub_seed, self.next as u64)?;
+        let signature = XmssSignature {
+            wots: self.wots.sign(&keypair, message)?,
+            path: self.tree.path(self.next)?,
+        };
+        self.next += 1;
+
+        Ok(signature)
+    }
+}
@@ -0,0 +1,170 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! SIV with Ciminion
+//!
+//! The synthetic IV is keyed by the IV subkey of both master keys (see `siv::subkeys`), then it is
+//! the nonce of Ciminion. Only the keystream of Ciminion is computed, without its tag: the IV
+//! authenticates the message. The keystream is keyed by the master keys through the key schedule
+//! of Ciminion, so the keystream subkey of `siv::subkeys` isn't used.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{Boolean, EqGadget},
+    R1CSVar,
+};
+use ark_relations::r1cs::SynthesisError;
+
+use super::{subkeys, synthetic_iv};
+use crate::{
+    api::{constants, ChipAPI, Sponge},
+    ciminion::{chip::CiminionChip, primitives::CiminionPrimitive},
+};
+
+/// Encrypts a message with Ciminion, the ciphertext is followed by the synthetic IV
+pub fn encrypt<F, C>(
+    chip: &CiminionChip<F>,
+    sponge: &Sponge<C>,
+    master_key: (&FpVar<F>, &FpVar<F>),
+    ad: &[FpVar<F>],
+    message: &[FpVar<F>],
+) -> Result<Vec<FpVar<F>>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (mk1, mk2) = master_key;
+    let (iv_key, _) = subkeys(sponge, &[mk1.clone(), mk2.clone()])?;
+    let iv = synthetic_iv(sponge, &iv_key, ad, message)?;
+
+    let mut chip = chip.clone();
+    chip.init(mk1.clone(), mk2.clone(), message.len());
+    let mut ciphertext: Vec<_> = message
+        .iter()
+        .zip(chip.keystream(iv.clone(), message.len())?)
+        .map(|(block, ks)| block + ks)
+        .collect();
+    ciphertext.push(iv);
+
+    Ok(ciphertext)
+}
+
+/// Decrypts a ciphertext followed by its IV, returns the message and whether the IV is valid
+pub fn decrypt<F, C>(
+    chip: &CiminionChip<F>,
+    sponge: &Sponge<C>,
+    master_key: (&FpVar<F>, &FpVar<F>),
+    ad: &[FpVar<F>],
+    ciphertext: &[FpVar<F>],
+) -> Result<(Vec<FpVar<F>>, Boolean<F>), SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (mk1, mk2) = master_key;
+    let (iv, ciphertext) = ciphertext
+        .split_last()
+        .ok_or(SynthesisError::Unsatisfiable)?;
+
+    let mut chip = chip.clone();
+    chip.init(mk1.clone(), mk2.clone(), ciphertext.len());
+    let message: Vec<_> = ciphertext
+        .iter()
+        .zip(chip.keystream(iv.clone(), ciphertext.len())?)
+        .map(|(block, ks)| block - ks)
+        .collect();
+
+    let (iv_key, _) = subkeys(sponge, &[mk1.clone(), mk2.clone()])?;
+    let is_valid = synthetic_iv(sponge, &iv_key, ad, &message)?.is_eq(iv)?;
+
+    Ok((message, is_valid))
+}
+
+/// Encrypts natively with the bundled Ciminion parameters, the ciphertext is followed by the IV
+pub fn encrypt_native<F, C>(
+    sponge: &Sponge<C>,
+    master_key: (F, F),
+    ad: &[F],
+    message: &[F],
+) -> Result<Vec<F>, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let iv = native_iv(sponge, master_key, ad, message)?;
+
+    let mut ciphertext: Vec<_> = message
+        .iter()
+        .zip(CiminionPrimitive::<F>::keystream(
+            master_key,
+            iv,
+            message.len(),
+        )?)
+        .map(|(block, ks)| *block + ks)
+        .collect();
+    ciphertext.push(iv);
+
+    Ok(ciphertext)
+}
+
+/// Decrypts natively a ciphertext followed by its IV, fails if the IV is invalid
+pub fn decrypt_native<F, C>(
+    sponge: &Sponge<C>,
+    master_key: (F, F),
+    ad: &[F],
+    ciphertext: &[F],
+) -> Result<Vec<F>, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (iv, ciphertext) = ciphertext
+        .split_last()
+        .ok_or_else(|| anyhow::Error::msg("Missing IV"))?;
+
+    let message: Vec<_> = ciphertext
+        .iter()
+        .zip(CiminionPrimitive::<F>::keystream(
+            master_key,
+            *iv,
+            ciphertext.len(),
+        )?)
+        .map(|(block, ks)| *block - ks)
+        .collect();
+
+    if native_iv(sponge, master_key, ad, &message)? != *iv {
+        return Err(anyhow::Error::msg("Invalid IV"));
+    }
+    Ok(message)
+}
+
+fn native_iv<F, C>(
+    sponge: &Sponge<C>,
+    master_key: (F, F),
+    ad: &[F],
+    message: &[F],
+) -> Result<F, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (iv_key, _) = subkeys(sponge, &constants(&[master_key.0, master_key.1]))?;
+    let iv = synthetic_iv(sponge, &iv_key, &constants(ad), &constants(message))?;
+
+    Ok(iv.value()?)
+}
@@ -0,0 +1,92 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, EqGadget},
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::{
+    api::{ChipAPI, Sponge},
+    ciminion::chip::CiminionChip,
+};
+
+/// SIV encryption of a private message under a private key over a sponge
+///
+/// The ciphertext (followed by the IV) and the associated data are public inputs.
+#[derive(Clone)]
+pub struct SivCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub sponge: Sponge<C>,
+    pub key: F,
+    pub ad: Vec<F>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+}
+
+impl<F, C> ConstraintSynthesizer<F> for SivCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message))?;
+        let k = FpVar::new_witness(cs.clone(), || Ok(self.key))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let ad = Vec::new_input(cs, || Ok(self.ad))?;
+
+        let result = super::encrypt(&self.sponge, &k, &ad, &m)?;
+
+        result.enforce_equal(&ct)?;
+
+        Ok(())
+    }
+}
+
+/// SIV encryption of a private message under private master keys with Ciminion
+///
+/// The ciphertext (followed by the IV) and the associated data are public inputs.
+#[derive(Clone)]
+pub struct CiminionSivCircuit<F: PrimeField, C: ChipAPI<Value = FpVar<F>> + Clone> {
+    pub chip: CiminionChip<F>,
+    pub sponge: Sponge<C>,
+    pub keys: (F, F),
+    pub ad: Vec<F>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+}
+
+impl<F, C> ConstraintSynthesizer<F> for CiminionSivCircuit<F, C>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message))?;
+        let mk1 = FpVar::new_witness(cs.clone(), || Ok(self.keys.0))?;
+        let mk2 = FpVar::new_witness(cs.clone(), || Ok(self.keys.1))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let ad = Vec::new_input(cs, || Ok(self.ad))?;
+
+        let result = super::ciminion::encrypt(&self.chip, &self.sponge, (&mk1, &mk2), &ad, &m)?;
+
+        result.enforce_equal(&ct)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,229 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Nonce-misuse-resistant authenticated encryption (SIV)
+//!
+//! The synthetic IV is a keyed hash of the associated data and of the message, with their lengths.
+//! The message is then encrypted under the key and that IV, which is appended to the ciphertext and
+//! acts as the tag. The IV and the keystream are keyed by two subkeys, derived from the key with the
+//! KDF of `prf`, so the key itself is never used twice. There is no nonce: encrypting the same message twice under the same key and
+//! associated data gives the same ciphertext, and nothing more is leaked (deterministic encryption).
+//!
+//! This module works over the SAFE sponges, `ciminion` encrypts with Ciminion instead.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{Boolean, EqGadget},
+    R1CSVar,
+};
+use ark_relations::r1cs::SynthesisError;
+
+use crate::{
+    api::{constants, ChipAPI, Sponge, SpongeAPI},
+    common::{
+        domain,
+        pattern::{IOPattern, SpongeOp},
+    },
+    prf::derive,
+};
+
+pub mod ciminion;
+pub mod circuit;
+pub mod tests;
+
+/// Derives the subkeys of the IV and of the keystream (in that order) from the key
+///
+/// The first element of the key is the key of the KDF, the others are absorbed ahead of the
+/// `SIV_SUBKEYS` context, e.g. the second master key of Ciminion.
+pub fn subkeys<F, C>(
+    sponge: &Sponge<C>,
+    key: &[FpVar<F>],
+) -> Result<(FpVar<F>, FpVar<F>), SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (first, rest) = key.split_first().ok_or(SynthesisError::Unsatisfiable)?;
+    let context = [rest, &[FpVar::Constant(F::from(domain::SIV_SUBKEYS))]].concat();
+
+    let mut subkeys = derive(sponge, first, &context, 2)?;
+    let keystream_key = subkeys.pop().ok_or(SynthesisError::Unsatisfiable)?;
+    let iv_key = subkeys.pop().ok_or(SynthesisError::Unsatisfiable)?;
+
+    Ok((iv_key, keystream_key))
+}
+
+/// Derives the synthetic IV from the IV subkey, the associated data and the message
+///
+/// The lengths are absorbed right after the subkey, in a single element, so moving elements from
+/// the associated data to the message changes the IV.
+pub fn synthetic_iv<F, C>(
+    sponge: &Sponge<C>,
+    iv_key: &FpVar<F>,
+    ad: &[FpVar<F>],
+    message: &[FpVar<F>],
+) -> Result<FpVar<F>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let lengths = F::from(((ad.len() as u64) << 32) + message.len() as u64);
+    let input = [
+        std::slice::from_ref(iv_key),
+        &[FpVar::Constant(lengths)],
+        ad,
+        message,
+    ]
+    .concat();
+
+    let mut sponge = sponge.clone();
+    let pattern = IOPattern::new(vec![
+        SpongeOp::Absorb(input.len() as u32),
+        SpongeOp::Squeeze(1),
+    ]);
+
+    sponge.start(pattern, Some(domain::SIV_IV));
+    sponge.absorb(input.len() as u32, &input);
+    let iv = sponge.squeeze(1)[0].clone();
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(iv)
+}
+
+/// Squeezes `len` elements of keystream from the keystream subkey and the IV
+fn keystream<F, C>(
+    sponge: &Sponge<C>,
+    keystream_key: &FpVar<F>,
+    iv: &FpVar<F>,
+    len: usize,
+) -> Result<Vec<FpVar<F>>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    if len == 0 {
+        return Ok(vec![]);
+    }
+
+    let mut sponge = sponge.clone();
+    let pattern = IOPattern::new(vec![SpongeOp::Absorb(2), SpongeOp::Squeeze(len as u32)]);
+
+    sponge.start(pattern, Some(domain::SIV_KEYSTREAM));
+    sponge.absorb(2, &[keystream_key.clone(), iv.clone()]);
+    let keystream = sponge.squeeze(len as u32);
+    sponge.finish().map_err(|_| SynthesisError::Unsatisfiable)?;
+
+    Ok(keystream)
+}
+
+/// Encrypts a message, the ciphertext is followed by the synthetic IV
+///
+/// It works both natively (on constants) and in a circuit.
+pub fn encrypt<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    ad: &[FpVar<F>],
+    message: &[FpVar<F>],
+) -> Result<Vec<FpVar<F>>, SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (iv_key, keystream_key) = subkeys(sponge, std::slice::from_ref(key))?;
+    let iv = synthetic_iv(sponge, &iv_key, ad, message)?;
+
+    let mut ciphertext: Vec<_> = message
+        .iter()
+        .zip(keystream(sponge, &keystream_key, &iv, message.len())?)
+        .map(|(block, ks)| block + ks)
+        .collect();
+    ciphertext.push(iv);
+
+    Ok(ciphertext)
+}
+
+/// Decrypts a ciphertext followed by its IV, returns the message and whether the IV is valid
+pub fn decrypt<F, C>(
+    sponge: &Sponge<C>,
+    key: &FpVar<F>,
+    ad: &[FpVar<F>],
+    ciphertext: &[FpVar<F>],
+) -> Result<(Vec<FpVar<F>>, Boolean<F>), SynthesisError>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (iv, ciphertext) = ciphertext
+        .split_last()
+        .ok_or(SynthesisError::Unsatisfiable)?;
+    let (iv_key, keystream_key) = subkeys(sponge, std::slice::from_ref(key))?;
+
+    let message: Vec<_> = ciphertext
+        .iter()
+        .zip(keystream(sponge, &keystream_key, iv, ciphertext.len())?)
+        .map(|(block, ks)| block - ks)
+        .collect();
+    let is_valid = synthetic_iv(sponge, &iv_key, ad, &message)?.is_eq(iv)?;
+
+    Ok((message, is_valid))
+}
+
+/// Encrypts natively, the ciphertext is followed by the synthetic IV
+pub fn encrypt_native<F, C>(
+    sponge: &Sponge<C>,
+    key: F,
+    ad: &[F],
+    message: &[F],
+) -> Result<Vec<F>, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let ciphertext = encrypt(
+        sponge,
+        &FpVar::Constant(key),
+        &constants(ad),
+        &constants(message),
+    )?;
+
+    Ok(ciphertext.value()?)
+}
+
+/// Decrypts natively a ciphertext followed by its IV, fails if the IV is invalid
+pub fn decrypt_native<F, C>(
+    sponge: &Sponge<C>,
+    key: F,
+    ad: &[F],
+    ciphertext: &[F],
+) -> Result<Vec<F>, anyhow::Error>
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let (message, is_valid) = decrypt(
+        sponge,
+        &FpVar::Constant(key),
+        &constants(ad),
+        &constants(ciphertext),
+    )?;
+
+    if !is_valid.value()? {
+        return Err(anyhow::Error::msg("Invalid IV"));
+    }
+    Ok(message.value()?)
+}
@@ -0,0 +1,266 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::Fr as BnFr;
+use ark_ff::PrimeField;
+use ark_groth16::Groth16;
+use ark_r1cs_std::{fields::fp::FpVar, prelude::AllocVar, R1CSVar};
+use ark_relations::r1cs::ConstraintSystem;
+use ark_snark::SNARK;
+
+use super::{
+    ciminion,
+    circuit::{CiminionSivCircuit, SivCircuit},
+    decrypt, decrypt_native, encrypt_native, subkeys, synthetic_iv,
+};
+use crate::{
+    api::{constants, ChipAPI, Sponge},
+    ciminion::chip::CiminionChip,
+    common::test_utils::is_satisfied,
+    griffin::{self, chip::GriffinChip},
+    neptune::{self, chip::NeptuneChip},
+};
+
+fn griffin<F: PrimeField>() -> Sponge<GriffinChip<F>> {
+    Sponge::<GriffinChip<F>>::new(
+        GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn neptune<F: PrimeField>() -> Sponge<NeptuneChip<F>> {
+    Sponge::<NeptuneChip<F>>::new(
+        NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn ciminion_chip<F: PrimeField>() -> CiminionChip<F> {
+    CiminionChip::try_new(crate::ciminion::params::bundled::<F>().unwrap()).unwrap()
+}
+
+fn random<F: PrimeField>(len: usize) -> Vec<F> {
+    let rng = &mut ark_std::test_rng();
+    (0..len).map(|_| F::rand(rng)).collect()
+}
+
+/// Checks that `encrypt` is deterministic, that `decrypt` inverts it, and that changes are detected
+fn round_trip<F: PrimeField>(
+    encrypt: impl Fn(&[F], &[F]) -> Vec<F>,
+    decrypt: impl Fn(&[F], &[F]) -> Result<Vec<F>, anyhow::Error>,
+) {
+    let one = F::from(1u64);
+
+    for (ad_len, message_len) in [(0, 0), (0, 1), (1, 0), (2, 5), (3, 4)] {
+        let ad = random::<F>(ad_len);
+        let message = random::<F>(message_len);
+
+        let ciphertext = encrypt(&ad, &message);
+        assert_eq!(ciphertext.len(), message.len() + 1);
+        assert_eq!(ciphertext, encrypt(&ad, &message));
+        assert_eq!(decrypt(&ad, &ciphertext).unwrap(), message);
+
+        // The same message with other associated data has another IV, and so another keystream
+        let mut other = ad.clone();
+        match other.last_mut() {
+            Some(x) => *x += one,
+            None => other.push(F::zero()),
+        }
+        assert_ne!(encrypt(&other, &message), ciphertext);
+        assert!(decrypt(&other, &ciphertext).is_err());
+        for i in 0..ciphertext.len() {
+            let mut invalid = ciphertext.clone();
+            invalid[i] += one;
+            assert!(decrypt(&ad, &invalid).is_err());
+        }
+    }
+    assert!(decrypt(&[], &[]).is_err());
+}
+
+fn sponge_round_trip<F, C>(sponge: &Sponge<C>)
+where
+    F: PrimeField,
+    C: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    let key = F::from(3u64);
+    round_trip(
+        |ad, m| encrypt_native(sponge, key, ad, m).unwrap(),
+        |ad, ct| decrypt_native(sponge, key, ad, ct),
+    );
+
+    // Another key gives another ciphertext, which doesn't decrypt under the first key
+    let ciphertext = encrypt_native(sponge, key, &[], &random::<F>(2)).unwrap();
+    assert_ne!(
+        encrypt_native(sponge, key + F::from(1u64), &[], &random::<F>(2)).unwrap(),
+        ciphertext
+    );
+    assert!(decrypt_native(sponge, key + F::from(1u64), &[], &ciphertext).is_err());
+}
+
+#[test]
+fn siv_sponges() {
+    sponge_round_trip(&griffin::<BlsFr>());
+    sponge_round_trip(&neptune::<BlsFr>());
+    sponge_round_trip(&neptune::<BnFr>());
+}
+
+#[test]
+fn siv_subkeys() {
+    let sponge = neptune::<BlsFr>();
+    let (key, ad, message) = (BlsFr::from(3u64), random::<BlsFr>(2), random::<BlsFr>(3));
+
+    // The two subkeys are apart from each other and from the key, which never keys the IV itself
+    let (iv_key, keystream_key) = subkeys(&sponge, &[FpVar::Constant(key)]).unwrap();
+    let (iv_key, keystream_key) = (iv_key.value().unwrap(), keystream_key.value().unwrap());
+    assert_ne!(iv_key, keystream_key);
+    assert_ne!(iv_key, key);
+    assert_ne!(keystream_key, key);
+
+    let iv = |k: BlsFr| {
+        synthetic_iv(
+            &sponge,
+            &FpVar::Constant(k),
+            &constants(&ad),
+            &constants(&message),
+        )
+        .unwrap()
+        .value()
+        .unwrap()
+    };
+    let ciphertext = encrypt_native(&sponge, key, &ad, &message).unwrap();
+    assert_eq!(ciphertext[message.len()], iv(iv_key));
+    assert_ne!(ciphertext[message.len()], iv(key));
+
+    // Both master keys of Ciminion go through the KDF
+    let (ciminion_iv_key, _) = subkeys(&sponge, &constants(&[key, BlsFr::from(5u64)])).unwrap();
+    assert_ne!(ciminion_iv_key.value().unwrap(), iv_key);
+    assert!(subkeys::<BlsFr, _>(&sponge, &[]).is_err());
+}
+
+#[test]
+fn siv_ciminion() {
+    let sponge = neptune::<BlsFr>();
+    let keys = (BlsFr::from(3u64), BlsFr::from(5u64));
+    round_trip(
+        |ad, m| ciminion::encrypt_native(&sponge, keys, ad, m).unwrap(),
+        |ad, ct| ciminion::decrypt_native(&sponge, keys, ad, ct),
+    );
+
+    // Only the keystream of Ciminion is used: without its tag, the ciphertext is the one of the
+    // AE under the IV as nonce
+    let message = random::<BlsFr>(3);
+    let ciphertext = ciminion::encrypt_native(&sponge, keys, &[], &message).unwrap();
+    let (iv, blocks) = ciphertext.split_last().unwrap();
+    assert_eq!(
+        blocks,
+        &crate::ciminion::primitives::CiminionPrimitive::encrypt(&message, keys, *iv)[..3]
+    );
+}
+
+#[test]
+fn siv_gadgets() {
+    let sponge = griffin::<BlsFr>();
+    let chip = ciminion_chip::<BlsFr>();
+    let (key, ad, message) = (random::<BlsFr>(2), random::<BlsFr>(2), random::<BlsFr>(3));
+
+    let cs = ConstraintSystem::<BlsFr>::new_ref();
+    let witness = |x: &[BlsFr]| Vec::new_witness(cs.clone(), || Ok(x.to_vec())).unwrap();
+    let (k, ad_var) = (witness(&key), witness(&ad));
+
+    let ciphertext = encrypt_native(&sponge, key[0], &ad, &message).unwrap();
+    let (decrypted, is_valid) = decrypt(&sponge, &k[0], &ad_var, &witness(&ciphertext)).unwrap();
+    assert_eq!(decrypted.value().unwrap(), message);
+    assert!(is_valid.value().unwrap());
+    let (_, is_valid) = decrypt(&sponge, &k[1], &ad_var, &witness(&ciphertext)).unwrap();
+    assert!(!is_valid.value().unwrap());
+
+    let ciphertext = ciminion::encrypt_native(&sponge, (key[0], key[1]), &ad, &message).unwrap();
+    let (decrypted, is_valid) = ciminion::decrypt(
+        &chip,
+        &sponge,
+        (&k[0], &k[1]),
+        &ad_var,
+        &witness(&ciphertext),
+    )
+    .unwrap();
+    assert_eq!(decrypted.value().unwrap(), message);
+    assert!(is_valid.value().unwrap());
+    let (_, is_valid) =
+        ciminion::decrypt(&chip, &sponge, (&k[0], &k[1]), &[], &witness(&ciphertext)).unwrap();
+    assert!(!is_valid.value().unwrap());
+
+    assert!(cs.is_satisfied().unwrap());
+}
+
+#[test]
+fn siv_circuits() {
+    let sponge = neptune::<BnFr>();
+    let (key, ad, message) = (BnFr::from(3u64), random::<BnFr>(2), random::<BnFr>(3));
+
+    let circuit = SivCircuit {
+        sponge: sponge.clone(),
+        key,
+        ad: ad.clone(),
+        message: message.clone(),
+        ciphertext: encrypt_native(&sponge, key, &ad, &message).unwrap(),
+    };
+    assert!(is_satisfied(circuit.clone()));
+    let mut invalid = circuit;
+    invalid.ad[0] += BnFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+
+    let keys = (key, BnFr::from(5u64));
+    let circuit = CiminionSivCircuit {
+        chip: ciminion_chip(),
+        sponge: sponge.clone(),
+        keys,
+        ad: ad.clone(),
+        message: message.clone(),
+        ciphertext: ciminion::encrypt_native(&sponge, keys, &ad, &message).unwrap(),
+    };
+    assert!(is_satisfied(circuit.clone()));
+    let mut invalid = circuit;
+    invalid.ciphertext[1] += BnFr::from(1u64);
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_siv_ciminion_bls() {
+    let sponge = neptune::<BlsFr>();
+    let keys = (BlsFr::from(3u64), BlsFr::from(5u64));
+    let (ad, message) = (random::<BlsFr>(1), random::<BlsFr>(2));
+    let circuit = CiminionSivCircuit {
+        chip: ciminion_chip(),
+        sponge: sponge.clone(),
+        keys,
+        ad: ad.clone(),
+        message: message.clone(),
+        ciphertext: ciminion::encrypt_native(&sponge, keys, &ad, &message).unwrap(),
+    };
+    let inputs = [circuit.ciphertext.clone(), ad].concat();
+
+    let rng = &mut ark_std::test_rng();
+    type GrothSetup = Groth16<Bls381>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &inputs, &proof).unwrap());
+    let mut invalid = inputs;
+    invalid[3] += BlsFr::from(1u64);
+    assert!(!GrothSetup::verify(&vk, &invalid, &proof).unwrap());
+}
@@ -0,0 +1,203 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+//! Verifiable encryption: a public ciphertext encrypts a message with a public digest
+//!
+//! The circuits witness the message blocks once, encrypt them and hash them. The message and the
+//! key stay secret, the ciphertext (followed by the tag), the nonce and the digest are public
+//! inputs, in that order. The digest can use any sponge, not necessarily the one encrypting.
+//! `new` computes the ciphertext and the digest natively, `public_inputs` lists them.
+
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    fields::fp::FpVar,
+    prelude::{AllocVar, EqGadget},
+    R1CSVar,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+use crate::{
+    aead,
+    api::{constants, ChipAPI, Sponge},
+    ciminion::chip::CiminionChip,
+    crh,
+    griffin::chip::GriffinChip,
+    neptune::chip::NeptuneChip,
+};
+
+pub mod tests;
+
+/// Verifiable encryption with the SAFE based AE of a sponge
+#[derive(Clone)]
+pub struct SpongeVerifiableCircuit<F, E, H>
+where
+    F: PrimeField,
+    E: ChipAPI<Value = FpVar<F>> + Clone,
+    H: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    pub sponge: Sponge<E>,
+    pub hash: Sponge<H>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+    pub key: F,
+    pub nonce: F,
+    pub digest: F,
+}
+
+pub type GriffinVerifiableCircuit<F, H> = SpongeVerifiableCircuit<F, GriffinChip<F>, H>;
+pub type NeptuneVerifiableCircuit<F, H> = SpongeVerifiableCircuit<F, NeptuneChip<F>, H>;
+
+impl<F, E, H> SpongeVerifiableCircuit<F, E, H>
+where
+    F: PrimeField,
+    E: ChipAPI<Value = FpVar<F>> + Clone,
+    H: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    /// Encrypts and hashes the message natively
+    pub fn new(
+        sponge: Sponge<E>,
+        hash: Sponge<H>,
+        message: Vec<F>,
+        key: F,
+        nonce: F,
+    ) -> Result<Self, anyhow::Error> {
+        let ciphertext = aead::encrypt_native(&sponge, key, nonce, &[], &message)?;
+        let digest = digest(&hash, &message)?;
+
+        Ok(Self {
+            sponge,
+            hash,
+            message,
+            ciphertext,
+            key,
+            nonce,
+            digest,
+        })
+    }
+
+    /// The public inputs: the ciphertext, the nonce and the digest
+    pub fn public_inputs(&self) -> Vec<F> {
+        [&self.ciphertext[..], &[self.nonce, self.digest]].concat()
+    }
+}
+
+impl<F, E, H> ConstraintSynthesizer<F> for SpongeVerifiableCircuit<F, E, H>
+where
+    F: PrimeField,
+    E: ChipAPI<Value = FpVar<F>> + Clone,
+    H: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message))?;
+        let k = FpVar::new_witness(cs.clone(), || Ok(self.key))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let n = FpVar::new_input(cs.clone(), || Ok(self.nonce))?;
+        let d = FpVar::new_input(cs, || Ok(self.digest))?;
+
+        aead::encrypt(&self.sponge, &k, &n, &[], &m)?.enforce_equal(&ct)?;
+        crh::hash(&self.hash, &m)?.enforce_equal(&d)?;
+
+        Ok(())
+    }
+}
+
+/// Verifiable encryption with Ciminion
+#[derive(Clone)]
+pub struct CiminionVerifiableCircuit<F, H>
+where
+    F: PrimeField,
+    H: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    pub chip: CiminionChip<F>,
+    pub hash: Sponge<H>,
+    pub message: Vec<F>,
+    pub ciphertext: Vec<F>,
+    pub keys: (F, F),
+    pub nonce: F,
+    pub digest: F,
+}
+
+impl<F, H> CiminionVerifiableCircuit<F, H>
+where
+    F: PrimeField,
+    H: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    /// Encrypts (with the parameters of the chip) and hashes the message natively
+    pub fn new(
+        chip: CiminionChip<F>,
+        hash: Sponge<H>,
+        message: Vec<F>,
+        keys: (F, F),
+        nonce: F,
+    ) -> Result<Self, anyhow::Error> {
+        let mut native = chip.clone();
+        native.init(
+            FpVar::Constant(keys.0),
+            FpVar::Constant(keys.1),
+            message.len(),
+        );
+        let ciphertext = native
+            .ae(&constants(&message), FpVar::Constant(nonce))?
+            .value()?;
+        let digest = digest(&hash, &message)?;
+
+        Ok(Self {
+            chip,
+            hash,
+            message,
+            ciphertext,
+            keys,
+            nonce,
+            digest,
+        })
+    }
+
+    /// The public inputs: the ciphertext, the nonce and the digest
+    pub fn public_inputs(&self) -> Vec<F> {
+        [&self.ciphertext[..], &[self.nonce, self.digest]].concat()
+    }
+}
+
+impl<F, H> ConstraintSynthesizer<F> for CiminionVerifiableCircuit<F, H>
+where
+    F: PrimeField,
+    H: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    fn generate_constraints(mut self, cs: ConstraintSystemRef<F>) -> Result<(), SynthesisError> {
+        let m = Vec::new_witness(cs.clone(), || Ok(self.message.clone()))?;
+        let mk1 = FpVar::new_witness(cs.clone(), || Ok(self.keys.0))?;
+        let mk2 = FpVar::new_witness(cs.clone(), || Ok(self.keys.1))?;
+        let ct = Vec::new_input(cs.clone(), || Ok(self.ciphertext))?;
+        let n = FpVar::new_input(cs.clone(), || Ok(self.nonce))?;
+        let d = FpVar::new_input(cs, || Ok(self.digest))?;
+
+        self.chip.init(mk1, mk2, self.message.len());
+        self.chip.ae(&m, n)?.enforce_equal(&ct)?;
+        crh::hash(&self.hash, &m)?.enforce_equal(&d)?;
+
+        Ok(())
+    }
+}
+
+/// Hashes a message natively, as the circuits do
+pub fn digest<F, H>(hash: &Sponge<H>, message: &[F]) -> Result<F, anyhow::Error>
+where
+    F: PrimeField,
+    H: ChipAPI<Value = FpVar<F>> + Clone,
+{
+    Ok(crh::hash(hash, &constants(message))?.value()?)
+}
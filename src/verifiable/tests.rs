@@ -0,0 +1,180 @@
+/* zekrom-arkworks
+* Copyright (C) 2023
+*
+* This program is free software: you can redistribute it and/or modify
+* it under the terms of the GNU General Public License as published by
+* the Free Software Foundation, either version 3 of the License, or
+* (at your option) any later version.
+*
+* This program is distributed in the hope that it will be useful,
+* but WITHOUT ANY WARRANTY; without even the implied warranty of
+* MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+* GNU General Public License for more details.
+*
+* You should have received a copy of the GNU General Public License
+* along with this program.  If not, see <http://www.gnu.org/licenses/>.
+*/
+
+#![cfg(test)]
+
+use ark_bls12_381::{Bls12_381 as Bls381, Fr as BlsFr};
+use ark_bn254::{Bn254, Fr as BnFr};
+use ark_ff::{PrimeField, UniformRand};
+use ark_groth16::Groth16;
+use ark_r1cs_std::R1CSVar;
+use ark_snark::SNARK;
+
+use super::{
+    digest, CiminionVerifiableCircuit, GriffinVerifiableCircuit, NeptuneVerifiableCircuit,
+};
+use crate::{
+    api::Sponge,
+    ciminion::{chip::CiminionChip, primitives::CiminionPrimitive},
+    common::test_utils::is_satisfied,
+    griffin::{self, chip::GriffinChip, primitives::GriffinPrimitives},
+    neptune::{self, chip::NeptuneChip},
+    rescue_prime::{self, chip::RescuePrimeChip},
+};
+
+fn griffin<F: PrimeField>() -> Sponge<GriffinChip<F>> {
+    Sponge::<GriffinChip<F>>::new(
+        GriffinChip::try_new(griffin::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn neptune<F: PrimeField>() -> Sponge<NeptuneChip<F>> {
+    Sponge::<NeptuneChip<F>>::new(
+        NeptuneChip::try_new(neptune::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn rescue_prime<F: PrimeField>() -> Sponge<RescuePrimeChip<F>> {
+    Sponge::<RescuePrimeChip<F>>::new(
+        RescuePrimeChip::try_new(rescue_prime::params::bundled::<F>().unwrap()).unwrap(),
+    )
+}
+
+fn ciminion<F: PrimeField>() -> CiminionChip<F> {
+    CiminionChip::try_new(crate::ciminion::params::bundled::<F>().unwrap()).unwrap()
+}
+
+fn random<F: PrimeField>(len: usize) -> Vec<F> {
+    let rng = &mut ark_std::test_rng();
+    (0..len).map(|_| F::rand(rng)).collect()
+}
+
+#[test]
+fn native_helpers() {
+    let message = random::<BlsFr>(3);
+    let (key, nonce) = (BlsFr::from(3u64), BlsFr::from(7u64));
+
+    // The digest is the hash of the message, and the ciphertext the one of the AE
+    let circuit =
+        GriffinVerifiableCircuit::new(griffin(), griffin(), message.clone(), key, nonce).unwrap();
+    assert_eq!(
+        circuit.digest,
+        GriffinPrimitives::<BlsFr>::hash(message.clone())
+            .unwrap()
+            .value()
+            .unwrap()
+    );
+    assert_eq!(
+        circuit.ciphertext,
+        GriffinPrimitives::<BlsFr>::ae(message.clone(), key, nonce)
+            .unwrap()
+            .value()
+            .unwrap()
+    );
+    assert_eq!(
+        circuit.public_inputs(),
+        [circuit.ciphertext.clone(), vec![nonce, circuit.digest]].concat()
+    );
+
+    let keys = (key, nonce);
+    let circuit =
+        CiminionVerifiableCircuit::new(ciminion(), neptune(), message.clone(), keys, nonce)
+            .unwrap();
+    assert_eq!(
+        circuit.ciphertext,
+        CiminionPrimitive::<BlsFr>::encrypt(&message, keys, nonce)
+    );
+    assert_eq!(circuit.digest, digest(&neptune(), &message).unwrap());
+}
+
+#[test]
+fn verifiable_circuits() {
+    let message = random::<BnFr>(4);
+    let (key, nonce) = (BnFr::from(3u64), BnFr::from(7u64));
+    let one = BnFr::from(1u64);
+
+    let circuit =
+        GriffinVerifiableCircuit::new(griffin(), neptune(), message.clone(), key, nonce).unwrap();
+    assert!(is_satisfied(circuit.clone()));
+    let mut invalid = circuit;
+    invalid.digest += one;
+    assert!(!is_satisfied(invalid));
+
+    let circuit =
+        NeptuneVerifiableCircuit::new(neptune(), rescue_prime(), message.clone(), key, nonce)
+            .unwrap();
+    assert!(is_satisfied(circuit.clone()));
+    // Another message with the right digest doesn't give the ciphertext
+    let mut invalid = circuit;
+    invalid.message[0] += one;
+    invalid.digest = digest(&rescue_prime(), &invalid.message).unwrap();
+    assert!(!is_satisfied(invalid));
+
+    let circuit =
+        CiminionVerifiableCircuit::new(ciminion(), griffin(), message, (key, one), nonce).unwrap();
+    assert!(is_satisfied(circuit.clone()));
+    let mut invalid = circuit.clone();
+    invalid.ciphertext[2] += one;
+    assert!(!is_satisfied(invalid));
+    let mut invalid = circuit;
+    invalid.digest += one;
+    assert!(!is_satisfied(invalid));
+}
+
+#[test]
+fn groth16_verifiable_neptune_bn254() {
+    let rng = &mut ark_std::test_rng();
+    let circuit = NeptuneVerifiableCircuit::new(
+        neptune(),
+        neptune(),
+        random(3),
+        BnFr::rand(rng),
+        BnFr::rand(rng),
+    )
+    .unwrap();
+    let public = circuit.public_inputs();
+
+    type GrothSetup = Groth16<Bn254>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+    let mut invalid = public;
+    let last = invalid.len() - 1;
+    invalid[last] += BnFr::from(1u64);
+    assert!(!GrothSetup::verify(&vk, &invalid, &proof).unwrap());
+}
+
+#[test]
+fn groth16_verifiable_ciminion_bls() {
+    let rng = &mut ark_std::test_rng();
+    let circuit = CiminionVerifiableCircuit::new(
+        ciminion(),
+        neptune(),
+        random(2),
+        (BlsFr::rand(rng), BlsFr::rand(rng)),
+        BlsFr::rand(rng),
+    )
+    .unwrap();
+    let public = circuit.public_inputs();
+
+    type GrothSetup = Groth16<Bls381>;
+    let (pk, vk) = GrothSetup::circuit_specific_setup(circuit.clone(), rng).unwrap();
+    let proof = GrothSetup::prove(&pk, circuit, rng).unwrap();
+
+    assert!(GrothSetup::verify(&vk, &public, &proof).unwrap());
+}